@@ -8,6 +8,8 @@
 // calls Rust FFI functions for each event. These functions are non-blocking
 // and simply send to a tokio::sync::mpsc channel.
 
+#include "CameraRemote_SDK.h"
+#include "CrImageDataBlock.h"
 #include "IDeviceCallback.h"
 #include "ICrCameraObjectInfo.h"
 
@@ -98,6 +100,39 @@ extern "C" {
     }
 }
 
+// C shim functions for live view (CrImageInfo/CrImageDataBlock are C++ classes)
+extern "C" {
+    CrInt32 crsdk_live_view_buffer_size(SCRSDK::CrDeviceHandle handle, CrInt32u* size) {
+        SCRSDK::CrImageInfo info;
+        SCRSDK::CrError err = SCRSDK::GetLiveViewImageInfo(handle, &info);
+        if (err == SCRSDK::CrError_None && size) *size = info.GetBufferSize();
+        return static_cast<CrInt32>(err);
+    }
+
+    // Fetch the latest live view frame into buf. The SDK prepends a header,
+    // so the JPEG starts at *offset within buf.
+    CrInt32 crsdk_live_view_image(
+        SCRSDK::CrDeviceHandle handle,
+        CrInt8u* buf,
+        CrInt32u buf_size,
+        CrInt32u* offset,
+        CrInt32u* image_size,
+        CrInt32u* frame_no
+    ) {
+        if (!buf || !offset || !image_size || !frame_no) return SCRSDK::CrError_Generic_InvalidParameter;
+        SCRSDK::CrImageDataBlock block;
+        block.SetSize(buf_size);
+        block.SetData(buf);
+        SCRSDK::CrError err = SCRSDK::GetLiveViewImage(handle, &block);
+        if (err != SCRSDK::CrError_None) return static_cast<CrInt32>(err);
+        CrInt8u* data = block.GetImageData();
+        *offset = data ? static_cast<CrInt32u>(data - buf) : 0;
+        *image_size = data ? block.GetImageSize() : 0;
+        *frame_no = block.GetFrameNo();
+        return static_cast<CrInt32>(err);
+    }
+}
+
 namespace {
     class MinimalCallback : public SCRSDK::IDeviceCallback {
     public:
@@ -25,6 +25,45 @@ include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 // Include generated property code enum
 include!(concat!(env!("OUT_DIR"), "/property_codes.rs"));
 
+impl DevicePropertyCode {
+    /// Look up a property by name
+    ///
+    /// Matches `name()` ignoring case, spaces and underscores, so
+    /// `"Iso Sensitivity"`, `"iso_sensitivity"` and `"IsoSensitivity"` all
+    /// find the same code.
+    pub fn from_name(name: &str) -> Option<Self> {
+        fn key(s: &str) -> impl Iterator<Item = char> + '_ {
+            s.chars()
+                .filter(|c| *c != ' ' && *c != '_')
+                .map(|c| c.to_ascii_lowercase())
+        }
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|code| key(code.name()).eq(key(name)))
+    }
+}
+
+/// Error returned when parsing an unknown property name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownPropertyName(pub String);
+
+impl core::fmt::Display for UnknownPropertyName {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown property: {}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownPropertyName {}
+
+impl core::str::FromStr for DevicePropertyCode {
+    type Err = UnknownPropertyName;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or_else(|| UnknownPropertyName(s.to_string()))
+    }
+}
+
 // Re-export SCRSDK namespace at crate root for convenience
 pub use root::SCRSDK;
 
@@ -94,6 +133,27 @@ extern "C" {
     pub fn crsdk_camera_info_release(info: *mut SCRSDK::ICrCameraObjectInfo);
 }
 
+// Live view shims (CrImageInfo/CrImageDataBlock are C++ classes)
+extern "C" {
+    /// Get the buffer size required for the current live view image
+    ///
+    /// Returns an SDK error code (0 on success).
+    pub fn crsdk_live_view_buffer_size(handle: i64, size: *mut u32) -> i32;
+
+    /// Fetch the latest live view frame into `buf`
+    ///
+    /// On success, the JPEG data is `buf[offset..offset + image_size]`.
+    /// Returns an SDK error code (0 on success).
+    pub fn crsdk_live_view_image(
+        handle: i64,
+        buf: *mut u8,
+        buf_size: u32,
+        offset: *mut u32,
+        image_size: *mut u32,
+        frame_no: *mut u32,
+    ) -> i32;
+}
+
 // Event callback shims
 extern "C" {
     /// Create a new RustCallback with the given context pointer
@@ -117,4 +177,35 @@ mod tests {
         let version_func_exists = SCRSDK::GetSDKVersion as usize;
         assert_ne!(version_func_exists, 0);
     }
+
+    #[test]
+    fn test_from_name_round_trips() {
+        for &code in DevicePropertyCode::ALL {
+            assert_eq!(DevicePropertyCode::from_name(code.name()), Some(code));
+            assert_eq!(code.name().parse::<DevicePropertyCode>(), Ok(code));
+        }
+    }
+
+    #[test]
+    fn test_from_name_loose_matching() {
+        let iso = Some(DevicePropertyCode::IsoSensitivity);
+        assert_eq!(DevicePropertyCode::from_name("IsoSensitivity"), iso);
+        assert_eq!(DevicePropertyCode::from_name("isosensitivity"), iso);
+        assert_eq!(DevicePropertyCode::from_name("iso_sensitivity"), iso);
+        assert_eq!(DevicePropertyCode::from_name("Iso Sensitivity"), iso);
+    }
+
+    #[test]
+    fn test_from_name_unknown() {
+        assert_eq!(DevicePropertyCode::from_name("NotAProperty"), None);
+        assert_eq!(DevicePropertyCode::from_name(""), None);
+        assert_eq!(
+            "NotAProperty".parse::<DevicePropertyCode>(),
+            Err(UnknownPropertyName("NotAProperty".to_string()))
+        );
+        assert_eq!(
+            UnknownPropertyName("NotAProperty".to_string()).to_string(),
+            "unknown property: NotAProperty"
+        );
+    }
 }
@@ -0,0 +1,85 @@
+//! Cost of converting a full `GetDeviceProperties` result
+//!
+//! Builds a synthetic snapshot shaped like a large body's (450 properties,
+//! each with 40 discrete allowed values) and compares full conversion with
+//! the current-value-only path used by `get_all_properties_current()`.
+//!
+//! Run with `cargo bench -p crsdk --bench properties`.
+
+use std::hint::black_box;
+use std::ptr;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use crsdk::__bench::{device_property_current_from_sdk, device_property_from_sdk};
+use crsdk_sys::SCRSDK::{
+    CrDataType_CrDataType_UInt16, CrDeviceProperty, CrPropertyEnableFlag_CrEnableValue_True,
+};
+
+const PROPERTY_COUNT: u32 = 450;
+const ALLOWED_VALUES: u16 = 40;
+
+/// SDK properties plus the buffers their pointers refer to
+struct Snapshot {
+    properties: Vec<CrDeviceProperty>,
+    _values: Vec<Vec<u16>>,
+}
+
+fn snapshot() -> Snapshot {
+    let mut values: Vec<Vec<u16>> = (0..PROPERTY_COUNT)
+        .map(|_| (0..ALLOWED_VALUES).map(|v| v * 100).collect())
+        .collect();
+    let properties = values
+        .iter_mut()
+        .enumerate()
+        .map(|(code, allowed)| CrDeviceProperty {
+            code: code as u32,
+            valueType: CrDataType_CrDataType_UInt16,
+            enableFlag: CrPropertyEnableFlag_CrEnableValue_True as _,
+            variableFlag: 0,
+            currentValue: 100,
+            currentStr: ptr::null_mut(),
+            valuesSize: (allowed.len() * 2) as u32,
+            values: allowed.as_mut_ptr() as *mut u8,
+            getSetValuesSize: 0,
+            getSetValues: ptr::null_mut(),
+        })
+        .collect();
+    Snapshot {
+        properties,
+        _values: values,
+    }
+}
+
+fn bench_conversion(c: &mut Criterion) {
+    let snapshot = snapshot();
+    let mut group = c.benchmark_group("get_all_properties");
+
+    group.bench_function("full", |b| {
+        b.iter(|| {
+            let props: Vec<_> = snapshot
+                .properties
+                .iter()
+                // SAFETY: every pointer refers to a live buffer in `snapshot`
+                .map(|p| unsafe { device_property_from_sdk(p) })
+                .collect();
+            black_box(props)
+        })
+    });
+
+    group.bench_function("current_only", |b| {
+        b.iter(|| {
+            let props: Vec<_> = snapshot
+                .properties
+                .iter()
+                // SAFETY: currentStr is null
+                .map(|p| unsafe { device_property_current_from_sdk(p) })
+                .collect();
+            black_box(props)
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_conversion);
+criterion_main!(benches);
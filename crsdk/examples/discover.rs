@@ -48,6 +48,7 @@ async fn main() -> Result<()> {
         println!("   Model: {}", camera.model);
         println!("   Name: {}", camera.name);
         println!("   Type: {}", camera.connection_type);
+        println!("   Status: {}", camera.connection_status);
 
         if let Some(ip) = &camera.ip_address {
             println!("   IP: {}", ip);
@@ -0,0 +1,81 @@
+//! Debounced property writes for slider-style controls
+
+use std::time::{Duration, Instant};
+
+use crsdk_sys::DevicePropertyCode;
+
+use super::CameraDevice;
+use crate::debounce::{Debounce, DEFAULT_QUIET_PERIOD};
+use crate::error::Result;
+use crate::log;
+
+/// Coalesces rapid writes to one property, sending only the latest value
+///
+/// Created with `CameraDevice::set_property_debounced()`. `set()` only
+/// remembers the value; `poll()` sends it once no new value has arrived for
+/// the quiet period, so dragging a slider lands on the final value without
+/// sending every intermediate one. Call `poll()` from the UI loop, sleeping
+/// until `next_deadline()` if there is nothing else to do. Dropping the sink
+/// sends any pending value right away.
+pub struct DebouncedProperty<'a> {
+    device: &'a CameraDevice,
+    code: DevicePropertyCode,
+    debounce: Debounce,
+}
+
+impl<'a> DebouncedProperty<'a> {
+    pub(crate) fn new(device: &'a CameraDevice, code: DevicePropertyCode) -> Self {
+        Self {
+            device,
+            code,
+            debounce: Debounce::new(DEFAULT_QUIET_PERIOD),
+        }
+    }
+
+    /// Change how long writes must stop before the value is sent (default 150ms)
+    pub fn with_quiet_period(mut self, quiet: Duration) -> Self {
+        self.debounce.quiet = quiet;
+        self
+    }
+
+    /// The property this sink writes
+    pub fn code(&self) -> DevicePropertyCode {
+        self.code
+    }
+
+    /// Queue `value`, replacing any value not sent yet
+    pub fn set(&mut self, value: u64) {
+        self.debounce.push(value, Instant::now());
+    }
+
+    /// Send the pending value if the quiet period has passed
+    ///
+    /// Returns whether a value was sent.
+    pub fn poll(&mut self) -> Result<bool> {
+        match self.debounce.take_due(Instant::now()) {
+            Some(value) => self.device.set_property(self.code, value).map(|()| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Send the pending value now, without waiting for the quiet period
+    pub fn flush(&mut self) -> Result<()> {
+        match self.debounce.take() {
+            Some(value) => self.device.set_property(self.code, value),
+            None => Ok(()),
+        }
+    }
+
+    /// When `poll()` will next have something to send, if anything is pending
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.debounce.deadline()
+    }
+}
+
+impl Drop for DebouncedProperty<'_> {
+    fn drop(&mut self) {
+        if let Err(e) = self.flush() {
+            log::warn!("Failed to send final {} value: {}", self.code.name(), e);
+        }
+    }
+}
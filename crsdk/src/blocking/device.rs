@@ -4,34 +4,141 @@
 use asyncwrap::async_wrap;
 use asyncwrap::blocking_impl;
 
-use crate::command::{CommandId, CommandParam};
+use super::DebouncedProperty;
+use crate::capture::{CaptureOutcome, CaptureResult, CaptureWatch};
+use crate::command::{supported_commands, CommandId, CommandParam, CommandValue, ResetScope};
+use crate::datetime::CameraDateTime;
+use crate::discovery::{diff_cameras, CameraDelta, WATCH_RESCAN_INTERVAL};
+use crate::dof::DepthOfField;
 use crate::error::{Error, Result};
-use crate::event::CameraEvent;
+use crate::event::{coalesce_events, warning_code_name, CameraEvent, ChangeHook, ChangeRecord};
 use crate::event_sender::EventSender;
+use crate::firmware::FirmwareInfo;
+use crate::focus::FocusState;
+use crate::health::{CameraCaution, CameraHealth};
+use crate::live_view::{CropRect, FocusMagnifier, LiveViewFrame, LiveViewFrameMeta};
+use crate::log;
+use crate::media::{estimate_remaining_shots, MediaSlotInfo};
+use crate::preset::{Preset, PresetReport};
 use crate::property::{
-    device_property_from_sdk, device_property_from_sdk_debug, DeviceProperty, DriveMode,
-    ExposureProgram, FlashMode, FocusArea, FocusMode, LockIndicator, MeteringMode, PropertyValue,
-    WhiteBalance,
+    device_property_current_from_sdk, device_property_from_sdk, device_property_from_sdk_debug,
+    property_value_type, raw_values_bytes, summarize_categories, Aperture, AspectRatio,
+    CameraOperatingMode, CategorySummary, ColorTemperature, CompressionFileFormat, DeviceProperty,
+    DriveMode, ExposureComp, ExposureCtrlType, ExposureProgram, FlashMode, FocusArea,
+    FocusIndicator, FocusMode, FocusOperation, FocusTrackingStatus, Gain, GainUnitSetting,
+    ImagerScanMode, LiveViewQuality, LiveViewStatus, LockIndicator, MeteringMode,
+    MonitoringOutputFormat, MovieFileFormat, MovieQuality, MovieRecReviewPlayingState,
+    PictureProfile, PlaybackMedia, PropertyValue, RAWFileCompressionType, RecordingFolderFormat,
+    RecordingFrameRate, RecordingState, ShutterMode, SpotBoostStatus, StillImageStoreDestination,
+    StreamStatus, SubjectRecognitionAF, SubjectTarget, Switch, ValueConstraint, WhiteBalance,
+    ZoomOperation, APSC_S35,
+};
+#[cfg(feature = "record")]
+use crate::session::Session;
+use crate::settings::{
+    validate_clip_name, AudioRouting, CreativeLookSettings, HdmiOutputConfig, IntervalConfig,
+    PictureProfileSettings, ProgramShift, Shutter, Stabilization, StreamConfig, VideoFormat,
+    VideoMode,
 };
 use crate::types::{
-    CameraModel, ConnectionInfo, ConnectionType, DiscoveredCamera, MacAddr, ToCrsdk,
+    parse_ip_address, parse_mac_address, CameraModel, ConnectionInfo, ConnectionStatus,
+    ConnectionType, DiscoveredCamera, DiscoveryDiagnostics, MacAddr, ToCrsdk,
 };
 use crate::Sdk;
 use crsdk_sys::DevicePropertyCode;
 use std::ffi::{c_void, CString};
 use std::net::Ipv4Addr;
+use std::path::PathBuf;
 use std::ptr;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
 use tokio::sync::mpsc;
 
-static SDK_INITIALIZED: AtomicBool = AtomicBool::new(false);
+/// Whether the process-wide SDK instance has been created
+///
+/// A mutex rather than an atomic flag so that callers racing on first use
+/// wait for the one doing the initialization instead of also calling
+/// `Sdk::init()` and failing with "already initialized".
+static SDK_INITIALIZED: Mutex<bool> = Mutex::new(false);
+
+/// How often `format_media()` polls `MediaFormatProgressRate`
+const FORMAT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Upper bound for a full format of a large, slow card
+const FORMAT_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// How long a format may go without showing progress or completing
+const FORMAT_START_GRACE: Duration = Duration::from_secs(10);
+
+/// Warning sent when a format finishes (`CrWarning_Format_Complete`)
+const WARNING_FORMAT_COMPLETE: u32 = 0x0002000B;
+
+/// Warnings sent when a format fails or can't run (`CrWarning_Format_Failed`,
+/// `CrWarning_Format_Invalid`)
+const WARNING_FORMAT_FAILED: [u32; 2] = [0x00020009, 0x0002000A];
+
+/// How often `autofocus()` checks the focus indicator
+const AF_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// How often `set_crop_mode()` checks whether the camera applied the change
+const CROP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long `set_crop_mode()` waits for the camera to apply the change
+const CROP_SETTLE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// How often `set_and_settle()` re-reads a property it is waiting on
+const SETTLE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long `set_and_settle()` waits for the camera to apply a write
+const SETTLE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// How often `connect()` checks for the camera's connection confirmation
+const CONNECT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// How often `wait_ready()` checks whether the camera has sent its properties
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How often the `*_and_wait` recording controls check `RecordingState`
+const RECORDING_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How often review controls check `MovieRecReviewPlayingState`
+const REVIEW_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
+/// How long review controls wait for the playing state to follow a button
+const REVIEW_SETTLE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// How often maintenance commands poll their enable status for completion
+const MAINTENANCE_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Upper bound for pixel mapping or sensor cleaning to finish
+const MAINTENANCE_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// How long a maintenance run may go without the camera reacting to it
+const MAINTENANCE_START_GRACE: Duration = Duration::from_secs(3);
+
+/// The property holding ND filter preset `slot`
+fn nd_preset_code(slot: u8) -> Result<DevicePropertyCode> {
+    match slot {
+        1 => Ok(DevicePropertyCode::NDFilterPreset1Value),
+        2 => Ok(DevicePropertyCode::NDFilterPreset2Value),
+        3 => Ok(DevicePropertyCode::NDFilterPreset3Value),
+        _ => Err(Error::InvalidParameter(format!(
+            "ND preset {} (presets are 1-3)",
+            slot
+        ))),
+    }
+}
+
+/// Initialize the SDK on first use; safe to call from any number of threads
+///
+/// A failed initialization is not remembered, so the next call tries again.
 fn ensure_sdk_initialized() -> Result<()> {
-    if !SDK_INITIALIZED.load(Ordering::Acquire) {
+    let mut initialized = SDK_INITIALIZED.lock().unwrap_or_else(|e| e.into_inner());
+    if !*initialized {
         let sdk = Sdk::init()?;
         std::mem::forget(sdk); // Keep SDK alive for program lifetime
-        SDK_INITIALIZED.store(true, Ordering::Release);
+        *initialized = true;
     }
     Ok(())
 }
@@ -49,6 +156,17 @@ fn ensure_sdk_initialized() -> Result<()> {
 ///
 /// A vector of discovered cameras. Empty if no cameras found.
 pub fn discover_cameras(timeout_secs: u8) -> Result<Vec<DiscoveredCamera>> {
+    discover_cameras_debug(timeout_secs).map(|(cameras, _)| cameras)
+}
+
+/// Discover cameras, also reporting what the SDK enumeration returned
+///
+/// Like `discover_cameras()`, but additionally returns how many raw entries
+/// the SDK reported and why any of them were dropped, for diagnosing a
+/// camera that doesn't show up.
+pub fn discover_cameras_debug(
+    timeout_secs: u8,
+) -> Result<(Vec<DiscoveredCamera>, DiscoveryDiagnostics)> {
     ensure_sdk_initialized()?;
 
     let mut enum_ptr: *mut crsdk_sys::SCRSDK::ICrEnumCameraObjectInfo = ptr::null_mut();
@@ -59,22 +177,28 @@ pub fn discover_cameras(timeout_secs: u8) -> Result<Vec<DiscoveredCamera>> {
         return Err(Error::from_sdk_error(result as u32));
     }
 
+    let mut diagnostics = DiscoveryDiagnostics::default();
     if enum_ptr.is_null() {
-        return Ok(Vec::new());
+        return Ok((Vec::new(), diagnostics));
     }
 
     let count = unsafe { crsdk_sys::crsdk_enum_camera_get_count(enum_ptr) };
+    diagnostics.raw_count = count;
     let mut cameras = Vec::with_capacity(count as usize);
 
     for i in 0..count {
         let info_ptr = unsafe { crsdk_sys::crsdk_enum_camera_get_info(enum_ptr, i) };
         if info_ptr.is_null() {
+            diagnostics.null_entries.push(i);
             continue;
         }
 
         match camera_info_from_sdk(info_ptr) {
             Ok(camera) => cameras.push(camera),
-            Err(e) => tracing::warn!("Failed to parse camera info at index {}: {}", i, e),
+            Err(e) => {
+                log::warn!("Failed to parse camera info at index {}: {}", i, e);
+                diagnostics.parse_errors.push((i, e.to_string()));
+            }
         }
     }
 
@@ -82,7 +206,93 @@ pub fn discover_cameras(timeout_secs: u8) -> Result<Vec<DiscoveredCamera>> {
         crsdk_sys::crsdk_enum_camera_release(enum_ptr);
     }
 
-    Ok(cameras)
+    Ok((cameras, diagnostics))
+}
+
+/// Watch for cameras being powered on, off or changing connection state
+///
+/// Enumerates repeatedly in a background thread (each scan taking up to
+/// `timeout_secs`) and sends a [`CameraDelta`] for every change, starting
+/// with an `Added` for each camera already present. A failed scan is logged
+/// and skipped rather than reported as every camera disappearing.
+///
+/// Scanning stops soon after the returned [`CameraWatcher`] is dropped,
+/// whether or not anything changed since.
+pub fn watch_cameras(timeout_secs: u8) -> CameraWatcher {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let stopped = Arc::new(AtomicBool::new(false));
+    let stop = Arc::clone(&stopped);
+    std::thread::spawn(move || {
+        run_camera_watch(
+            timeout_secs,
+            |delta| tx.send(delta).is_ok(),
+            || stop.load(Ordering::Acquire),
+        );
+    });
+    CameraWatcher { rx, stopped }
+}
+
+/// Camera changes reported by [`watch_cameras()`]
+///
+/// Iterate it to wait for each change in turn, or use `recv_timeout()` to
+/// wait with a limit. Dropping it stops the background scanning.
+pub struct CameraWatcher {
+    rx: std::sync::mpsc::Receiver<CameraDelta>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl CameraWatcher {
+    /// Wait for the next change
+    pub fn recv(&self) -> Option<CameraDelta> {
+        self.rx.recv().ok()
+    }
+
+    /// Wait up to `timeout` for the next change
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<CameraDelta> {
+        self.rx.recv_timeout(timeout).ok()
+    }
+
+    /// Take a change that has already arrived, without waiting
+    pub fn try_recv(&self) -> Option<CameraDelta> {
+        self.rx.try_recv().ok()
+    }
+}
+
+impl Iterator for CameraWatcher {
+    type Item = CameraDelta;
+
+    fn next(&mut self) -> Option<CameraDelta> {
+        self.recv()
+    }
+}
+
+impl Drop for CameraWatcher {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Release);
+    }
+}
+
+/// Enumerate until `send` fails or `closed` returns true, sending changes
+pub(crate) fn run_camera_watch(
+    timeout_secs: u8,
+    mut send: impl FnMut(CameraDelta) -> bool,
+    closed: impl Fn() -> bool,
+) {
+    let mut known: Vec<DiscoveredCamera> = Vec::new();
+    while !closed() {
+        match discover_cameras(timeout_secs) {
+            Ok(cameras) => {
+                for delta in diff_cameras(&known, &cameras) {
+                    if !send(delta) {
+                        return;
+                    }
+                }
+                known = cameras;
+            }
+            Err(e) => log::warn!("Camera scan failed: {}", e),
+        }
+        std::thread::sleep(WATCH_RESCAN_INTERVAL);
+    }
 }
 
 /// Parse a string field from SDK camera info.
@@ -185,6 +395,11 @@ fn camera_info_from_sdk(
     // SAFETY: info is valid per caller contract
     let ssh_supported = unsafe { crsdk_sys::crsdk_camera_info_get_ssh_support(info) != 0 };
 
+    // SAFETY: info is valid per caller contract
+    let connection_status = ConnectionStatus::from_raw(unsafe {
+        crsdk_sys::crsdk_camera_info_get_connection_status(info)
+    });
+
     // SAFETY: info is valid per caller contract
     let usb_pid = unsafe {
         let pid = crsdk_sys::crsdk_camera_info_get_usb_pid(info);
@@ -203,6 +418,7 @@ fn camera_info_from_sdk(
         mac_address,
         ssh_supported,
         usb_pid,
+        connection_status,
     })
 }
 
@@ -246,12 +462,20 @@ pub struct CameraDevice {
     callback_ptr: *mut crsdk_sys::SCRSDK::IDeviceCallback,
     /// Event sender pointer - must be reclaimed when device is dropped
     event_sender_ptr: *mut c_void,
+    /// Called after each successful `set_property()`
+    on_change: Option<ChangeHook>,
+    /// Set by `disconnect()`; the SDK session is gone once this is true
+    disconnected: AtomicBool,
+    /// Recording or replay of this device's SDK calls
+    #[cfg(feature = "record")]
+    session: Option<Session>,
 }
 
 // SAFETY: CameraDevice can be sent between threads because:
 // - handle is just an i64
 // - model is Copy
 // - event_receiver is Send
+// - on_change is Send + Sync
 // - callback_ptr and event_sender_ptr are only accessed in Drop
 unsafe impl Send for CameraDevice {}
 
@@ -274,46 +498,103 @@ impl CameraDevice {
         self.model
     }
 
-    /// Get a property from the camera
+    /// Whether the camera connection is currently live
     ///
-    /// Returns the property with its current value, possible values, and metadata.
+    /// False until the camera confirms the connection and again after a
+    /// disconnect, until the SDK reconnects. While false, every method that
+    /// talks to the camera fails with `Error::NotConnected`.
     #[async_wrap]
-    pub fn get_property(&self, code: DevicePropertyCode) -> Result<DeviceProperty> {
-        let mut properties_ptr: *mut crsdk_sys::SCRSDK::CrDeviceProperty = ptr::null_mut();
-        let mut num_properties: i32 = 0;
+    pub fn is_connected(&self) -> bool {
+        !self.disconnected.load(Ordering::Acquire) && self.event_sender().is_connected()
+    }
 
-        let result = unsafe {
-            crsdk_sys::SCRSDK::GetDeviceProperties(
-                self.handle,
-                &mut properties_ptr,
-                &mut num_properties,
-            )
-        };
+    /// Whether the body has remote shooting enabled
+    ///
+    /// Reads `ShootingEnableSettingLicense`. Bodies that don't report it
+    /// don't gate remote shooting, so a missing property counts as enabled.
+    /// A body with PC Remote switched off usually refuses the connection
+    /// outright, which `connect()` reports as `Error::PairingRequired`.
+    #[async_wrap]
+    pub fn connection_enabled(&self) -> Result<bool> {
+        match self.get_property(DevicePropertyCode::ShootingEnableSettingLicense) {
+            Ok(prop) => Ok(prop.current_value != 0),
+            Err(Error::PropertyNotSupported) => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
 
+    /// Disconnect from the camera, releasing it for other hosts
+    ///
+    /// Afterwards every method that talks to the camera fails with
+    /// `Error::NotConnected`, and the SDK won't try to reconnect. Calling it
+    /// again is a no-op. Dropping the device disconnects too, but can't
+    /// report errors.
+    #[async_wrap]
+    pub fn disconnect(&self) -> Result<()> {
+        if self.disconnected.swap(true, Ordering::AcqRel) || self.handle == 0 {
+            return Ok(());
+        }
+        // SAFETY: handle is valid while non-zero, and the flag above makes
+        // sure Disconnect() is only called once
+        let result = unsafe { crsdk_sys::SCRSDK::Disconnect(self.handle) };
         if result != 0 {
             return Err(Error::from_sdk_error(result as u32));
         }
+        Ok(())
+    }
 
-        if properties_ptr.is_null() || num_properties == 0 {
-            return Err(Error::PropertyNotSupported);
+    /// Wait until the camera has sent its initial property set
+    ///
+    /// Right after connecting, the camera is still populating its properties
+    /// and reads can come back empty or partial. This polls until the camera
+    /// reports a valid `CameraOperatingMode` (record or playback), or returns
+    /// `Error::Timeout` once `timeout` elapses.
+    #[async_wrap]
+    pub fn wait_ready(&self, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            match self.get_property(DevicePropertyCode::CameraOperatingMode) {
+                Ok(prop)
+                    if prop.enable_flag.is_readable()
+                        && CameraOperatingMode::from_raw(prop.current_value).is_some() =>
+                {
+                    return Ok(());
+                }
+                Ok(prop) => log::debug!("Camera not ready yet: mode {:#x}", prop.current_value),
+                Err(e) => log::debug!("Camera not ready yet: {}", e),
+            }
+            if Instant::now() >= deadline {
+                return Err(Error::Timeout);
+            }
+            std::thread::sleep(READY_POLL_INTERVAL);
         }
+    }
 
+    /// Get a property from the camera
+    ///
+    /// Returns the property with its current value, possible values, and metadata.
+    #[async_wrap]
+    pub fn get_property(&self, code: DevicePropertyCode) -> Result<DeviceProperty> {
+        self.ensure_connected()?;
         let target_code = code.as_raw();
-        let mut found_property: Option<DeviceProperty> = None;
-
-        unsafe {
-            for i in 0..num_properties as usize {
-                let prop = &*properties_ptr.add(i);
-                if prop.code == target_code {
-                    found_property = Some(device_property_from_sdk(prop));
-                    break;
-                }
-            }
+        let call = || {
+            self.sdk_get_properties(|p| {
+                (p.code == target_code).then(|| unsafe { device_property_from_sdk(p) })
+            })
+            .map(|mut found| found.pop())
+        };
 
-            crsdk_sys::SCRSDK::ReleaseDeviceProperties(self.handle, properties_ptr);
-        }
+        #[cfg(feature = "record")]
+        let result = match &self.session {
+            Some(session) => session.get_property(target_code, call, self.event_sender()),
+            None => call(),
+        };
+        #[cfg(not(feature = "record"))]
+        let result = call();
 
-        found_property.ok_or(Error::PropertyNotSupported)
+        result
+            .map_err(Error::from_sdk_error)?
+            .ok_or(Error::PropertyNotSupported)
     }
 
     /// Get all properties from the camera
@@ -322,73 +603,111 @@ impl CameraDevice {
     /// Useful for debugging what properties are available.
     #[async_wrap]
     pub fn get_all_properties(&self) -> Result<Vec<DeviceProperty>> {
-        let mut properties_ptr: *mut crsdk_sys::SCRSDK::CrDeviceProperty = ptr::null_mut();
-        let mut num_properties: i32 = 0;
-
-        let result = unsafe {
-            crsdk_sys::SCRSDK::GetDeviceProperties(
-                self.handle,
-                &mut properties_ptr,
-                &mut num_properties,
-            )
-        };
-
-        if result != 0 {
-            return Err(Error::from_sdk_error(result as u32));
-        }
+        self.ensure_connected()?;
+        self.sdk_get_property_list(device_property_from_sdk)
+            .map_err(Error::from_sdk_error)
+    }
 
-        if properties_ptr.is_null() || num_properties == 0 {
-            return Ok(Vec::new());
-        }
+    /// Get the current value of every property, without allowed values
+    ///
+    /// Like `get_all_properties()`, but skips decoding each property's
+    /// allowed values: every `constraint` is `ValueConstraint::None` and
+    /// array `values` are empty. Much cheaper for dashboards that refresh
+    /// current values on a timer; fetch a full property when the allowed
+    /// values are needed.
+    #[async_wrap]
+    pub fn get_all_properties_current(&self) -> Result<Vec<DeviceProperty>> {
+        self.ensure_connected()?;
+        self.sdk_get_property_list(device_property_current_from_sdk)
+            .map_err(Error::from_sdk_error)
+    }
 
-        let mut properties = Vec::with_capacity(num_properties as usize);
+    /// Count the camera's properties per category
+    ///
+    /// Gives total, writable and read-only counts for each category that has
+    /// at least one property, e.g. for "Exposure: 12 (9 writable)".
+    #[async_wrap]
+    pub fn category_summary(&self) -> Result<Vec<CategorySummary>> {
+        Ok(summarize_categories(&self.get_all_properties()?))
+    }
 
-        unsafe {
-            for i in 0..num_properties as usize {
-                let prop = &*properties_ptr.add(i);
-                properties.push(device_property_from_sdk(prop));
-            }
+    /// Get a health summary (temperature, battery, power, lens, errors)
+    ///
+    /// Reads everything from a single property snapshot.
+    #[async_wrap]
+    pub fn health(&self) -> Result<CameraHealth> {
+        Ok(CameraHealth::from_properties(&self.get_all_properties()?))
+    }
 
-            crsdk_sys::SCRSDK::ReleaseDeviceProperties(self.handle, properties_ptr);
-        }
+    /// Get every error or caution the camera currently reports
+    ///
+    /// Empty when all is well. See `CameraHealth::cautions()`.
+    #[async_wrap]
+    pub fn cautions(&self) -> Result<Vec<CameraCaution>> {
+        Ok(CameraHealth::from_properties(&self.get_all_properties_current()?).cautions())
+    }
 
-        Ok(properties)
+    /// Get the body's firmware version and whether it can be updated now
+    ///
+    /// Fails with `Error::PropertyNotSupported` if the camera doesn't report
+    /// its firmware version.
+    #[async_wrap]
+    pub fn firmware(&self) -> Result<FirmwareInfo> {
+        FirmwareInfo::from_properties(&self.get_all_properties()?)
+            .ok_or(Error::PropertyNotSupported)
     }
 
     /// Get all properties with debug info (for debugging SDK values)
     #[async_wrap]
     pub fn get_all_properties_debug(&self) -> Result<Vec<(DeviceProperty, String)>> {
-        let mut properties_ptr: *mut crsdk_sys::SCRSDK::CrDeviceProperty = ptr::null_mut();
-        let mut num_properties: i32 = 0;
-
-        let result = unsafe {
-            crsdk_sys::SCRSDK::GetDeviceProperties(
-                self.handle,
-                &mut properties_ptr,
-                &mut num_properties,
-            )
+        self.ensure_connected()?;
+        let call =
+            || self.sdk_get_properties(|p| Some(unsafe { device_property_from_sdk_debug(p) }));
+
+        #[cfg(feature = "record")]
+        let result = match &self.session {
+            Some(session) => session.get_properties_debug(call, self.event_sender()),
+            None => call(),
         };
+        #[cfg(not(feature = "record"))]
+        let result = call();
 
-        if result != 0 {
-            return Err(Error::from_sdk_error(result as u32));
-        }
-
-        if properties_ptr.is_null() || num_properties == 0 {
-            return Ok(Vec::new());
-        }
-
-        let mut properties = Vec::with_capacity(num_properties as usize);
+        result.map_err(Error::from_sdk_error)
+    }
 
-        unsafe {
-            for i in 0..num_properties as usize {
-                let prop = &*properties_ptr.add(i);
-                properties.push(device_property_from_sdk_debug(prop));
-            }
+    /// Get a single property along with its raw value buffer
+    ///
+    /// The bytes are the property's `values` buffer exactly as the SDK
+    /// returned it (allowed values, or the payload of array and struct-like
+    /// properties), before any decoding. Useful for mapping out encodings
+    /// this crate doesn't know yet. `current_value` on the returned property
+    /// is the SDK's value unchanged.
+    #[async_wrap]
+    pub fn get_property_debug(
+        &self,
+        code: DevicePropertyCode,
+    ) -> Result<(DeviceProperty, Vec<u8>)> {
+        self.ensure_connected()?;
+        let target_code = code.as_raw();
+        let call = || {
+            self.sdk_get_properties(|p| {
+                (p.code == target_code)
+                    .then(|| unsafe { (device_property_from_sdk(p), raw_values_bytes(p)) })
+            })
+            .map(|mut found| found.pop())
+        };
 
-            crsdk_sys::SCRSDK::ReleaseDeviceProperties(self.handle, properties_ptr);
-        }
+        #[cfg(feature = "record")]
+        let result = match &self.session {
+            Some(session) => session.get_property_debug(target_code, call, self.event_sender()),
+            None => call(),
+        };
+        #[cfg(not(feature = "record"))]
+        let result = call();
 
-        Ok(properties)
+        result
+            .map_err(Error::from_sdk_error)?
+            .ok_or(Error::PropertyNotSupported)
     }
 
     /// Set a property on the camera
@@ -407,25 +726,89 @@ impl CameraDevice {
             return Err(Error::InvalidPropertyValue);
         }
 
+        let result = self.sdk_set_property(code.as_raw(), 0, value);
+
+        if result != 0 {
+            return Err(Error::from_sdk_error(result));
+        }
+
+        if let Some(on_change) = &self.on_change {
+            on_change(ChangeRecord {
+                code,
+                old_value: prop.current_value,
+                new_value: value,
+                old_string: None,
+                new_string: None,
+                timestamp: SystemTime::now(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Get a sink that coalesces rapid writes to `code`
+    ///
+    /// For slider-driven controls: each `set()` on the sink replaces the
+    /// pending value, and only the latest is sent once writes stop for the
+    /// quiet period. See `DebouncedProperty`.
+    pub fn set_property_debounced(&self, code: DevicePropertyCode) -> DebouncedProperty<'_> {
+        DebouncedProperty::new(self, code)
+    }
+
+    /// Set a string property on the camera
+    ///
+    /// For properties whose data type is `DataType::String`. The `on_change`
+    /// hook reports these with `old_string` and `new_string` filled in.
+    #[async_wrap]
+    pub fn set_property_string(&self, code: DevicePropertyCode, value: &str) -> Result<()> {
+        let prop = self.get_property(code)?;
+        #[cfg(feature = "record")]
+        self.ensure_not_replaying("string properties")?;
+
+        if !prop.is_writable() {
+            return Err(Error::PropertyNotWritable);
+        }
+
+        // The SDK expects UTF-16 prefixed with its length including the
+        // terminating null
+        let mut buf: Vec<u16> = Vec::with_capacity(value.len() + 2);
+        buf.push(0);
+        buf.extend(value.encode_utf16());
+        buf.push(0);
+        buf[0] = u16::try_from(buf.len() - 1).map_err(|_| {
+            Error::InvalidParameter(format!("string too long ({} characters)", value.len()))
+        })?;
+
         let mut sdk_prop = crsdk_sys::SCRSDK::CrDeviceProperty {
             code: code.as_raw(),
-            valueType: 0,
+            valueType: crsdk_sys::SCRSDK::CrDataType_CrDataType_STR,
             enableFlag: 0,
             variableFlag: 0,
-            currentValue: value,
-            currentStr: ptr::null_mut(),
+            currentValue: 0,
+            currentStr: buf.as_mut_ptr(),
             valuesSize: 0,
             values: ptr::null_mut(),
             getSetValuesSize: 0,
             getSetValues: ptr::null_mut(),
         };
 
+        // SAFETY: buf outlives the call and the SDK copies the string
         let result = unsafe { crsdk_sys::SCRSDK::SetDeviceProperty(self.handle, &mut sdk_prop) };
 
         if result != 0 {
             return Err(Error::from_sdk_error(result as u32));
         }
 
+        if let Some(on_change) = &self.on_change {
+            on_change(ChangeRecord {
+                code,
+                old_value: prop.current_value,
+                new_value: 0,
+                old_string: prop.current_string,
+                new_string: Some(value.to_string()),
+                timestamp: SystemTime::now(),
+            });
+        }
         Ok(())
     }
 
@@ -459,6 +842,59 @@ impl CameraDevice {
         self.set_property(DevicePropertyCode::WhiteBalance, wb.to_raw())
     }
 
+    /// Get the manual white balance color temperature
+    #[async_wrap]
+    pub fn color_temperature(&self) -> Result<ColorTemperature> {
+        let prop = self.get_property(DevicePropertyCode::Colortemp)?;
+        ColorTemperature::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Set the manual white balance color temperature
+    ///
+    /// `kelvin` is snapped to the range and step the camera reports (e.g.
+    /// 5673K becomes 5700K on a body with 100K steps), and the value written
+    /// is returned. Only takes effect while white balance is
+    /// `WhiteBalance::ColorTemp`.
+    #[async_wrap]
+    pub fn set_color_temperature(&self, kelvin: u32) -> Result<ColorTemperature> {
+        let code = DevicePropertyCode::Colortemp;
+        let prop = self.get_property(code)?;
+        let snapped = prop.constraint.snap(kelvin as i64);
+        let value = ColorTemperature::from_raw(snapped.max(0) as u64).ok_or_else(|| {
+            Error::InvalidParameter(format!("invalid color temperature {}K", kelvin))
+        })?;
+        self.set_property(code, value.to_raw())?;
+        Ok(value)
+    }
+
+    /// Check whether auto white balance is locked
+    ///
+    /// Reads the AWB lock status, falling back to the AWB lock button state
+    /// on bodies that don't report the status separately.
+    #[async_wrap]
+    pub fn awb_locked(&self) -> Result<bool> {
+        let prop = match self.get_property(DevicePropertyCode::AWB) {
+            Err(Error::PropertyNotSupported) => self.get_property(DevicePropertyCode::AWBL)?,
+            result => result?,
+        };
+        Ok(LockIndicator::from_raw(prop.current_value) == Some(LockIndicator::Locked))
+    }
+
+    /// Lock or unlock auto white balance
+    ///
+    /// Holds the current AWB result so it can't drift mid-shot, like pressing
+    /// the AWB lock button on the camera. Only has an effect while white
+    /// balance is set to Auto.
+    #[async_wrap]
+    pub fn lock_awb(&self, locked: bool) -> Result<()> {
+        let state = if locked {
+            LockIndicator::Locked
+        } else {
+            LockIndicator::Unlocked
+        };
+        self.set_property(DevicePropertyCode::AWBL, state.to_raw())
+    }
+
     /// Get the current exposure program mode
     #[async_wrap]
     pub fn exposure_program(&self) -> Result<ExposureProgram> {
@@ -467,11 +903,69 @@ impl CameraDevice {
     }
 
     /// Set the exposure program mode
+    ///
+    /// Checks the mode against the ones the body currently offers first, so
+    /// e.g. a cine-only mode on a stills body fails with `Error::Unsupported`
+    /// listing the available modes.
+    ///
+    /// On cine bodies the modes on offer depend on the exposure control
+    /// type: flexible exposure modes (`MovieFMode` and friends) only under
+    /// `FlexibleExposure`, P/A/S/M modes only under `Pasm`. If `program`
+    /// needs the other control type it is switched first, waiting until the
+    /// camera has applied it so the programs offered are the new ones, and
+    /// switched back if the body still doesn't offer `program`.
     #[async_wrap]
     pub fn set_exposure_program(&self, program: ExposureProgram) -> Result<()> {
+        // Bodies without ExposureCtrlType offer every program they support
+        let switch_ctrl = self
+            .exposure_ctrl_type()
+            .ok()
+            .filter(|&ctrl| ctrl != program.exposure_ctrl_type());
+        if switch_ctrl.is_some() {
+            let code = DevicePropertyCode::ExposureCtrlType;
+            let ctrl = program.exposure_ctrl_type();
+            self.ensure_offered(code, ctrl, "exposure control type")?;
+            self.set_and_settle(code, ctrl.to_raw())?;
+        }
+
+        let offered = self.ensure_offered(
+            DevicePropertyCode::ExposureProgramMode,
+            program,
+            "exposure program",
+        );
+        if let Err(e) = offered {
+            if let Some(previous) = switch_ctrl {
+                if let Err(restore) = self.set_exposure_ctrl_type(previous) {
+                    log::warn!("Failed to restore exposure control type: {}", restore);
+                }
+            }
+            return Err(e);
+        }
         self.set_property(DevicePropertyCode::ExposureProgramMode, program.to_raw())
     }
 
+    /// Get the exposure control type (P/A/S/M or flexible exposure)
+    ///
+    /// Only cine bodies such as the FX6 report this; others fail with
+    /// `Error::PropertyNotSupported`.
+    #[async_wrap]
+    pub fn exposure_ctrl_type(&self) -> Result<ExposureCtrlType> {
+        let prop = self.get_property(DevicePropertyCode::ExposureCtrlType)?;
+        ExposureCtrlType::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Set the exposure control type
+    ///
+    /// Changes which exposure programs the body offers, see
+    /// `set_exposure_program()`. Does nothing if already set.
+    #[async_wrap]
+    pub fn set_exposure_ctrl_type(&self, ctrl: ExposureCtrlType) -> Result<()> {
+        let code = DevicePropertyCode::ExposureCtrlType;
+        self.ensure_offered(code, ctrl, "exposure control type")?;
+        self.set_if_changed(code, ctrl.to_raw())?;
+        Ok(())
+    }
+
     /// Get the current drive mode
     #[async_wrap]
     pub fn drive_mode(&self) -> Result<DriveMode> {
@@ -480,192 +974,2488 @@ impl CameraDevice {
     }
 
     /// Set the drive mode
+    ///
+    /// Checks the mode against the ones the body currently offers first, so
+    /// e.g. `ContinuousHiPlus` on a body without it fails with
+    /// `Error::Unsupported` listing the available modes.
     #[async_wrap]
     pub fn set_drive_mode(&self, mode: DriveMode) -> Result<()> {
+        self.ensure_offered(DevicePropertyCode::DriveMode, mode, "drive mode")?;
         self.set_property(DevicePropertyCode::DriveMode, mode.to_raw())
     }
 
-    /// Get the current metering mode
+    /// Get the current self-timer delay, `None` if the self-timer is off
     #[async_wrap]
-    pub fn metering_mode(&self) -> Result<MeteringMode> {
-        let prop = self.get_property(DevicePropertyCode::MeteringMode)?;
-        MeteringMode::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    pub fn self_timer(&self) -> Result<Option<Duration>> {
+        Ok(self.drive_mode()?.self_timer_delay())
     }
 
-    /// Set the metering mode
+    /// Arm or disarm the still self-timer
+    ///
+    /// The delay is part of the drive mode, so `Some(delay)` switches to the
+    /// matching timer drive mode and `None` switches back to single shooting
+    /// (leaving non-timer drive modes untouched). Returns the delays this body
+    /// supports; a delay it doesn't offer fails with `Error::Unsupported`.
     #[async_wrap]
-    pub fn set_metering_mode(&self, mode: MeteringMode) -> Result<()> {
-        self.set_property(DevicePropertyCode::MeteringMode, mode.to_raw())
+    pub fn set_self_timer(&self, delay: Option<Duration>) -> Result<Vec<Duration>> {
+        let prop = self.get_property(DevicePropertyCode::DriveMode)?;
+        let supported: Vec<Duration> = match prop.possible_values() {
+            Some(values) if !values.is_empty() => values
+                .iter()
+                .filter_map(|&raw| DriveMode::from_raw(raw)?.self_timer_delay())
+                .collect(),
+            _ => [DriveMode::Timer2s, DriveMode::Timer5s, DriveMode::Timer10s]
+                .iter()
+                .filter_map(|mode| mode.self_timer_delay())
+                .collect(),
+        };
+
+        let mode = match delay {
+            Some(delay) => match DriveMode::self_timer(delay) {
+                Some(mode) if supported.contains(&delay) => mode,
+                _ => {
+                    let available: Vec<String> = supported
+                        .iter()
+                        .map(|d| format!("{}s", d.as_secs()))
+                        .collect();
+                    return Err(Error::Unsupported(format!(
+                        "self-timer delay {:?} (available: {})",
+                        delay,
+                        available.join(", ")
+                    )));
+                }
+            },
+            None => {
+                let current = DriveMode::from_raw(prop.current_value);
+                if current.and_then(|m| m.self_timer_delay()).is_none() {
+                    return Ok(supported);
+                }
+                DriveMode::Single
+            }
+        };
+
+        self.set_property(DevicePropertyCode::DriveMode, mode.to_raw())?;
+        Ok(supported)
     }
 
-    /// Get the current flash mode
+    /// Whether a continuous-shooting spot boost is currently active
     #[async_wrap]
-    pub fn flash_mode(&self) -> Result<FlashMode> {
-        let prop = self.get_property(DevicePropertyCode::FlashMode)?;
-        FlashMode::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    pub fn spot_boost_status(&self) -> Result<SpotBoostStatus> {
+        let prop = self.get_property(DevicePropertyCode::ContinuousShootingSpotBoostStatus)?;
+        SpotBoostStatus::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
     }
 
-    /// Set the flash mode
+    /// Frame rate the body shoots at while a spot boost is active
     #[async_wrap]
-    pub fn set_flash_mode(&self, mode: FlashMode) -> Result<()> {
-        self.set_property(DevicePropertyCode::FlashMode, mode.to_raw())
+    pub fn spot_boost_frame_speed(&self) -> Result<u64> {
+        Ok(self
+            .get_property(DevicePropertyCode::ContinuousShootingSpotBoostFrameSpeed)?
+            .current_value)
     }
 
-    /// Get the current focus area
+    /// Start or end a continuous-shooting spot boost
+    ///
+    /// Works like holding the body's boost button: `true` presses it and
+    /// `false` releases it. Bodies that don't report
+    /// `ContinuousShootingSpotBoostEnableStatus` fail with
+    /// `Error::Unsupported` before anything is sent (see `send_command()`);
+    /// check `spot_boost_status()` for the result.
     #[async_wrap]
-    pub fn focus_area(&self) -> Result<FocusArea> {
-        let prop = self.get_property(DevicePropertyCode::FocusArea)?;
-        FocusArea::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    pub fn set_spot_boost(&self, enabled: bool) -> Result<()> {
+        let param = if enabled {
+            CommandParam::Down
+        } else {
+            CommandParam::Up
+        };
+        self.send_command(CommandId::ContinuousShootingSpotBoost, param)
     }
 
-    /// Set the focus area
+    /// Read the camera's clock
+    #[async_wrap]
+    pub fn datetime(&self) -> Result<CameraDateTime> {
+        let prop = self.get_property(DevicePropertyCode::DateTimeSettings)?;
+        prop.current_string
+            .as_deref()
+            .and_then(CameraDateTime::parse)
+            .ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Set the camera's clock, e.g. from an NTP-synced host at shoot start
+    ///
+    /// `utc_offset_minutes` is the offset of the local time the body should
+    /// display (e.g. `-300` for UTC-5). Fails with `Error::InvalidParameter`
+    /// for offsets beyond ±14 hours. Sub-second precision is dropped.
+    #[async_wrap]
+    pub fn set_datetime(&self, time: SystemTime, utc_offset_minutes: i32) -> Result<()> {
+        let datetime = CameraDateTime::new(time, utc_offset_minutes).ok_or_else(|| {
+            Error::InvalidParameter(format!("invalid UTC offset {} minutes", utc_offset_minutes))
+        })?;
+        self.set_property_string(DevicePropertyCode::DateTimeSettings, &datetime.to_string())
+    }
+
+    /// Get the current metering mode
+    #[async_wrap]
+    pub fn metering_mode(&self) -> Result<MeteringMode> {
+        let prop = self.get_property(DevicePropertyCode::MeteringMode)?;
+        MeteringMode::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Set the metering mode
+    ///
+    /// Checks the mode against the ones the body currently offers first, so
+    /// e.g. `HighLightWeighted` on a body without it fails with
+    /// `Error::Unsupported` listing the available modes.
+    #[async_wrap]
+    pub fn set_metering_mode(&self, mode: MeteringMode) -> Result<()> {
+        self.ensure_offered(DevicePropertyCode::MeteringMode, mode, "metering mode")?;
+        self.set_property(DevicePropertyCode::MeteringMode, mode.to_raw())
+    }
+
+    /// Get the current still image aspect ratio
+    #[async_wrap]
+    pub fn aspect_ratio(&self) -> Result<AspectRatio> {
+        let prop = self.get_property(DevicePropertyCode::AspectRatio)?;
+        AspectRatio::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Set the still image aspect ratio
+    ///
+    /// Live view follows the new framing. Ratios the body doesn't currently
+    /// offer fail with `Error::Unsupported` listing the available ones.
+    #[async_wrap]
+    pub fn set_aspect_ratio(&self, ratio: AspectRatio) -> Result<()> {
+        self.ensure_offered(DevicePropertyCode::AspectRatio, ratio, "aspect ratio")?;
+        self.set_property(DevicePropertyCode::AspectRatio, ratio.to_raw())
+    }
+
+    /// Set image stabilization for stills or movies
+    ///
+    /// Pick the variant matching what is being shot; see [`Stabilization`].
+    /// Modes the body doesn't currently offer fail with `Error::Unsupported`
+    /// listing the available ones.
+    #[async_wrap]
+    pub fn set_stabilization(&self, mode: Stabilization) -> Result<()> {
+        let code = mode.code();
+        match mode {
+            Stabilization::Still(v) => self.ensure_offered(code, v, "SteadyShot")?,
+            Stabilization::Movie(v) => self.ensure_offered(code, v, "movie SteadyShot")?,
+            Stabilization::MovieLevel(v) => {
+                self.ensure_offered(code, v, "movie SteadyShot level")?
+            }
+        }
+        self.set_property(code, mode.to_raw())
+    }
+
+    /// Get the focal length SteadyShot assumes, in mm
+    #[async_wrap]
+    pub fn stabilization_focal_length(&self) -> Result<u16> {
+        let prop =
+            self.get_property(DevicePropertyCode::ImageStabilizationSteadyShotFocalLength)?;
+        Ok(prop.current_value as u16)
+    }
+
+    /// Tell SteadyShot the focal length of the mounted lens, in mm
+    ///
+    /// Lenses without electronic contacts (e.g. adapted manual glass) don't
+    /// report their focal length, so stabilization needs it set by hand.
+    /// Values outside what the camera accepts fail with
+    /// `Error::InvalidParameter`.
+    #[async_wrap]
+    pub fn set_stabilization_focal_length(&self, focal_length_mm: u16) -> Result<()> {
+        let code = DevicePropertyCode::ImageStabilizationSteadyShotFocalLength;
+        let prop = self.get_property(code)?;
+        if !prop.constraint.is_valid(focal_length_mm as u64) {
+            let accepted = match prop.constraint.range_params() {
+                Some((min, max, _)) => format!(" (accepted: {}-{}mm)", min, max),
+                None => String::new(),
+            };
+            return Err(Error::InvalidParameter(format!(
+                "SteadyShot focal length {}mm{}",
+                focal_length_mm, accepted
+            )));
+        }
+        self.set_property(code, focal_length_mm as u64)
+    }
+
+    /// Get which ND filter preset (1-3) is selected
+    ///
+    /// Fails with `Error::InvalidPropertyValue` if the camera reports
+    /// something other than a preset number.
+    #[async_wrap]
+    pub fn nd_preset(&self) -> Result<u8> {
+        let prop = self.get_property(DevicePropertyCode::NDFilterPresetSelect)?;
+        match prop.current_value {
+            slot @ 1..=3 => Ok(slot as u8),
+            _ => Err(Error::InvalidPropertyValue),
+        }
+    }
+
+    /// Recall ND filter preset `slot` (1-3)
+    ///
+    /// The variable ND moves to the density stored in that preset. The
+    /// slot number is only written once the values the camera offers for
+    /// `NDFilterPresetSelect` are themselves preset numbers; a body that
+    /// encodes the selection differently fails with `Error::Unsupported`
+    /// listing what it offers, rather than getting a misread write. Waits
+    /// until the camera reports the preset selected.
+    #[async_wrap]
+    pub fn select_nd_preset(&self, slot: u8) -> Result<()> {
+        nd_preset_code(slot)?;
+        let code = DevicePropertyCode::NDFilterPresetSelect;
+        let prop = self.get_property(code)?;
+        let offered: Vec<u64> = match &prop.constraint {
+            ValueConstraint::Discrete(values) => values.clone(),
+            ValueConstraint::Range { min, max, .. } if *min >= 1 && *max <= 3 => {
+                (*min as u64..=*max as u64).collect()
+            }
+            _ => Vec::new(),
+        };
+        if offered.is_empty() || offered.iter().any(|v| !(1..=3).contains(v)) {
+            return Err(Error::Unsupported(format!(
+                "ND preset selection encoded as {:?}",
+                prop.constraint
+            )));
+        }
+        if !offered.contains(&(slot as u64)) {
+            return Err(Error::InvalidParameter(format!(
+                "ND preset {} (offered: {:?})",
+                slot, offered
+            )));
+        }
+        self.set_and_settle(code, slot as u64)
+    }
+
+    /// Get the density stored in ND filter preset `slot` (1-3)
+    ///
+    /// Raw value, in the same unit as `NDFilterValue`.
+    #[async_wrap]
+    pub fn nd_preset_value(&self, slot: u8) -> Result<u64> {
+        Ok(self.get_property(nd_preset_code(slot)?)?.current_value)
+    }
+
+    /// Store `density` in ND filter preset `slot` (1-3)
+    ///
+    /// `density` is a raw value in the same unit as `NDFilterValue`;
+    /// values outside what the camera accepts fail with
+    /// `Error::InvalidParameter`.
+    #[async_wrap]
+    pub fn set_nd_preset(&self, slot: u8, density: u64) -> Result<()> {
+        let code = nd_preset_code(slot)?;
+        let prop = self.get_property(code)?;
+        if !prop.constraint.is_valid(density) {
+            return Err(Error::InvalidParameter(format!(
+                "ND preset {} density {}",
+                slot, density
+            )));
+        }
+        self.set_property(code, density)
+    }
+
+    /// Get the current flash mode
+    #[async_wrap]
+    pub fn flash_mode(&self) -> Result<FlashMode> {
+        let prop = self.get_property(DevicePropertyCode::FlashMode)?;
+        FlashMode::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Set the flash mode
+    #[async_wrap]
+    pub fn set_flash_mode(&self, mode: FlashMode) -> Result<()> {
+        self.set_property(DevicePropertyCode::FlashMode, mode.to_raw())
+    }
+
+    /// Get the current flash exposure compensation
+    #[async_wrap]
+    pub fn flash_compensation(&self) -> Result<ExposureComp> {
+        let prop = self.get_property(DevicePropertyCode::FlashCompensation)?;
+        ExposureComp::from_raw(prop.current_signed() as u64).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Set the flash exposure compensation in EV
+    ///
+    /// `ev` is snapped to the nearest 1/3 step. Values outside the range the
+    /// camera reports fail with `Error::InvalidParameter`. Returns the value
+    /// that was written.
+    #[async_wrap]
+    pub fn set_flash_compensation(&self, ev: f32) -> Result<ExposureComp> {
+        if !ev.is_finite() {
+            return Err(Error::InvalidParameter(format!(
+                "invalid flash compensation {}",
+                ev
+            )));
+        }
+        let value = ExposureComp::from_ev(ev as f64);
+        let target = value.raw_value();
+
+        let code = DevicePropertyCode::FlashCompensation;
+        let prop = self.get_property(code)?;
+        let raw = match &prop.constraint {
+            ValueConstraint::Range { min, max, .. } => {
+                if target < *min || target > *max {
+                    return Err(Error::InvalidParameter(format!(
+                        "flash compensation {} EV is outside {}..{} EV",
+                        value,
+                        ExposureComp::from_raw(*min as u64).unwrap_or(value),
+                        ExposureComp::from_raw(*max as u64).unwrap_or(value)
+                    )));
+                }
+                target as u64
+            }
+            // Discrete values may be zero-extended, so compare them signed
+            ValueConstraint::Discrete(values) if !values.is_empty() => values
+                .iter()
+                .copied()
+                .find(|&raw| prop.data_type.to_signed(raw) == target)
+                .ok_or_else(|| {
+                    let available: Vec<String> = values
+                        .iter()
+                        .filter_map(|&raw| {
+                            ExposureComp::from_raw(prop.data_type.to_signed(raw) as u64)
+                        })
+                        .map(|v| v.to_string())
+                        .collect();
+                    Error::InvalidParameter(format!(
+                        "flash compensation {} EV is not offered (available: {})",
+                        value,
+                        available.join(", ")
+                    ))
+                })?,
+            _ => target as u64,
+        };
+
+        self.set_property(code, raw)?;
+        Ok(value)
+    }
+
+    /// Get the current focus area
+    #[async_wrap]
+    pub fn focus_area(&self) -> Result<FocusArea> {
+        let prop = self.get_property(DevicePropertyCode::FocusArea)?;
+        FocusArea::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Set the focus area
     #[async_wrap]
     pub fn set_focus_area(&self, area: FocusArea) -> Result<()> {
         self.set_property(DevicePropertyCode::FocusArea, area.to_raw())
     }
 
-    /// Get the current ISO sensitivity (raw value)
+    /// Get the current AI subject recognition target
+    #[async_wrap]
+    pub fn subject_target(&self) -> Result<SubjectTarget> {
+        let prop = self.get_property(DevicePropertyCode::RecognitionTarget)?;
+        SubjectTarget::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Set the AI subject recognition target and turn recognition on or off
+    ///
+    /// The target is checked against the body's
+    /// `AutoRecognitionTargetCandidates`, failing with `Error::Unsupported`
+    /// if it isn't one of them; bodies that don't report candidates leave
+    /// the check to the camera. When enabling, recognition is switched to
+    /// Priority AF only if it is currently off, so an existing "Only AF"
+    /// choice is kept. When disabling, the target is left as is.
+    #[async_wrap]
+    pub fn set_subject_recognition(&self, target: SubjectTarget, enabled: bool) -> Result<()> {
+        if !enabled {
+            return self.set_property(
+                DevicePropertyCode::SubjectRecognitionAF,
+                SubjectRecognitionAF::Off.to_raw(),
+            );
+        }
+
+        match self.get_property(DevicePropertyCode::AutoRecognitionTargetCandidates) {
+            Ok(candidates) => candidates.check_offered(target, "recognition target")?,
+            Err(Error::PropertyNotSupported) => {}
+            Err(e) => return Err(e),
+        }
+        self.set_property(DevicePropertyCode::RecognitionTarget, target.to_raw())?;
+
+        let current = self.get_property(DevicePropertyCode::SubjectRecognitionAF)?;
+        if SubjectRecognitionAF::from_raw(current.current_value) == Some(SubjectRecognitionAF::Off)
+        {
+            self.set_property(
+                DevicePropertyCode::SubjectRecognitionAF,
+                SubjectRecognitionAF::PriorityAF.to_raw(),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Get whether recognition AF prefers faces registered on the body
+    ///
+    /// Faces are registered in the camera menu. The SDK only exposes this
+    /// on/off switch, not the list of registered faces or their order.
+    #[async_wrap]
+    pub fn registered_face_priority(&self) -> Result<bool> {
+        let prop =
+            self.get_property(DevicePropertyCode::SubjectRecognitionPriorityOnRegisteredFace)?;
+        Ok(Switch::from_raw(prop.current_value) == Some(Switch::On))
+    }
+
+    /// Make recognition AF prefer faces registered on the body
+    #[async_wrap]
+    pub fn set_registered_face_priority(&self, enabled: bool) -> Result<()> {
+        let value = if enabled { Switch::On } else { Switch::Off };
+        self.set_property(
+            DevicePropertyCode::SubjectRecognitionPriorityOnRegisteredFace,
+            value.to_raw(),
+        )
+    }
+
+    /// Get the current still image compression format (JPEG or HEIF)
+    #[async_wrap]
+    pub fn compression_format(&self) -> Result<CompressionFileFormat> {
+        let prop = self.get_property(DevicePropertyCode::CompressionFileFormatStill)?;
+        CompressionFileFormat::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Set the still image compression format
+    ///
+    /// Checks the format against the ones the body offers first, so choosing
+    /// HEIF on a JPEG-only camera fails with `Error::Unsupported` instead of
+    /// being rejected by the SDK.
+    #[async_wrap]
+    pub fn set_compression_format(&self, format: CompressionFileFormat) -> Result<()> {
+        self.ensure_offered(
+            DevicePropertyCode::CompressionFileFormatStill,
+            format,
+            "compression format",
+        )?;
+        self.set_property(
+            DevicePropertyCode::CompressionFileFormatStill,
+            format.to_raw(),
+        )
+    }
+
+    /// Get the current RAW compression type
+    ///
+    /// Bodies that set RAW compression per card slot report slot 1's.
+    #[async_wrap]
+    pub fn raw_compression(&self) -> Result<RAWFileCompressionType> {
+        let prop = match self.get_property(DevicePropertyCode::RAWFileCompressionType) {
+            Err(Error::PropertyNotSupported) => {
+                self.get_property(DevicePropertyCode::MediaSLOT1RAWFileCompressionType)?
+            }
+            other => other?,
+        };
+        RAWFileCompressionType::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Set the RAW compression type
+    ///
+    /// Bodies that set RAW compression per card slot get it on every slot.
+    /// Types the body doesn't currently offer fail with `Error::Unsupported`
+    /// listing the available ones.
+    #[async_wrap]
+    pub fn set_raw_compression(&self, compression: RAWFileCompressionType) -> Result<()> {
+        let code = DevicePropertyCode::RAWFileCompressionType;
+        match self.ensure_offered(code, compression, "RAW compression") {
+            Ok(()) => return self.set_property(code, compression.to_raw()),
+            Err(Error::PropertyNotSupported) => {}
+            Err(e) => return Err(e),
+        }
+
+        let slot_codes = [
+            DevicePropertyCode::MediaSLOT1RAWFileCompressionType,
+            DevicePropertyCode::MediaSLOT2RAWFileCompressionType,
+        ];
+        let mut supported = false;
+        for code in slot_codes {
+            match self.ensure_offered(code, compression, "RAW compression") {
+                Ok(()) => {}
+                Err(Error::PropertyNotSupported) => continue,
+                Err(e) => return Err(e),
+            }
+            supported = true;
+            self.set_property(code, compression.to_raw())?;
+        }
+        if supported {
+            Ok(())
+        } else {
+            Err(Error::PropertyNotSupported)
+        }
+    }
+
+    /// Get the current ISO sensitivity (raw value)
+    #[async_wrap]
+    pub fn iso(&self) -> Result<u64> {
+        let prop = self.get_property(DevicePropertyCode::IsoSensitivity)?;
+        Ok(prop.current_value)
+    }
+
+    /// Set the ISO sensitivity
+    #[async_wrap]
+    pub fn set_iso(&self, value: u64) -> Result<()> {
+        self.set_property(DevicePropertyCode::IsoSensitivity, value)
+    }
+
+    /// Get whether the camera shows gain as ISO or in dB
+    #[async_wrap]
+    pub fn gain_unit(&self) -> Result<GainUnitSetting> {
+        let prop = self.get_property(DevicePropertyCode::GainUnitSetting)?;
+        GainUnitSetting::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Get the current gain in the unit the camera is set to display
+    ///
+    /// Reads `GaindBValue` when `GainUnitSetting` is dB and `IsoSensitivity`
+    /// otherwise. Bodies without `GainUnitSetting` always report ISO.
+    #[async_wrap]
+    pub fn gain(&self) -> Result<Gain> {
+        let unit = match self.gain_unit() {
+            Ok(unit) => unit,
+            Err(Error::PropertyNotSupported) => GainUnitSetting::ISO,
+            Err(e) => return Err(e),
+        };
+        let raw = |code| self.get_property(code).map(|p| p.current_value);
+        let (iso, db) = match unit {
+            GainUnitSetting::ISO => (Some(raw(DevicePropertyCode::IsoSensitivity)?), None),
+            GainUnitSetting::DB => (None, Some(raw(DevicePropertyCode::GaindBValue)?)),
+        };
+        Gain::from_raw_parts(unit, iso, db).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Get the current aperture/f-number (raw SDK value)
+    #[async_wrap]
+    pub fn aperture(&self) -> Result<u64> {
+        let prop = self.get_property(DevicePropertyCode::FNumber)?;
+        Ok(prop.current_value)
+    }
+
+    /// Set the aperture/f-number
+    #[async_wrap]
+    pub fn set_aperture(&self, value: u64) -> Result<()> {
+        self.set_property(DevicePropertyCode::FNumber, value)
+    }
+
+    /// Get whether program shift is active, with the shifted exposure pair
+    #[async_wrap]
+    pub fn program_shift_status(&self) -> Result<ProgramShift> {
+        ProgramShift::from_properties(&self.get_all_properties_current()?)
+            .ok_or(Error::PropertyNotSupported)
+    }
+
+    /// Shift the P mode exposure pair by `steps` aperture stops
+    ///
+    /// The SDK has no program-shift command, so this moves `FNumber` by
+    /// `steps` of the f-numbers the lens offers (positive stops down,
+    /// negative opens up, clamped at either end) and leaves the shutter to
+    /// the camera. That only works on bodies that report `FNumber` writable
+    /// in P mode; elsewhere it fails with `Error::PropertyNotWritable`
+    /// before writing anything. Read `program_shift_status()` afterwards to
+    /// see whether the camera treated the write as a shift. Fails with
+    /// `Error::InvalidState` outside P mode.
+    #[async_wrap]
+    pub fn program_shift(&self, steps: i8) -> Result<()> {
+        let program = self.exposure_program()?;
+        if program != ExposureProgram::ProgramAuto {
+            return Err(Error::InvalidState(format!(
+                "program shift needs P mode (currently {})",
+                program
+            )));
+        }
+        let prop = self.get_property(DevicePropertyCode::FNumber)?;
+        if !prop.is_writable() {
+            return Err(Error::PropertyNotWritable);
+        }
+        let mut apertures: Vec<u64> = prop
+            .possible_values()
+            .unwrap_or_default()
+            .iter()
+            .copied()
+            .filter(|&v| v != 0 && v < 0xFFFD)
+            .collect();
+        apertures.sort_unstable();
+        let current = apertures
+            .iter()
+            .position(|&v| v == prop.current_value)
+            .ok_or_else(|| {
+                Error::Unsupported("program shift without a list of apertures".to_string())
+            })?;
+        let target = (current as i64 + steps as i64).clamp(0, apertures.len() as i64 - 1);
+        self.set_if_changed(DevicePropertyCode::FNumber, apertures[target as usize])?;
+        Ok(())
+    }
+
+    /// Get the widest and narrowest f-numbers the lens allows right now
+    ///
+    /// Read from the `FNumber` constraint, which the camera updates as the
+    /// lens zooms, so on variable-aperture zooms the wide-open value follows
+    /// the focal length. Call again after a zoom (e.g. on a `FocalPosition`
+    /// change) rather than caching the result.
+    #[async_wrap]
+    pub fn aperture_range(&self) -> Result<(f64, f64)> {
+        let prop = self.get_property(DevicePropertyCode::FNumber)?;
+        let (wide, narrow) =
+            Aperture::limits(&prop.constraint).ok_or(Error::InvalidPropertyValue)?;
+        Ok((wide.f_number(), narrow.f_number()))
+    }
+
+    /// Get the shutter in the unit the camera is showing: a speed, or an
+    /// angle when `shutter_mode()` is `Angle`
+    #[async_wrap]
+    pub fn shutter(&self) -> Result<Shutter> {
+        Shutter::from_properties(&self.get_all_properties_current()?)
+            .ok_or(Error::PropertyNotSupported)
+    }
+
+    /// Get whether shutter is set as a speed or an angle
+    #[async_wrap]
+    pub fn shutter_mode(&self) -> Result<ShutterMode> {
+        let prop = self.get_property(DevicePropertyCode::ShutterMode)?;
+        ShutterMode::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Choose whether shutter is set as a speed or an angle
+    ///
+    /// Angles keep motion blur constant across frame rates. Only cine and
+    /// movie-oriented bodies offer this; others fail with
+    /// `Error::PropertyNotSupported`.
+    #[async_wrap]
+    pub fn set_shutter_mode(&self, mode: ShutterMode) -> Result<()> {
+        let code = DevicePropertyCode::ShutterMode;
+        self.ensure_offered(code, mode, "shutter mode")?;
+        self.set_if_changed(code, mode.to_raw())?;
+        Ok(())
+    }
+
+    /// Get the current shutter speed (raw SDK value)
+    #[async_wrap]
+    pub fn shutter_speed(&self) -> Result<u64> {
+        let prop = self.get_property(DevicePropertyCode::ShutterSpeed)?;
+        Ok(prop.current_value)
+    }
+
+    /// Set the shutter speed
+    #[async_wrap]
+    pub fn set_shutter_speed(&self, value: u64) -> Result<()> {
+        self.set_property(DevicePropertyCode::ShutterSpeed, value)
+    }
+
+    /// Configure S&Q (slow & quick) motion in one call
+    ///
+    /// Turns S&Q mode on, then sets the recording frame rate
+    /// (`SQRecordingFrameRateSetting`) and the capture frame rate
+    /// (`SQFrameRate`). Each rate is checked against the values the body
+    /// offers at that point, so an unsupported combination fails with
+    /// `Error::InvalidParameter` listing what is available.
+    ///
+    /// Returns the effective slow-motion ratio `capture_fps / record_fps`,
+    /// e.g. `5.0` for 120/24. Ratios below 1.0 mean quick motion.
+    #[async_wrap]
+    pub fn configure_sq(&self, capture_fps: u32, record_fps: u32) -> Result<f64> {
+        if capture_fps == 0 || record_fps == 0 {
+            return Err(Error::InvalidParameter(
+                "S&Q frame rates must be non-zero".to_string(),
+            ));
+        }
+
+        let mode = self.get_property(DevicePropertyCode::SQModeSetting)?;
+        if Switch::from_raw(mode.current_value) != Some(Switch::On) {
+            self.set_property(DevicePropertyCode::SQModeSetting, Switch::On.to_raw())?;
+        }
+
+        // Available rates depend on the mode, so read them after enabling S&Q
+        let record_prop = self.get_property(DevicePropertyCode::SQRecordingFrameRateSetting)?;
+        let record_rates: Vec<RecordingFrameRate> = record_prop
+            .possible_values()
+            .unwrap_or(&[])
+            .iter()
+            .filter_map(|&raw| RecordingFrameRate::from_raw(raw))
+            .collect();
+        let record_rate = record_rates
+            .iter()
+            .copied()
+            .find(|rate| rate.nominal_fps() == record_fps)
+            .ok_or_else(|| {
+                let supported: Vec<String> = record_rates.iter().map(|r| r.to_string()).collect();
+                Error::InvalidParameter(format!(
+                    "S&Q recording at {}p is not supported (available: {})",
+                    record_fps,
+                    supported.join(", ")
+                ))
+            })?;
+        self.set_property(
+            DevicePropertyCode::SQRecordingFrameRateSetting,
+            record_rate.to_raw(),
+        )?;
+
+        // The capture rates on offer depend on the recording rate just set
+        let capture_prop = self.get_property(DevicePropertyCode::SQFrameRate)?;
+        if !capture_prop.is_valid_value(capture_fps as u64) {
+            let supported: Vec<String> = capture_prop
+                .possible_values()
+                .unwrap_or(&[])
+                .iter()
+                .map(|fps| fps.to_string())
+                .collect();
+            return Err(Error::InvalidParameter(format!(
+                "S&Q capture at {}fps is not supported with {} recording (available: {})",
+                capture_fps,
+                record_rate,
+                supported.join(", ")
+            )));
+        }
+        self.set_property(DevicePropertyCode::SQFrameRate, capture_fps as u64)?;
+
+        Ok(capture_fps as f64 / record_fps as f64)
+    }
+
+    /// Get the current video mode (file format, frame rate and setting)
+    #[async_wrap]
+    pub fn video_mode(&self) -> Result<VideoMode> {
+        VideoMode::from_properties(&self.get_all_properties()?).ok_or(Error::PropertyNotSupported)
+    }
+
+    /// Get the main recording resolution and frame rate
+    ///
+    /// Read from a single property snapshot; prints as e.g. "UHD 23.98p".
+    #[async_wrap]
+    pub fn video_format(&self) -> Result<VideoFormat> {
+        Ok(VideoFormat::from_properties(
+            &self.get_all_properties_current()?,
+        ))
+    }
+
+    /// List the video modes available without changing format or frame rate
+    ///
+    /// The camera only reports the frame rates valid for the selected format
+    /// and the settings valid for the selected rate, so from a single
+    /// snapshot this is the current format and rate with each recording
+    /// setting on offer. Use `probe_video_modes()` for every combination.
+    #[async_wrap]
+    pub fn video_modes(&self) -> Result<Vec<VideoMode>> {
+        let props = self.get_all_properties()?;
+        let current = VideoMode::from_properties(&props).ok_or(Error::PropertyNotSupported)?;
+        let settings = props
+            .iter()
+            .find(|p| p.code == DevicePropertyCode::MovieRecordingSetting.as_raw())
+            .and_then(|p| p.possible_values())
+            .filter(|values| !values.is_empty())
+            .map(|values| {
+                values
+                    .iter()
+                    .filter_map(|&raw| MovieQuality::from_raw(raw))
+                    .collect()
+            })
+            .unwrap_or_else(|| vec![current.setting]);
+        Ok(settings
+            .into_iter()
+            .map(|setting| VideoMode { setting, ..current })
+            .collect())
+    }
+
+    /// List every video mode the camera accepts
+    ///
+    /// Unlike `video_modes()`, this changes the camera's settings: it walks
+    /// through each format and rate in turn, reading what the camera offers
+    /// once each change has taken effect, and restores the original mode
+    /// when done. Don't call it while recording.
+    #[async_wrap]
+    pub fn probe_video_modes(&self) -> Result<Vec<VideoMode>> {
+        let original = self.video_mode()?;
+        let mut modes = Vec::new();
+
+        let result = self.collect_video_modes(&mut modes);
+        let restored = self.apply_video_mode(original);
+        result?;
+        restored?;
+        Ok(modes)
+    }
+
+    /// Switch to a video mode in one step
+    ///
+    /// Writes the format, frame rate and setting in dependency order,
+    /// checking each against what the camera offers at that point. If any
+    /// step fails, the previous mode is restored and the error returned
+    /// (`Error::Unsupported` for a combination the camera doesn't offer).
+    #[async_wrap]
+    pub fn set_video_mode(&self, mode: VideoMode) -> Result<()> {
+        let previous = self.video_mode()?;
+        let result = self.apply_video_mode(mode);
+        if result.is_err() && previous != mode {
+            if let Err(e) = self.apply_video_mode(previous) {
+                log::warn!("Failed to restore video mode {}: {}", previous, e);
+            }
+        }
+        result
+    }
+
+    /// Read every adjustable parameter of a picture profile
+    ///
+    /// The camera only reports parameters for the active profile, so this
+    /// selects `pp` first (leaving it selected) and then reads everything in
+    /// a single property fetch instead of one round trip per parameter.
+    #[async_wrap]
+    pub fn picture_profile(&self, pp: PictureProfile) -> Result<PictureProfileSettings> {
+        self.select_picture_profile(pp)?;
+        let properties = self.get_all_properties()?;
+        Ok(PictureProfileSettings::from_properties(&properties))
+    }
+
+    /// Write picture profile parameters, e.g. to copy a look from another body
+    ///
+    /// Selects `pp` and writes every `Some` field of `settings` in dependency
+    /// order. Parameters this body doesn't expose (or that are locked by the
+    /// current mode) are skipped, as are values that already match.
+    #[async_wrap]
+    pub fn apply_picture_profile(
+        &self,
+        pp: PictureProfile,
+        settings: PictureProfileSettings,
+    ) -> Result<()> {
+        self.select_picture_profile(pp)?;
+        self.apply_raw_values(settings.to_raw_values())
+    }
+
+    /// Read the active creative look and all of its adjustments
+    ///
+    /// Everything comes from a single property fetch, so the look and its
+    /// adjustments are guaranteed to be consistent with each other.
+    #[async_wrap]
+    pub fn creative_look(&self) -> Result<CreativeLookSettings> {
+        Ok(CreativeLookSettings::from_properties(
+            &self.get_all_properties_current()?,
+        ))
+    }
+
+    /// Write a creative look, e.g. to match another body
+    ///
+    /// Selects the look first and then writes every `Some` adjustment.
+    /// Adjustments this body doesn't expose are skipped, as are values that
+    /// already match.
+    #[async_wrap]
+    pub fn apply_creative_look(&self, settings: &CreativeLookSettings) -> Result<()> {
+        if let Some(look) = settings.look {
+            self.set_if_changed(DevicePropertyCode::CreativeLook, look.to_raw())?;
+        }
+        let adjustments = CreativeLookSettings {
+            look: None,
+            ..settings.clone()
+        };
+        self.apply_raw_values(adjustments.to_raw_values())
+    }
+
+    /// Write raw values in order, skipping unchanged and unwritable ones
+    ///
+    /// Re-reads the current values first, so this must run after any write
+    /// that changes which parameters are active.
+    fn apply_raw_values(&self, values: Vec<(DevicePropertyCode, u64)>) -> Result<()> {
+        let current = self.get_all_properties()?;
+
+        for (code, value) in values {
+            let Some(prop) = current.iter().find(|p| p.code == code.as_raw()) else {
+                continue;
+            };
+            if prop.data_type.to_signed(prop.current_value) == prop.data_type.to_signed(value) {
+                continue;
+            }
+            match self.set_property(code, value) {
+                Ok(()) => {}
+                Err(Error::PropertyNotSupported | Error::PropertyNotWritable) => {
+                    log::debug!("Skipping {} (not writable on this body)", code.name());
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Apply every value in `preset`, in order
+    ///
+    /// Each property is read again just before it is written, so a value an
+    /// earlier entry changed as a side effect is compared correctly.
+    /// Properties already at the preset's value, missing from this body or
+    /// read-only are skipped; a value the camera refuses is recorded in the
+    /// report and the rest of the preset is still applied. Only fails as a
+    /// whole with `Error::NotConnected`.
+    #[async_wrap]
+    pub fn apply_preset(&self, preset: &Preset) -> Result<PresetReport> {
+        self.ensure_connected()?;
+        let mut report = PresetReport::default();
+
+        for entry in &preset.values {
+            let code = entry.code;
+            match self.set_if_changed(code, entry.value) {
+                Ok(true) => report.applied.push(code),
+                Ok(false) | Err(Error::PropertyNotSupported | Error::PropertyNotWritable) => {
+                    report.skipped.push(code)
+                }
+                Err(e) => {
+                    log::debug!("Preset {}: {} failed: {}", preset.name, code.name(), e);
+                    report.failed.push((code, e));
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Write several properties, each independently of the others
+    ///
+    /// Best effort: every change is attempted in order even if earlier ones
+    /// fail, and the outcome of each is returned in the same order. Use
+    /// `apply_preset()` to also skip values that are already set.
+    #[async_wrap]
+    pub fn set_properties(
+        &self,
+        changes: &[(DevicePropertyCode, u64)],
+    ) -> Vec<(DevicePropertyCode, Result<()>)> {
+        changes
+            .iter()
+            .map(|&(code, value)| {
+                let result = self.set_property(code, value);
+                if let Err(e) = &result {
+                    log::debug!("Setting {} to {} failed: {}", code.name(), value, e);
+                }
+                (code, result)
+            })
+            .collect()
+    }
+
+    /// Make `pp` the active picture profile (needed before reading/writing it)
+    fn select_picture_profile(&self, pp: PictureProfile) -> Result<()> {
+        if pp == PictureProfile::Off {
+            return Err(Error::InvalidParameter(
+                "picture profile Off has no parameters".to_string(),
+            ));
+        }
+        let prop = self.get_property(DevicePropertyCode::PictureProfile)?;
+        if prop.current_value != pp.to_raw() {
+            self.set_property(DevicePropertyCode::PictureProfile, pp.to_raw())?;
+        }
+        Ok(())
+    }
+
+    /// Get the current zoom position, from 0.0 (wide) to 1.0 (tele)
+    ///
+    /// `ZoomPositionCurrentValue` is normalized against the range reported by
+    /// `ZoomPositionSetting`, so the same scale works across lenses.
+    #[async_wrap]
+    pub fn zoom_position(&self) -> Result<f32> {
+        let range = self.get_property(DevicePropertyCode::ZoomPositionSetting)?;
+        let current = self.get_property(DevicePropertyCode::ZoomPositionCurrentValue)?;
+        range
+            .constraint
+            .normalize(current.data_type.to_signed(current.current_value))
+            .ok_or(Error::PropertyNotSupported)
+    }
+
+    /// Zoom to a position from 0.0 (wide) to 1.0 (tele)
+    ///
+    /// Uses the same normalized scale as `zoom_position()`. Out-of-range
+    /// positions are clamped, and the result is snapped to the lens's step.
+    #[async_wrap]
+    pub fn zoom_to(&self, position: f32) -> Result<()> {
+        let range = self.get_property(DevicePropertyCode::ZoomPositionSetting)?;
+        if range.range_params().is_none() {
+            return Err(Error::PropertyNotSupported);
+        }
+        let value = range.constraint.denormalize(position).ok_or_else(|| {
+            Error::InvalidParameter(format!("invalid zoom position {}", position))
+        })?;
+        self.set_property(DevicePropertyCode::ZoomPositionSetting, value as u64)
+    }
+
+    /// Start or stop continuous zoom
+    ///
+    /// The lens keeps moving in the given direction until this is called
+    /// again with `ZoomOperation::Stop`, so callers implementing
+    /// press-and-hold must always send the stop.
+    #[async_wrap]
+    pub fn drive_zoom(&self, direction: ZoomOperation) -> Result<()> {
+        self.set_signed_property(DevicePropertyCode::ZoomOperation, direction as i8 as i64)
+    }
+
+    /// Start or stop continuous manual focus drive
+    ///
+    /// `FocusOperation::Wide` drives toward near and `Tele` toward far.
+    /// Like `drive_zoom()`, the motor runs until `FocusOperation::Stop` is
+    /// sent. Requires manual focus.
+    #[async_wrap]
+    pub fn drive_focus(&self, direction: FocusOperation) -> Result<()> {
+        self.set_signed_property(DevicePropertyCode::FocusOperation, direction as i8 as i64)
+    }
+
+    /// Compute the depth of field from the current lens and focus settings
+    ///
+    /// Reads focal length, aperture and focus distance from one property
+    /// snapshot and uses this model's circle of confusion, adjusted for
+    /// Super 35mm crop. Fails with `Error::InvalidState` if the lens doesn't
+    /// report all three, e.g. a manual lens without electronic contacts.
+    #[async_wrap]
+    pub fn depth_of_field(&self) -> Result<DepthOfField> {
+        DepthOfField::from_properties(&self.get_all_properties_current()?, self.model).ok_or_else(
+            || {
+                Error::InvalidState(
+                    "lens doesn't report focal length, aperture and focus distance".to_string(),
+                )
+            },
+        )
+    }
+
+    /// Switch between full frame and APS-C/Super 35mm crop
+    ///
+    /// Writes `APSCS35` and waits for the camera to apply it. Returns the
+    /// crop state the camera settled on, which differs from `mode` when the
+    /// camera overrides the request (e.g. an APS-C lens forcing crop with
+    /// `Off` requested). For `Auto`, bodies that report `ImagerScanMode`
+    /// return the resolved `On`/`Off`.
+    ///
+    /// Fails with `Error::InvalidState` if the camera currently doesn't
+    /// allow switching.
+    #[async_wrap]
+    pub fn set_crop_mode(&self, mode: APSC_S35) -> Result<APSC_S35> {
+        // CrAPSCOrFullSwitchingEnableStatus: 0x00 = Disable, 0x01 = Enable
+        if let Ok(prop) = self.get_property(DevicePropertyCode::APSCOrFullSwitchingEnableStatus) {
+            if prop.current_value != 0x01 {
+                return Err(Error::InvalidState(
+                    "APS-C/full frame switching is not available right now".to_string(),
+                ));
+            }
+        }
+
+        self.set_property(DevicePropertyCode::APSCS35, mode.to_raw())?;
+
+        let read_setting = || -> Result<Option<APSC_S35>> {
+            let prop = self.get_property(DevicePropertyCode::APSCS35)?;
+            Ok(APSC_S35::from_raw(prop.current_value))
+        };
+        let deadline = Instant::now() + CROP_SETTLE_TIMEOUT;
+        let mut setting = read_setting()?;
+        while setting != Some(mode) && Instant::now() < deadline {
+            std::thread::sleep(CROP_POLL_INTERVAL);
+            setting = read_setting()?;
+        }
+
+        // The scan mode reflects what the sensor is actually doing, which a
+        // lens can force regardless of the setting
+        let scan_mode = self
+            .get_property(DevicePropertyCode::ImagerScanMode)
+            .ok()
+            .and_then(|p| ImagerScanMode::from_raw(p.current_value));
+        let effective = match (scan_mode, setting) {
+            (Some(ImagerScanMode::FullFrame), _) => APSC_S35::Off,
+            (Some(ImagerScanMode::Super35mm), _) => APSC_S35::On,
+            (_, Some(setting)) => setting,
+            (_, None) => return Err(Error::InvalidPropertyValue),
+        };
+
+        if mode != APSC_S35::Auto && effective != mode {
+            log::warn!(
+                "Requested crop mode {} but camera is using {}",
+                mode,
+                effective
+            );
+        }
+        Ok(effective)
+    }
+
+    // -------------------------------------------------------------------------
+    // Live view
+    // -------------------------------------------------------------------------
+
+    /// Whether the camera is delivering live view
+    ///
+    /// A viewer whose picture has frozen can check this to tell a camera
+    /// that turned live view off (e.g. while playing back) from one that
+    /// has simply stopped sending frames; `LiveViewRate` measures the latter.
+    #[async_wrap]
+    pub fn live_view_status(&self) -> Result<LiveViewStatus> {
+        let prop = self.get_property(DevicePropertyCode::LiveViewStatus)?;
+        LiveViewStatus::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Get the current live view image quality
+    #[async_wrap]
+    pub fn live_view_quality(&self) -> Result<LiveViewQuality> {
+        let prop = self.get_property(DevicePropertyCode::LiveViewImageQuality)?;
+        LiveViewQuality::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Set the live view image quality
+    ///
+    /// Frames fetched with `get_live_view()` afterwards are delivered at the
+    /// selected size. Bodies that only expose
+    /// `LiveViewImageQualityByNumericalValue` get its lowest value for
+    /// `Low` and its highest for `High`.
+    #[async_wrap]
+    pub fn set_live_view_quality(&self, quality: LiveViewQuality) -> Result<()> {
+        match self.set_property(DevicePropertyCode::LiveViewImageQuality, quality.to_raw()) {
+            Err(Error::PropertyNotSupported) => {}
+            other => return other,
+        }
+
+        let code = DevicePropertyCode::LiveViewImageQualityByNumericalValue;
+        let prop = self.get_property(code)?;
+        let value = match (&prop.constraint, quality) {
+            (ValueConstraint::Range { min, .. }, LiveViewQuality::Low) => *min as u64,
+            (ValueConstraint::Range { max, .. }, LiveViewQuality::High) => *max as u64,
+            (ValueConstraint::Discrete(values), LiveViewQuality::Low) => {
+                *values.iter().min().ok_or(Error::PropertyNotSupported)?
+            }
+            (ValueConstraint::Discrete(values), LiveViewQuality::High) => {
+                *values.iter().max().ok_or(Error::PropertyNotSupported)?
+            }
+            (ValueConstraint::None, _) => return Err(Error::PropertyNotSupported),
+        };
+        self.set_property(code, value)
+    }
+
+    /// Get the area of the sensor currently shown in live view
+    ///
+    /// Use `CropRect::to_sensor()` to map a point on the live view image
+    /// (e.g. a touch) to sensor coordinates. The SDK doesn't report the
+    /// rectangle directly, so it is derived from the APS-C/Super 35mm crop
+    /// and digital zoom, assuming both are centered.
+    #[async_wrap]
+    pub fn live_view_crop(&self) -> Result<CropRect> {
+        let props = self.get_all_properties()?;
+        Ok(CropRect::from_properties(&props))
+    }
+
+    /// Get the focus magnifier state, `None` while it is off
+    ///
+    /// Whether it is on comes from `EnlargeScreenSetting`, the ratio and
+    /// position from `FocusMagnifierSetting`.
+    #[async_wrap]
+    pub fn focus_magnifier(&self) -> Result<Option<FocusMagnifier>> {
+        let enlarge = self.get_property(DevicePropertyCode::EnlargeScreenSetting)?;
+        if Switch::from_raw(enlarge.current_value) != Some(Switch::On) {
+            return Ok(None);
+        }
+        let prop = self.get_property(DevicePropertyCode::FocusMagnifierSetting)?;
+        Ok(FocusMagnifier::from_raw(prop.current_value))
+    }
+
+    /// Turn the focus magnifier on or off
+    ///
+    /// Switches `EnlargeScreenSetting`. With `ratio` unset the camera picks
+    /// the ratio itself (see `InitialFocusMagnifier`); otherwise the
+    /// magnifier is then set to `ratio`, keeping the current position or
+    /// starting centered. Ratios the body doesn't offer fail with
+    /// `Error::Unsupported` listing the available ones. Live view shows the
+    /// magnified region once it is on.
+    #[async_wrap]
+    pub fn set_focus_magnifier(&self, on: bool, ratio: Option<f32>) -> Result<()> {
+        let enlarge = DevicePropertyCode::EnlargeScreenSetting;
+        let switch = if on { Switch::On } else { Switch::Off };
+        // The magnifier settings only take writes while it is shown
+        self.set_and_settle(enlarge, switch.to_raw())?;
+        let Some(ratio) = ratio.filter(|_| on) else {
+            return Ok(());
+        };
+
+        let code = DevicePropertyCode::FocusMagnifierSetting;
+        let prop = self.get_property(code)?;
+        let current = FocusMagnifier::from_raw(prop.current_value);
+        let mut offered: Vec<f32> = prop
+            .possible_values()
+            .unwrap_or(&[])
+            .iter()
+            .filter_map(|&raw| FocusMagnifier::from_raw(raw).map(|m| m.ratio))
+            .collect();
+        offered.sort_by(f32::total_cmp);
+        offered.dedup();
+
+        if !offered.is_empty() && !offered.iter().any(|r| (r - ratio).abs() < 0.05) {
+            let available: Vec<String> = offered.iter().map(|r| format!("×{}", r)).collect();
+            return Err(Error::Unsupported(format!(
+                "focus magnifier ratio ×{} (available: {})",
+                ratio,
+                available.join(", ")
+            )));
+        }
+
+        let (x, y) = current.map_or((0.5, 0.5), |m| (m.x, m.y));
+        self.set_property(code, FocusMagnifier { ratio, x, y }.to_raw())
+    }
+
+    /// Move the magnified region so it is centered on (`x`, `y`)
+    ///
+    /// Coordinates use the same 0.0-1.0 scale as [`CropRect`]. Fails with
+    /// `Error::InvalidState` while the magnifier is off.
+    #[async_wrap]
+    pub fn move_focus_magnifier(&self, x: f32, y: f32) -> Result<()> {
+        if !(x.is_finite() && y.is_finite()) {
+            return Err(Error::InvalidParameter(format!(
+                "invalid magnifier position ({}, {})",
+                x, y
+            )));
+        }
+        let current = self
+            .focus_magnifier()?
+            .ok_or_else(|| Error::InvalidState("focus magnifier is off".to_string()))?;
+        self.set_property(
+            DevicePropertyCode::FocusMagnifierSetting,
+            FocusMagnifier { x, y, ..current }.to_raw(),
+        )
+    }
+
+    /// Fetch the latest live view frame
+    ///
+    /// Returns `Error::FrameNotUpdated` if the camera has not produced a new
+    /// frame since the previous call; callers polling in a loop should
+    /// simply try again.
+    #[async_wrap]
+    pub fn get_live_view(&self) -> Result<LiveViewFrame> {
+        let mut data = Vec::new();
+        let meta = self.get_live_view_into(&mut data)?;
+        Ok(LiveViewFrame {
+            data,
+            frame_number: meta.frame_number,
+        })
+    }
+
+    /// Fetch a live view frame and compute its histogram
+    ///
+    /// Requires the `image` feature. The async device has the same method.
+    #[cfg(feature = "image")]
+    pub fn live_view_histogram(&self) -> Result<crate::Histogram> {
+        self.get_live_view()?.histogram()
+    }
+
+    /// Fetch the latest live view frame into a caller-provided buffer
+    ///
+    /// Like `get_live_view()`, but reuses `buf` instead of allocating a new
+    /// one per frame. On success `buf` holds exactly the JPEG data; it only
+    /// reallocates when a frame needs more room than its current capacity.
+    /// On error the contents of `buf` are unspecified.
+    #[async_wrap]
+    pub fn get_live_view_into(&self, buf: &mut Vec<u8>) -> Result<LiveViewFrameMeta> {
+        self.ensure_connected()?;
+        #[cfg(feature = "record")]
+        self.ensure_not_replaying("live view")?;
+        let mut buffer_size: u32 = 0;
+        let result =
+            unsafe { crsdk_sys::crsdk_live_view_buffer_size(self.handle, &mut buffer_size) };
+        if result != 0 {
+            return Err(Error::from_sdk_error(result as u32));
+        }
+        if buffer_size == 0 {
+            return Err(Error::FrameNotUpdated);
+        }
+
+        buf.resize(buffer_size as usize, 0);
+        let mut offset: u32 = 0;
+        let mut image_size: u32 = 0;
+        let mut frame_number: u32 = 0;
+        // SAFETY: buf is valid for buffer_size bytes and outlives the call.
+        // The SDK only writes within the size it was given.
+        let result = unsafe {
+            crsdk_sys::crsdk_live_view_image(
+                self.handle,
+                buf.as_mut_ptr(),
+                buffer_size,
+                &mut offset,
+                &mut image_size,
+                &mut frame_number,
+            )
+        };
+        if result != 0 {
+            return Err(Error::from_sdk_error(result as u32));
+        }
+
+        let start = offset as usize;
+        let end = start.saturating_add(image_size as usize);
+        if image_size == 0 || end > buf.len() {
+            return Err(Error::FrameNotUpdated);
+        }
+        buf.copy_within(start..end, 0);
+        buf.truncate(image_size as usize);
+
+        Ok(LiveViewFrameMeta {
+            frame_number,
+            size: image_size as usize,
+        })
+    }
+
+    // -------------------------------------------------------------------------
+    // Command operations (shooting, recording, AF)
+    // -------------------------------------------------------------------------
+
+    /// Send a raw command to the camera
+    ///
+    /// This is the escape hatch for operations the high-level API doesn't wrap
+    /// yet. Most commands model a physical button: send `CommandParam::Down`
+    /// to press it and `CommandParam::Up` to release it. Commands with a
+    /// numeric argument go through `send_command_value()` instead; see each
+    /// `CommandId` for details.
+    ///
+    /// A successful return only means the SDK accepted the command, not that
+    /// the camera finished (or even started) the operation:
+    ///
+    /// - **Fire-and-forget**: `Release`, `S1AndRelease`, `CancelShooting`,
+    ///   `CancelFocusPosition` and `TrackingOnAndAfOn` act immediately and
+    ///   have no state worth confirming.
+    /// - **Confirm via property**: `MovieRecord` and `MovieRecButtonToggle`
+    ///   change `RecordingState`; wait for a `PropertyChanged` event and read
+    ///   it back with `get_property()` before assuming recording started.
+    ///
+    /// Failures reported asynchronously by the camera arrive as
+    /// `CameraEvent::Warning` (e.g. "Operation Results") rather than as an
+    /// error from this method. Commands the body doesn't offer (see
+    /// `supported_commands()`) fail with `Error::Unsupported` without
+    /// reaching the camera.
+    #[async_wrap]
+    pub fn send_command(&self, command: CommandId, param: CommandParam) -> Result<()> {
+        self.send_command_value(command, param.into())
+    }
+
+    /// Send a command with a numeric argument, e.g. a media slot number
+    ///
+    /// Behaves like `send_command()`; commands without an argument take
+    /// `CommandValue::none()`.
+    #[async_wrap]
+    pub fn send_command_value(&self, command: CommandId, value: CommandValue) -> Result<()> {
+        self.ensure_connected()?;
+        self.ensure_command_offered(command)?;
+        let (command, param) = (command.as_raw(), value.as_raw() as u16);
+        let call = || unsafe { crsdk_sys::SCRSDK::SendCommand(self.handle, command, param) as u32 };
+
+        #[cfg(feature = "record")]
+        let result = match &self.session {
+            Some(session) => session.send_command(command, param, call, self.event_sender()),
+            None => call(),
+        };
+        #[cfg(not(feature = "record"))]
+        let result = call();
+
+        if result != 0 {
+            return Err(Error::from_sdk_error(result));
+        }
+
+        Ok(())
+    }
+
+    /// Commands this body implements
+    ///
+    /// Commands without a status property of their own (shooting, AF and
+    /// media format) are always listed; the rest only when the body reports
+    /// the status property that goes with them (e.g.
+    /// `RecordingState` for `MovieRecord`, `StreamStatus` for
+    /// `StreamButton`). A listed command can still be refused in the
+    /// camera's current state.
+    #[async_wrap]
+    pub fn supported_commands(&self) -> Result<Vec<CommandId>> {
+        Ok(supported_commands(&self.get_all_properties_current()?))
+    }
+
+    /// Turn a two-state property on or off without knowing its encoding
+    ///
+    /// Looks up the property's value type and writes the matching raw value
+    /// (see `PropertyValueType::toggle_value()`), so `Switch` (1/2), `OnOff`
+    /// (0/1) and `AutoManual` properties all take the same call. Other
+    /// properties fail with `Error::InvalidParameter`.
+    #[async_wrap]
+    pub fn set_toggle(&self, code: DevicePropertyCode, on: bool) -> Result<()> {
+        let value_type = property_value_type(code);
+        let raw = value_type.toggle_value(on).ok_or_else(|| {
+            Error::InvalidParameter(format!(
+                "{} is not an on/off property ({:?})",
+                code.name(),
+                value_type
+            ))
+        })?;
+        self.set_property(code, raw)
+    }
+
+    /// Check that the body currently offers `value` for a property
+    ///
+    /// Returns `Error::Unsupported` naming `what` and listing the offered
+    /// values otherwise. Passes if the camera doesn't report a list.
+    fn ensure_offered<T: PropertyValue>(
+        &self,
+        code: DevicePropertyCode,
+        value: T,
+        what: &str,
+    ) -> Result<()> {
+        self.get_property(code)?.check_offered(value, what)
+    }
+
+    /// Fail with `Error::Unsupported` if the body doesn't report the status
+    /// property that goes with `command` (see `CommandId::capability()`)
+    ///
+    /// Reads only that one property; commands without one always pass.
+    fn ensure_command_offered(&self, command: CommandId) -> Result<()> {
+        let Some(code) = command.capability() else {
+            return Ok(());
+        };
+        match self.get_property(code) {
+            Ok(_) => Ok(()),
+            Err(Error::PropertyNotSupported) => Err(Error::Unsupported(format!(
+                "{:?} command on {}",
+                command, self.model
+            ))),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Values a property currently offers, or just its current value if it
+    /// doesn't report a list
+    fn offered_values<T: PropertyValue>(&self, code: DevicePropertyCode) -> Result<Vec<T>> {
+        let prop = self.get_property(code)?;
+        let raw = match prop.possible_values() {
+            Some(values) if !values.is_empty() => values.to_vec(),
+            _ => vec![prop.current_value],
+        };
+        Ok(raw.into_iter().filter_map(T::from_raw).collect())
+    }
+
+    /// Write a signed value, matching the raw form the camera offers
+    ///
+    /// Discrete values of signed properties may be reported zero-extended
+    /// (e.g. 0xFF for -1 in an Int8), so pick the offered raw value that
+    /// decodes to `value`.
+    fn set_signed_property(&self, code: DevicePropertyCode, value: i64) -> Result<()> {
+        let prop = self.get_property(code)?;
+        let raw = prop
+            .possible_values()
+            .unwrap_or(&[])
+            .iter()
+            .copied()
+            .find(|&raw| prop.data_type.to_signed(raw) == value)
+            .unwrap_or(value as u64);
+        self.set_property(code, raw)
+    }
+
+    /// Write a property only if it doesn't already hold `value`
+    ///
+    /// Values are compared as the property's data type, so a sign-extended
+    /// negative value matches its narrower raw form. Returns whether it wrote.
+    fn set_if_changed(&self, code: DevicePropertyCode, value: u64) -> Result<bool> {
+        let prop = self.get_property(code)?;
+        if prop.data_type.to_signed(prop.current_value) == prop.data_type.to_signed(value) {
+            return Ok(false);
+        }
+        self.set_property(code, value)?;
+        Ok(true)
+    }
+
+    /// Like `set_if_changed()`, but wait until the camera reports the new value
+    ///
+    /// Properties that depend on this one, e.g. the frame rates offered for a
+    /// movie format, are only refreshed once the camera has applied the
+    /// write, so read them after this returns rather than after the write.
+    fn set_and_settle(&self, code: DevicePropertyCode, value: u64) -> Result<()> {
+        if self.get_property(code)?.current_value == value {
+            return Ok(());
+        }
+
+        // Subscribe before writing so the change notification can't slip past
+        let events = self.events();
+        self.set_property(code, value)?;
+
+        let deadline = Instant::now() + SETTLE_TIMEOUT;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match events.recv_timeout(remaining.min(SETTLE_POLL_INTERVAL)) {
+                Ok(CameraEvent::PropertyChanged { .. })
+                | Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
+                    if self.get_property(code)?.current_value == value {
+                        return Ok(());
+                    }
+                    if remaining.is_zero() {
+                        return Err(Error::Timeout);
+                    }
+                }
+                Ok(_) => {}
+                Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+                    return Err(Error::NotConnected)
+                }
+            }
+        }
+    }
+
+    /// Walk every format and frame rate, collecting the video modes offered
+    fn collect_video_modes(&self, modes: &mut Vec<VideoMode>) -> Result<()> {
+        for format in self.offered_values::<MovieFileFormat>(DevicePropertyCode::MovieFileFormat)? {
+            self.set_and_settle(DevicePropertyCode::MovieFileFormat, format.to_raw())?;
+            for frame_rate in self.offered_values::<RecordingFrameRate>(
+                DevicePropertyCode::MovieRecordingFrameRateSetting,
+            )? {
+                self.set_and_settle(
+                    DevicePropertyCode::MovieRecordingFrameRateSetting,
+                    frame_rate.to_raw(),
+                )?;
+                for setting in
+                    self.offered_values::<MovieQuality>(DevicePropertyCode::MovieRecordingSetting)?
+                {
+                    modes.push(VideoMode {
+                        format,
+                        frame_rate,
+                        setting,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Write each part of a video mode, validating it first
+    fn apply_video_mode(&self, mode: VideoMode) -> Result<()> {
+        self.ensure_offered(
+            DevicePropertyCode::MovieFileFormat,
+            mode.format,
+            "movie format",
+        )?;
+        self.set_and_settle(DevicePropertyCode::MovieFileFormat, mode.format.to_raw())?;
+        self.ensure_offered(
+            DevicePropertyCode::MovieRecordingFrameRateSetting,
+            mode.frame_rate,
+            "frame rate",
+        )?;
+        self.set_and_settle(
+            DevicePropertyCode::MovieRecordingFrameRateSetting,
+            mode.frame_rate.to_raw(),
+        )?;
+        self.ensure_offered(
+            DevicePropertyCode::MovieRecordingSetting,
+            mode.setting,
+            "recording setting",
+        )?;
+        self.set_if_changed(
+            DevicePropertyCode::MovieRecordingSetting,
+            mode.setting.to_raw(),
+        )?;
+        Ok(())
+    }
+
+    /// Set the S1 (half-press shutter) lock state for autofocus
+    fn set_s1_lock(&self, lock: LockIndicator) -> Result<()> {
+        self.ensure_connected()?;
+        let result = self.sdk_set_property(
+            crsdk_sys::SCRSDK::CrDevicePropertyCode_CrDeviceProperty_S1,
+            crsdk_sys::SCRSDK::CrDataType_CrDataType_UInt16,
+            lock.to_raw(),
+        );
+
+        if result != 0 {
+            return Err(Error::from_sdk_error(result));
+        }
+
+        Ok(())
+    }
+
+    /// Take a photo (shutter release)
+    ///
+    /// This performs a full shutter release cycle: press down, brief delay, release up.
+    /// The camera must be in a mode that supports still capture (Photo mode, not Movie mode).
+    ///
+    /// Only checks that the camera accepted the command; use
+    /// `capture_confirmed()` to wait until the shot has actually been taken.
+    ///
+    /// Bodies that can take stills while recording a movie accept this mid
+    /// recording. On the others the refusal is reported as
+    /// `Error::Unsupported` rather than the camera's generic error.
+    #[async_wrap]
+    pub fn capture(&self) -> Result<()> {
+        if let Err(e) = self.send_command(CommandId::Release, CommandParam::Down) {
+            if self.recording_state().ok() == Some(RecordingState::Recording) {
+                return Err(Error::Unsupported(format!(
+                    "still capture during movie recording on {} ({})",
+                    self.model, e
+                )));
+            }
+            return Err(e);
+        }
+        std::thread::sleep(Duration::from_millis(35));
+        self.send_command(CommandId::Release, CommandParam::Up)?;
+        Ok(())
+    }
+
+    /// Take a photo and wait until the camera confirms where it went
+    ///
+    /// Reads `StillImageStoreDestination` (card only if the body doesn't
+    /// report it), releases the shutter, then watches the camera's events
+    /// for the card write and/or host transfer. Fails with `Error::Timeout`
+    /// if the shot isn't confirmed within `timeout`, e.g. because the
+    /// shutter never fired.
+    #[async_wrap]
+    pub fn capture_confirmed(&self, timeout: Duration) -> Result<CaptureOutcome> {
+        let destination = match self.get_property(DevicePropertyCode::StillImageStoreDestination) {
+            Ok(prop) => StillImageStoreDestination::from_raw(prop.current_value)
+                .unwrap_or(StillImageStoreDestination::MemoryCard),
+            Err(Error::PropertyNotSupported) => StillImageStoreDestination::MemoryCard,
+            Err(e) => return Err(e),
+        };
+
+        // Subscribe before releasing so no confirmation can slip past
+        let events = self.events();
+        let mut watch = CaptureWatch::new(destination);
+        self.capture()?;
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match events.recv_timeout(remaining) {
+                Ok(event) => {
+                    if let Some(outcome) = watch.observe(&event) {
+                        return outcome;
+                    }
+                }
+                Err(std::sync::mpsc::RecvTimeoutError::Timeout) => return Err(Error::Timeout),
+                Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+                    return Err(Error::NotConnected)
+                }
+            }
+        }
+    }
+
+    /// Take a photo and return the settings it was taken with
+    ///
+    /// ISO, aperture, shutter speed and white balance are read in a single
+    /// property snapshot immediately before the shutter is released, so they
+    /// reflect the capture rather than whatever is set a moment later.
+    ///
+    /// The shot is then confirmed as in `capture_confirmed()`, and `path` is
+    /// set to the file name the camera reports when it transfers the image to
+    /// the host (left `None` when saving to the card only).
+    #[async_wrap]
+    pub fn capture_with_metadata(&self, timeout: Duration) -> Result<CaptureResult> {
+        let props = self.get_all_properties()?;
+        let mut result = CaptureResult::from_properties(&props, SystemTime::now());
+        let outcome = self.capture_confirmed(timeout)?;
+        result.path = outcome.filename().map(PathBuf::from);
+        Ok(result)
+    }
+
+    /// Half-press the shutter to activate autofocus
+    ///
+    /// This is equivalent to pressing the shutter button halfway on a physical camera.
+    /// The camera will attempt to focus on the current subject. Call `release_shutter()`
+    /// to release the half-press state.
+    #[async_wrap]
+    pub fn half_press_shutter(&self) -> Result<()> {
+        self.set_s1_lock(LockIndicator::Locked)
+    }
+
+    /// Release the half-pressed shutter
+    ///
+    /// This releases the autofocus lock initiated by `half_press_shutter()`.
+    #[async_wrap]
+    pub fn release_shutter(&self) -> Result<()> {
+        self.set_s1_lock(LockIndicator::Unlocked)
+    }
+
+    /// Autofocus and capture in one operation
+    ///
+    /// Half-presses to focus, waits briefly, then captures the image.
+    /// This is a convenience method that combines `half_press_shutter()` + delay + `capture()`.
+    #[async_wrap]
+    pub fn focus_and_capture(&self) -> Result<()> {
+        self.half_press_shutter()?;
+        std::thread::sleep(Duration::from_millis(500));
+        self.capture()?;
+        self.release_shutter()?;
+        Ok(())
+    }
+
+    /// Autofocus and report whether focus was achieved
+    ///
+    /// Half-presses the shutter, watches `FocusIndication` (or
+    /// `FocusTrackingStatus` on bodies without it) until the camera reports
+    /// focus, gives up, or `timeout` elapses, then releases the half-press.
+    /// Returns `true` if focus was achieved.
+    #[async_wrap]
+    pub fn autofocus(&self, timeout: Duration) -> Result<bool> {
+        self.half_press_shutter()?;
+        let focused = self.wait_for_focus(timeout);
+        let released = self.release_shutter();
+        let focused = focused?;
+        released?;
+        Ok(focused)
+    }
+
+    /// Get the autofocus state (indicator, tracking and focus motor) from a
+    /// single property read
+    #[async_wrap]
+    pub fn focus_state(&self) -> Result<FocusState> {
+        Ok(FocusState::from_properties(
+            &self.get_all_properties_current()?,
+        ))
+    }
+
+    /// Poll until the camera reports focus (`true`) or failure/timeout (`false`)
+    fn wait_for_focus(&self, timeout: Duration) -> Result<bool> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let indicator = self
+                .get_property(DevicePropertyCode::FocusIndication)
+                .ok()
+                .and_then(|p| FocusIndicator::from_raw(p.current_value));
+            match indicator {
+                Some(
+                    FocusIndicator::FocusedAfS
+                    | FocusIndicator::FocusedAfC
+                    | FocusIndicator::TrackingAfC,
+                ) => return Ok(true),
+                // AF-S gives up for good; AF-C keeps trying until the timeout
+                Some(FocusIndicator::NotFocusedAfS) => return Ok(false),
+                Some(_) => {}
+                None => {
+                    let tracking = self
+                        .get_property(DevicePropertyCode::FocusTrackingStatus)
+                        .ok()
+                        .and_then(|p| FocusTrackingStatus::from_raw(p.current_value));
+                    if tracking == Some(FocusTrackingStatus::Tracking) {
+                        return Ok(true);
+                    }
+                }
+            }
+
+            if Instant::now() >= deadline {
+                return Ok(false);
+            }
+            std::thread::sleep(AF_POLL_INTERVAL);
+        }
+    }
+
+    /// Start movie recording
+    ///
+    /// The camera must be in a mode that supports movie recording (Movie mode).
+    /// Call `stop_recording()` to stop.
+    #[async_wrap]
+    pub fn start_recording(&self) -> Result<()> {
+        self.send_command(CommandId::MovieRecord, CommandParam::Down)
+    }
+
+    /// Stop movie recording
+    #[async_wrap]
+    pub fn stop_recording(&self) -> Result<()> {
+        self.send_command(CommandId::MovieRecord, CommandParam::Up)
+    }
+
+    /// Get whether movies are recorded to both card slots at once
+    #[async_wrap]
+    pub fn simul_rec(&self) -> Result<bool> {
+        let prop = self.get_property(DevicePropertyCode::SimulRecSetting)?;
+        Ok(Switch::from_raw(prop.current_value) == Some(Switch::On))
+    }
+
+    /// Record movies to both card slots at once (`SimulRecSetting`)
+    ///
+    /// This is the slot mirroring setting; it doesn't affect whether stills
+    /// can be taken during recording, which depends on the body (see
+    /// `capture()`).
+    #[async_wrap]
+    pub fn set_simul_rec(&self, enabled: bool) -> Result<()> {
+        let value = if enabled { Switch::On } else { Switch::Off };
+        self.set_property(DevicePropertyCode::SimulRecSetting, value.to_raw())
+    }
+
+    /// Get whether the camera is recording (`RecordingState`)
+    #[async_wrap]
+    pub fn recording_state(&self) -> Result<RecordingState> {
+        let prop = self.get_property(DevicePropertyCode::RecordingState)?;
+        RecordingState::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Start movie recording and wait until the camera reports Recording
+    ///
+    /// Fails with `Error::Timeout` if the state hasn't changed within
+    /// `timeout`, and with `Error::InvalidState` if the camera reports that
+    /// recording failed (e.g. no card or a full card).
+    #[async_wrap]
+    pub fn start_recording_and_wait(&self, timeout: Duration) -> Result<()> {
+        self.start_recording()?;
+        self.wait_for_recording_state(RecordingState::Recording, timeout)
+    }
+
+    /// Stop movie recording and wait until the camera reports NotRecording
+    ///
+    /// Fails with `Error::Timeout` if the state hasn't changed within
+    /// `timeout`.
+    #[async_wrap]
+    pub fn stop_recording_and_wait(&self, timeout: Duration) -> Result<()> {
+        self.stop_recording()?;
+        self.wait_for_recording_state(RecordingState::NotRecording, timeout)
+    }
+
+    fn wait_for_recording_state(&self, want: RecordingState, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            match self.recording_state()? {
+                state if state == want => return Ok(()),
+                RecordingState::RecordingFailed if want == RecordingState::Recording => {
+                    return Err(Error::InvalidState(
+                        "camera reported that recording failed".to_string(),
+                    ));
+                }
+                _ => {}
+            }
+            if Instant::now() >= deadline {
+                return Err(Error::Timeout);
+            }
+            std::thread::sleep(RECORDING_POLL_INTERVAL);
+        }
+    }
+
+    /// Get the status of every memory card slot the camera reports
+    ///
+    /// Returns one entry per slot in slot order: two for most bodies, three
+    /// for FX6/FX9. Reads all slot properties in a single fetch.
+    #[async_wrap]
+    pub fn media_status(&self) -> Result<Vec<MediaSlotInfo>> {
+        let props = self.get_all_properties()?;
+        Ok(MediaSlotInfo::from_properties(&props))
+    }
+
+    /// Estimate how many more stills fit on the active card(s)
+    ///
+    /// Combines the per-slot remaining counts according to the recording
+    /// media setting (the smaller count when recording to both slots).
+    /// Returns `Error::PropertyNotSupported` if no slot in use reports a count,
+    /// e.g. because no card is inserted.
+    #[async_wrap]
+    pub fn estimated_remaining_shots(&self) -> Result<u32> {
+        let props = self.get_all_properties()?;
+        estimate_remaining_shots(&props).ok_or(Error::PropertyNotSupported)
+    }
+
+    /// Get the HDMI output settings: resolution, RAW, timecode and overlays
+    #[async_wrap]
+    pub fn hdmi_output(&self) -> Result<HdmiOutputConfig> {
+        Ok(HdmiOutputConfig::from_properties(
+            &self.get_all_properties_current()?,
+        ))
+    }
+
+    /// Set the HDMI output resolution and scan
+    ///
+    /// Fails with `Error::Unsupported` listing the available formats if the
+    /// body doesn't offer `format` in the current recording format.
+    #[async_wrap]
+    pub fn set_hdmi_output_format(&self, format: MonitoringOutputFormat) -> Result<()> {
+        let code = DevicePropertyCode::MonitoringOutputFormat;
+        self.ensure_offered(code, format, "HDMI output format")?;
+        self.set_property(code, format.to_raw())
+    }
+
+    /// Embed timecode in the HDMI output
+    #[async_wrap]
+    pub fn set_hdmi_timecode(&self, enabled: bool) -> Result<()> {
+        let value = if enabled { Switch::On } else { Switch::Off };
+        self.set_property(DevicePropertyCode::MovieHDMIOutputTimeCode, value.to_raw())
+    }
+
+    /// Send RAW video over HDMI to an external recorder
+    #[async_wrap]
+    pub fn set_hdmi_raw_output(&self, enabled: bool) -> Result<()> {
+        let value = if enabled { Switch::On } else { Switch::Off };
+        self.set_property(DevicePropertyCode::MovieHDMIOutputRAW, value.to_raw())
+    }
+
+    /// Apply network streaming settings
+    ///
+    /// Writes every `Some` field of `config`, skipping values that already
+    /// match. Call `start_stream()` afterwards to go live.
+    #[async_wrap]
+    pub fn configure_stream(&self, config: StreamConfig) -> Result<()> {
+        for (code, value) in config.to_raw_values() {
+            if self.get_property(code)?.current_value != value {
+                self.set_property(code, value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Route audio inputs to channels and set channel recording levels
+    ///
+    /// Writes every `Some` entry of `routing`, inputs first, skipping values
+    /// that already match. Inputs the body doesn't offer on a channel fail
+    /// with `Error::Unsupported`, and levels outside the channel's range with
+    /// `Error::InvalidParameter`. Levels are written as whole dB, the unit
+    /// the level properties' ranges are expressed in.
+    #[async_wrap]
+    pub fn set_audio_routing(&self, routing: &AudioRouting) -> Result<()> {
+        for (code, input) in routing.input_values() {
+            self.ensure_offered(code, input, "audio input")?;
+            self.set_if_changed(code, input.to_raw())?;
+        }
+        for (code, db) in routing.level_values() {
+            let prop = self.get_property(code)?;
+            if let Some((min, max, _)) = prop.constraint.range_params() {
+                if !(min..=max).contains(&db) {
+                    return Err(Error::InvalidParameter(format!(
+                        "{} {} dB (accepted: {} to {} dB)",
+                        code.name(),
+                        db,
+                        min,
+                        max
+                    )));
+                }
+            }
+            if prop.data_type.to_signed(prop.current_value) != db {
+                self.set_signed_property(code, db)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the interval recording (timelapse) settings
+    #[async_wrap]
+    pub fn interval_config(&self) -> Result<IntervalConfig> {
+        Ok(IntervalConfig::from_properties(&self.get_all_properties()?))
+    }
+
+    /// Apply interval recording (timelapse) settings
+    ///
+    /// Writes every `Some` field of `config`, skipping values that already
+    /// match.
+    #[async_wrap]
+    pub fn configure_interval(&self, config: IntervalConfig) -> Result<()> {
+        for (code, value) in config.to_raw_values() {
+            self.set_if_changed(code, value)?;
+        }
+        Ok(())
+    }
+
+    /// Get the current network streaming status
+    #[async_wrap]
+    pub fn stream_status(&self) -> Result<StreamStatus> {
+        let prop = self.get_property(DevicePropertyCode::StreamStatus)?;
+        StreamStatus::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Start network streaming to the destination selected on the camera
+    ///
+    /// Does nothing if the camera is already streaming. The stream takes a
+    /// moment to connect; watch `stream_status()` to see it go live.
+    #[async_wrap]
+    pub fn start_stream(&self) -> Result<()> {
+        if self.stream_status()? == StreamStatus::Streaming {
+            return Ok(());
+        }
+        self.press_stream_button()
+    }
+
+    /// Stop network streaming
+    ///
+    /// Does nothing if the camera is not streaming.
+    #[async_wrap]
+    pub fn stop_stream(&self) -> Result<()> {
+        if self.stream_status()? != StreamStatus::Streaming {
+            return Ok(());
+        }
+        self.press_stream_button()
+    }
+
+    /// Play back the last recorded clip on the camera's monitor
+    ///
+    /// Presses `MovieRecReviewButton` and returns the resulting
+    /// `MovieRecReviewPlayingState` once playback starts. Like the other
+    /// review controls, fails with `Error::Timeout` if the camera doesn't
+    /// reach the expected state within 2 seconds.
+    #[async_wrap]
+    pub fn review_last_clip(&self) -> Result<MovieRecReviewPlayingState> {
+        self.press_review_button(
+            DevicePropertyCode::MovieRecReviewButton,
+            MovieRecReviewPlayingState::Playing,
+        )
+    }
+
+    /// Resume playback of the clip under review
     #[async_wrap]
-    pub fn iso(&self) -> Result<u64> {
-        let prop = self.get_property(DevicePropertyCode::IsoSensitivity)?;
-        Ok(prop.current_value)
+    pub fn review_play(&self) -> Result<MovieRecReviewPlayingState> {
+        self.press_review_button(
+            DevicePropertyCode::MoviePlayButton,
+            MovieRecReviewPlayingState::Playing,
+        )
     }
 
-    /// Set the ISO sensitivity
+    /// Pause playback of the clip under review
     #[async_wrap]
-    pub fn set_iso(&self, value: u64) -> Result<()> {
-        self.set_property(DevicePropertyCode::IsoSensitivity, value)
+    pub fn review_pause(&self) -> Result<MovieRecReviewPlayingState> {
+        self.press_review_button(
+            DevicePropertyCode::MoviePlayPauseButton,
+            MovieRecReviewPlayingState::NotPlaying,
+        )
     }
 
-    /// Get the current aperture/f-number (raw SDK value)
+    /// Stop reviewing and return to the shooting screen
     #[async_wrap]
-    pub fn aperture(&self) -> Result<u64> {
-        let prop = self.get_property(DevicePropertyCode::FNumber)?;
-        Ok(prop.current_value)
+    pub fn review_stop(&self) -> Result<MovieRecReviewPlayingState> {
+        self.press_review_button(
+            DevicePropertyCode::MoviePlayStopButton,
+            MovieRecReviewPlayingState::NotPlaying,
+        )
     }
 
-    /// Set the aperture/f-number
-    #[async_wrap]
-    pub fn set_aperture(&self, value: u64) -> Result<()> {
-        self.set_property(DevicePropertyCode::FNumber, value)
+    /// Press a playback button property and wait for the review state
+    ///
+    /// Returns the state once it reads `expected`, or `Error::Timeout` if it
+    /// doesn't within `REVIEW_SETTLE_TIMEOUT`. Pausing a clip that already
+    /// ended succeeds right away, since it is already not playing.
+    fn press_review_button(
+        &self,
+        code: DevicePropertyCode,
+        expected: MovieRecReviewPlayingState,
+    ) -> Result<MovieRecReviewPlayingState> {
+        // Button properties: 0x0001 = Up, 0x0002 = Down
+        self.set_property(code, 0x0002)?;
+        std::thread::sleep(Duration::from_millis(35));
+        self.set_property(code, 0x0001)?;
+
+        let deadline = Instant::now() + REVIEW_SETTLE_TIMEOUT;
+        loop {
+            let prop = self.get_property(DevicePropertyCode::MovieRecReviewPlayingState)?;
+            let state = MovieRecReviewPlayingState::from_raw(prop.current_value)
+                .ok_or(Error::InvalidPropertyValue)?;
+            if state == expected {
+                return Ok(state);
+            }
+            if Instant::now() >= deadline {
+                return Err(Error::Timeout);
+            }
+            std::thread::sleep(REVIEW_POLL_INTERVAL);
+        }
     }
 
-    /// Get the current shutter speed (raw SDK value)
-    #[async_wrap]
-    pub fn shutter_speed(&self) -> Result<u64> {
-        let prop = self.get_property(DevicePropertyCode::ShutterSpeed)?;
-        Ok(prop.current_value)
+    /// Toggle streaming, checking the camera accepts the button first
+    fn press_stream_button(&self) -> Result<()> {
+        // CrStreamButtonEnableStatus: 0x00 = Disable, 0x01 = Enable
+        if let Ok(prop) = self.get_property(DevicePropertyCode::StreamButtonEnableStatus) {
+            if prop.current_value != 0x01 {
+                return Err(Error::InvalidState(
+                    "streaming is not available (is streaming mode on?)".to_string(),
+                ));
+            }
+        }
+        self.send_command(CommandId::StreamButton, CommandParam::Down)?;
+        std::thread::sleep(Duration::from_millis(35));
+        self.send_command(CommandId::StreamButton, CommandParam::Up)
     }
 
-    /// Set the shutter speed
+    /// Format the memory card in the given slot
+    ///
+    /// This erases everything on the card, so `confirm` must be `true` or the
+    /// call fails with `Error::InvalidParameter` without touching the camera.
+    ///
+    /// Checks the slot's (quick) format enable status, refuses with
+    /// `Error::InvalidState` while a recording is in progress, then issues the
+    /// format command and blocks until `MediaFormatProgressRate` or the
+    /// camera's Format Complete warning reports completion. Progress is
+    /// emitted as `CameraEvent::MediaFormatProgress`. A format that neither
+    /// shows progress nor completes within `FORMAT_START_GRACE` fails with
+    /// `Error::Timeout`, and a Format Failed warning with `Error::InvalidState`.
+    ///
+    /// Slot 3 cannot be formatted remotely and returns
+    /// `Error::PropertyNotSupported`.
     #[async_wrap]
-    pub fn set_shutter_speed(&self, value: u64) -> Result<()> {
-        self.set_property(DevicePropertyCode::ShutterSpeed, value)
-    }
+    pub fn format_media(&self, slot: PlaybackMedia, quick: bool, confirm: bool) -> Result<()> {
+        if !confirm {
+            return Err(Error::InvalidParameter(
+                "formatting erases the card; pass confirm = true to proceed".to_string(),
+            ));
+        }
 
-    // -------------------------------------------------------------------------
-    // Command operations (shooting, recording, AF)
-    // -------------------------------------------------------------------------
+        // Bodies without movie support don't expose RecordingState at all
+        if let Ok(prop) = self.get_property(DevicePropertyCode::RecordingState) {
+            if RecordingState::from_raw(prop.current_value) == Some(RecordingState::Recording) {
+                return Err(Error::InvalidState(
+                    "cannot format media while recording".to_string(),
+                ));
+            }
+        }
 
-    /// Send a command to the camera
-    fn send_command(&self, command: CommandId, param: CommandParam) -> Result<()> {
-        let result = unsafe {
-            crsdk_sys::SCRSDK::SendCommand(self.handle, command.as_raw(), param.as_raw() as u16)
+        let (enable_code, command) = match (slot, quick) {
+            (PlaybackMedia::Slot1, false) => (
+                DevicePropertyCode::MediaSLOT1FormatEnableStatus,
+                CommandId::MediaFormat,
+            ),
+            (PlaybackMedia::Slot2, false) => (
+                DevicePropertyCode::MediaSLOT2FormatEnableStatus,
+                CommandId::MediaFormat,
+            ),
+            (PlaybackMedia::Slot1, true) => (
+                DevicePropertyCode::MediaSLOT1QuickFormatEnableStatus,
+                CommandId::MediaQuickFormat,
+            ),
+            (PlaybackMedia::Slot2, true) => (
+                DevicePropertyCode::MediaSLOT2QuickFormatEnableStatus,
+                CommandId::MediaQuickFormat,
+            ),
+            // The SDK has no format enable status for slot 3, so there is no
+            // way to check the card is safe to format first
+            (PlaybackMedia::Slot3, _) => return Err(Error::PropertyNotSupported),
         };
 
-        if result != 0 {
-            return Err(Error::from_sdk_error(result as u32));
+        // CrMediaFormat: 0x00 = Disable, 0x01 = Enable
+        if self.get_property(enable_code)?.current_value != 0x01 {
+            return Err(Error::InvalidState(format!(
+                "{} cannot be formatted right now",
+                slot
+            )));
         }
 
-        Ok(())
-    }
+        let slot_number = slot.to_raw() as u32;
+        let value = CommandValue::try_from(slot_number)?;
+        // Subscribe first so a quick format's completion can't be missed
+        let events = self.events();
+        self.send_command_value(command, value)?;
+
+        let started_at = Instant::now();
+        let mut last_percent: Option<u32> = None;
+        let mut completed = false;
+
+        loop {
+            std::thread::sleep(FORMAT_POLL_INTERVAL);
+
+            for event in events.try_iter() {
+                if let CameraEvent::Warning { code, .. } = event {
+                    if code == WARNING_FORMAT_COMPLETE {
+                        completed = true;
+                    } else if WARNING_FORMAT_FAILED.contains(&code) {
+                        return Err(Error::InvalidState(format!(
+                            "formatting {} failed ({})",
+                            slot,
+                            warning_code_name(code)
+                        )));
+                    }
+                }
+            }
 
-    /// Set the S1 (half-press shutter) lock state for autofocus
-    fn set_s1_lock(&self, lock: LockIndicator) -> Result<()> {
-        let mut sdk_prop = crsdk_sys::SCRSDK::CrDeviceProperty {
-            code: crsdk_sys::SCRSDK::CrDevicePropertyCode_CrDeviceProperty_S1,
-            valueType: crsdk_sys::SCRSDK::CrDataType_CrDataType_UInt16,
-            enableFlag: 0,
-            variableFlag: 0,
-            currentValue: lock.to_raw(),
-            currentStr: ptr::null_mut(),
-            valuesSize: 0,
-            values: ptr::null_mut(),
-            getSetValuesSize: 0,
-            getSetValues: ptr::null_mut(),
-        };
+            let percent = self
+                .get_property(DevicePropertyCode::MediaFormatProgressRate)?
+                .current_value
+                .min(100) as u32;
 
-        let result = unsafe { crsdk_sys::SCRSDK::SetDeviceProperty(self.handle, &mut sdk_prop) };
+            if last_percent != Some(percent) {
+                self.emit_event(CameraEvent::MediaFormatProgress {
+                    slot: slot_number,
+                    percent,
+                });
+            }
 
-        if result != 0 {
-            return Err(Error::from_sdk_error(result as u32));
+            // The rate drops back to 0 once the camera is done; quick
+            // formats may finish before any progress is seen, but still send
+            // the completion warning.
+            let started = last_percent.is_some_and(|p| p > 0);
+            let finished = completed || percent >= 100 || (percent == 0 && started);
+
+            if finished {
+                if last_percent != Some(100) {
+                    self.emit_event(CameraEvent::MediaFormatProgress {
+                        slot: slot_number,
+                        percent: 100,
+                    });
+                }
+                return Ok(());
+            }
+
+            let idle = percent == 0 && !started;
+            if started_at.elapsed() >= FORMAT_TIMEOUT
+                || (idle && started_at.elapsed() >= FORMAT_START_GRACE)
+            {
+                return Err(Error::Timeout);
+            }
+
+            last_percent = Some(percent);
         }
+    }
 
-        Ok(())
+    /// Get the camera's current file number (`RecordingFileNumber`)
+    #[async_wrap]
+    pub fn file_number(&self) -> Result<u32> {
+        let prop = self.get_property(DevicePropertyCode::RecordingFileNumber)?;
+        Ok(prop.current_value as u32)
     }
 
-    /// Take a photo (shutter release)
+    /// Reset file numbering so the next file starts again from 0001
     ///
-    /// This performs a full shutter release cycle: press down, brief delay, release up.
-    /// The camera must be in a mode that supports still capture (Photo mode, not Movie mode).
+    /// Fails with `Error::InvalidState` unless
+    /// `ForcedFileNumberResetEnableStatus` reports Enable.
     #[async_wrap]
-    pub fn capture(&self) -> Result<()> {
-        self.send_command(CommandId::Release, CommandParam::Down)?;
-        std::thread::sleep(Duration::from_millis(35));
-        self.send_command(CommandId::Release, CommandParam::Up)?;
-        Ok(())
+    pub fn reset_file_number(&self) -> Result<()> {
+        // Enable status: 0x00 = Disable, 0x01 = Enable
+        let status = self.get_property(DevicePropertyCode::ForcedFileNumberResetEnableStatus)?;
+        if status.current_value != 0x01 {
+            return Err(Error::InvalidState(
+                "file number reset is not available right now".to_string(),
+            ));
+        }
+        self.send_command(CommandId::ForcedFileNumberReset, CommandParam::Down)
     }
 
-    /// Half-press the shutter to activate autofocus
+    /// Get the name given to recorded clips (`RecorderClipName`)
+    #[async_wrap]
+    pub fn clip_name(&self) -> Result<String> {
+        let prop = self.get_property(DevicePropertyCode::RecorderClipName)?;
+        prop.current_string.ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Set the name given to recorded clips, e.g. `A_` for the A camera of a
+    /// multicam shoot
     ///
-    /// This is equivalent to pressing the shutter button halfway on a physical camera.
-    /// The camera will attempt to focus on the current subject. Call `release_shutter()`
-    /// to release the half-press state.
+    /// Names with characters other than ASCII letters, digits, `_` and `-`
+    /// fail with `Error::InvalidParameter`; a name too long for the body
+    /// fails with the camera's error. Like other writes, it is reported to
+    /// the `on_change` hook.
     #[async_wrap]
-    pub fn half_press_shutter(&self) -> Result<()> {
-        self.set_s1_lock(LockIndicator::Locked)
+    pub fn set_clip_name(&self, name: &str) -> Result<()> {
+        validate_clip_name(name)?;
+        self.set_property_string(DevicePropertyCode::RecorderClipName, name)
     }
 
-    /// Release the half-pressed shutter
+    /// Get how recording folders are organized on the card
+    #[async_wrap]
+    pub fn folder_format(&self) -> Result<RecordingFolderFormat> {
+        let prop = self.get_property(DevicePropertyCode::RecordingFolderFormat)?;
+        RecordingFolderFormat::from_raw(prop.current_value).ok_or(Error::InvalidPropertyValue)
+    }
+
+    /// Set how recording folders are organized on the card
     ///
-    /// This releases the autofocus lock initiated by `half_press_shutter()`.
+    /// Formats the body doesn't currently offer fail with
+    /// `Error::Unsupported` listing the available ones.
     #[async_wrap]
-    pub fn release_shutter(&self) -> Result<()> {
-        self.set_s1_lock(LockIndicator::Unlocked)
+    pub fn set_folder_format(&self, format: RecordingFolderFormat) -> Result<()> {
+        let code = DevicePropertyCode::RecordingFolderFormat;
+        self.ensure_offered(code, format, "folder format")?;
+        self.set_property(code, format.to_raw())
     }
 
-    /// Autofocus and capture in one operation
+    /// Run pixel mapping to remap defective sensor pixels
     ///
-    /// Half-presses to focus, waits briefly, then captures the image.
-    /// This is a convenience method that combines `half_press_shutter()` + delay + `capture()`.
+    /// Fails with `Error::InvalidState` unless `PixelMappingEnableStatus`
+    /// reports Enable (the camera usually needs the lens cap on and no
+    /// shooting in progress), then blocks until the camera finishes.
     #[async_wrap]
-    pub fn focus_and_capture(&self) -> Result<()> {
-        self.half_press_shutter()?;
-        std::thread::sleep(Duration::from_millis(500));
-        self.capture()?;
-        self.release_shutter()?;
-        Ok(())
+    pub fn run_pixel_mapping(&self) -> Result<()> {
+        self.run_maintenance(
+            CommandId::PixelMapping,
+            DevicePropertyCode::PixelMappingEnableStatus,
+            "pixel mapping",
+        )
     }
 
-    /// Start movie recording
+    /// Run the sensor cleaning cycle
     ///
-    /// The camera must be in a mode that supports movie recording (Movie mode).
-    /// Call `stop_recording()` to stop.
+    /// Fails with `Error::InvalidState` unless `SensorCleaningEnableStatus`
+    /// reports Enable, then blocks until the camera finishes.
     #[async_wrap]
-    pub fn start_recording(&self) -> Result<()> {
-        self.send_command(CommandId::MovieRecord, CommandParam::Down)
+    pub fn run_sensor_cleaning(&self) -> Result<()> {
+        self.run_maintenance(
+            CommandId::SensorCleaning,
+            DevicePropertyCode::SensorCleaningEnableStatus,
+            "sensor cleaning",
+        )
     }
 
-    /// Stop movie recording
+    /// Reset camera settings to their defaults
+    ///
+    /// `ResetScope::All` is what to use to bring a body back to a known
+    /// baseline: it also clears custom button assignments, which the SDK
+    /// cannot reset on their own. Fails with `Error::InvalidState` unless
+    /// the scope's enable status reports Enable, then blocks until the
+    /// camera finishes. A full reset may change connection-related settings,
+    /// so expect to reconnect afterwards.
     #[async_wrap]
-    pub fn stop_recording(&self) -> Result<()> {
-        self.send_command(CommandId::MovieRecord, CommandParam::Up)
+    pub fn reset_settings(&self, scope: ResetScope) -> Result<()> {
+        self.run_maintenance(scope.command(), scope.enable_status(), &scope.to_string())
+    }
+
+    /// Issue a maintenance command gated by an enable status and wait for it
+    ///
+    /// The status drops out of Enable while the camera is busy and returns
+    /// once it is done. Short runs may finish between two polls, so a
+    /// `PropertyChanged` event for the status also counts as having gone
+    /// busy. A run that shows neither within `MAINTENANCE_START_GRACE` never
+    /// started and fails with `Error::Timeout`.
+    fn run_maintenance(
+        &self,
+        command: CommandId,
+        status_code: DevicePropertyCode,
+        what: &str,
+    ) -> Result<()> {
+        // Enable status: 0x00 = Disable, 0x01 = Enable
+        let is_enabled = |device: &Self| -> Result<bool> {
+            Ok(device.get_property(status_code)?.current_value == 0x01)
+        };
+
+        if !is_enabled(self)? {
+            return Err(Error::InvalidState(format!(
+                "{} is not available right now",
+                what
+            )));
+        }
+
+        // Subscribe before sending so a brief busy spell can't slip past
+        let events = self.events();
+        self.send_command(command, CommandParam::Down)?;
+
+        let started_at = Instant::now();
+        let mut went_busy = false;
+
+        loop {
+            std::thread::sleep(MAINTENANCE_POLL_INTERVAL);
+
+            went_busy |= events.try_iter().any(|event| match event {
+                CameraEvent::PropertyChanged { codes } => codes.contains(&status_code),
+                _ => false,
+            });
+            if !is_enabled(self)? {
+                went_busy = true;
+            } else if went_busy {
+                return Ok(());
+            }
+
+            let elapsed = started_at.elapsed();
+            if elapsed >= MAINTENANCE_TIMEOUT || (!went_busy && elapsed >= MAINTENANCE_START_GRACE)
+            {
+                return Err(Error::Timeout);
+            }
+        }
+    }
+
+    /// `GetDeviceProperties`, keeping what `convert` returns for each property
+    ///
+    /// `convert` sees each property while the SDK's list is still alive; the
+    /// list is released before returning. Fails with the SDK error code.
+    fn sdk_get_properties<T>(
+        &self,
+        mut convert: impl FnMut(&crsdk_sys::SCRSDK::CrDeviceProperty) -> Option<T>,
+    ) -> std::result::Result<Vec<T>, u32> {
+        let mut properties_ptr: *mut crsdk_sys::SCRSDK::CrDeviceProperty = ptr::null_mut();
+        let mut num_properties: i32 = 0;
+
+        let result = unsafe {
+            crsdk_sys::SCRSDK::GetDeviceProperties(
+                self.handle,
+                &mut properties_ptr,
+                &mut num_properties,
+            )
+        };
+
+        if result != 0 {
+            return Err(result as u32);
+        }
+
+        if properties_ptr.is_null() || num_properties == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut converted = Vec::new();
+
+        unsafe {
+            for i in 0..num_properties as usize {
+                if let Some(value) = convert(&*properties_ptr.add(i)) {
+                    converted.push(value);
+                }
+            }
+
+            crsdk_sys::SCRSDK::ReleaseDeviceProperties(self.handle, properties_ptr);
+        }
+
+        Ok(converted)
+    }
+
+    /// Every property as `convert` decodes it, through the session if any
+    fn sdk_get_property_list(
+        &self,
+        convert: unsafe fn(&crsdk_sys::SCRSDK::CrDeviceProperty) -> DeviceProperty,
+    ) -> std::result::Result<Vec<DeviceProperty>, u32> {
+        let call = || self.sdk_get_properties(|p| Some(unsafe { convert(p) }));
+
+        #[cfg(feature = "record")]
+        if let Some(session) = &self.session {
+            return session.get_properties(call, self.event_sender());
+        }
+        call()
+    }
+
+    /// `SetDeviceProperty` for a numeric value, returning the SDK result code
+    fn sdk_set_property(&self, code: u32, value_type: u32, value: u64) -> u32 {
+        let call = || {
+            let mut sdk_prop = crsdk_sys::SCRSDK::CrDeviceProperty {
+                code,
+                valueType: value_type,
+                enableFlag: 0,
+                variableFlag: 0,
+                currentValue: value,
+                currentStr: ptr::null_mut(),
+                valuesSize: 0,
+                values: ptr::null_mut(),
+                getSetValuesSize: 0,
+                getSetValues: ptr::null_mut(),
+            };
+            unsafe { crsdk_sys::SCRSDK::SetDeviceProperty(self.handle, &mut sdk_prop) as u32 }
+        };
+
+        #[cfg(feature = "record")]
+        if let Some(session) = &self.session {
+            return session.set_property(code, value, call, self.event_sender());
+        }
+        call()
+    }
+
+    /// Fail for calls a replayed session has no recording of
+    #[cfg(feature = "record")]
+    fn ensure_not_replaying(&self, what: &str) -> Result<()> {
+        match &self.session {
+            Some(session) if session.is_replay() => Err(Error::Unsupported(format!(
+                "{} is not available when replaying a session",
+                what
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Fail with `Error::NotConnected` unless the connection is live
+    fn ensure_connected(&self) -> Result<()> {
+        if self.is_connected() {
+            Ok(())
+        } else {
+            Err(Error::NotConnected)
+        }
+    }
+
+    /// The sender shared with the SDK callback
+    fn event_sender(&self) -> &EventSender {
+        // SAFETY: event_sender_ptr is set from EventSender::into_raw() when
+        // the device is built and only reclaimed in Drop, which can't run
+        // while &self is borrowed
+        unsafe { &*(self.event_sender_ptr as *const EventSender) }
+    }
+
+    /// Push a locally generated event into this device's event channel
+    fn emit_event(&self, event: CameraEvent) {
+        self.event_sender().send(event);
+    }
+
+    /// Subscribe to camera events on a std channel
+    ///
+    /// Every event from this point on is delivered to the returned receiver,
+    /// independently of `try_recv_event()` and other subscribers, so a
+    /// dedicated thread can block on `recv()`. The receiver disconnects when
+    /// the device is dropped.
+    pub fn events(&self) -> std::sync::mpsc::Receiver<CameraEvent> {
+        self.event_sender().subscribe()
+    }
+
+    /// Like `events()`, but with bursts of `PropertyChanged` events merged
+    ///
+    /// Property changes arriving within `window` of each other are delivered
+    /// as one event listing each code once, which avoids redrawing per event
+    /// during the flood that follows connecting. Other events pass through
+    /// unchanged and in order. Merging runs on a helper thread that exits
+    /// when the receiver is dropped or the device disconnects.
+    pub fn events_coalesced(&self, window: Duration) -> std::sync::mpsc::Receiver<CameraEvent> {
+        let events = self.events();
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            coalesce_events(events, window, |event| tx.send(event).is_ok());
+        });
+        rx
     }
 
     /// Try to receive an event without blocking
     ///
     /// Returns `None` if no events are currently available.
-    /// For async code, use `events()` to get a stream instead.
+    /// For async code, use `take_event_receiver()` to get a stream instead.
     pub fn try_recv_event(&mut self) -> Option<CameraEvent> {
         self.event_receiver.try_recv().ok()
     }
@@ -684,6 +3474,39 @@ impl CameraDevice {
     }
 }
 
+#[cfg(feature = "record")]
+impl CameraDevice {
+    /// Open a recorded session as a device, without a camera
+    ///
+    /// Every SDK call is answered from the file written by
+    /// `CameraDeviceBuilder::record_to()`, in the order it was recorded,
+    /// and the recorded camera events are emitted along the way. The device
+    /// is connected once the recording's `Connected` event has been emitted,
+    /// normally right away; `model` is what `model()` reports.
+    ///
+    /// # Panics
+    ///
+    /// Calls panic if they don't match the next recorded call, since the
+    /// code under test no longer behaves as it did when recording.
+    pub fn replay(path: impl AsRef<std::path::Path>, model: CameraModel) -> Result<Self> {
+        let session = Session::replay(path.as_ref())?;
+        let (event_sender, event_receiver) = mpsc::unbounded_channel();
+        let event_sender = EventSender::new(event_sender);
+        session.emit_leading_events(&event_sender);
+
+        Ok(CameraDevice {
+            handle: 0,
+            model,
+            event_receiver,
+            callback_ptr: ptr::null_mut(),
+            event_sender_ptr: event_sender.into_raw(),
+            on_change: None,
+            disconnected: AtomicBool::new(false),
+            session: Some(session),
+        })
+    }
+}
+
 impl Drop for CameraDevice {
     fn drop(&mut self) {
         // IMPORTANT: Order matters here to avoid use-after-free
@@ -698,9 +3521,12 @@ impl Drop for CameraDevice {
         // This order ensures no callbacks can fire after we free memory.
 
         if self.handle != 0 {
-            // SAFETY: handle is valid if non-zero, obtained from SDK Connect
+            // SAFETY: handle is valid if non-zero, obtained from SDK Connect.
+            // An explicit disconnect() already did step 1.
             unsafe {
-                crsdk_sys::SCRSDK::Disconnect(self.handle);
+                if !*self.disconnected.get_mut() {
+                    crsdk_sys::SCRSDK::Disconnect(self.handle);
+                }
                 crsdk_sys::SCRSDK::ReleaseDevice(self.handle);
             }
         }
@@ -728,6 +3554,7 @@ impl Drop for CameraDevice {
 pub struct CameraDeviceBuilder {
     info: ConnectionInfo,
     camera_info_ptr: Option<*mut crsdk_sys::SCRSDK::ICrCameraObjectInfo>,
+    on_change: Option<ChangeHook>,
 }
 
 impl CameraDeviceBuilder {
@@ -748,6 +3575,22 @@ impl CameraDeviceBuilder {
         self
     }
 
+    /// Set the camera's IP address from a string (e.g. CLI or env input)
+    ///
+    /// Returns `Error::InvalidParameter` naming the offending input instead
+    /// of panicking when it is not a valid IPv4 address.
+    pub fn try_ip_address(self, ip: &str) -> Result<Self> {
+        Ok(self.ip_address(parse_ip_address(ip)?))
+    }
+
+    /// Set the camera's MAC address from a string (e.g. CLI or env input)
+    ///
+    /// Returns `Error::InvalidParameter` describing what is wrong with the
+    /// input (wrong number of octets, bad hex) instead of panicking.
+    pub fn try_mac_address(self, mac: &str) -> Result<Self> {
+        Ok(self.mac_address(parse_mac_address(mac)?))
+    }
+
     /// Set the camera model
     pub fn model(mut self, model: CameraModel) -> Self {
         self.info.model = Some(model);
@@ -774,6 +3617,50 @@ impl CameraDeviceBuilder {
         self
     }
 
+    /// Limit how long `connect()` waits for the camera
+    ///
+    /// With a timeout set, `connect()` waits until the camera confirms the
+    /// connection and returns `Error::Timeout` (after disconnecting) if it
+    /// doesn't within `timeout`. Without one, `connect()` returns as soon as
+    /// the SDK accepts the request.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.info.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Wait for the camera's initial properties before `connect()` returns
+    ///
+    /// Runs `wait_ready(timeout)` after connecting, so the first property
+    /// reads don't come back empty. `connect()` fails with `Error::Timeout`
+    /// if the properties don't arrive in time. Without this, `connect()`
+    /// returns immediately and callers can call `wait_ready()` themselves.
+    pub fn ready_timeout(mut self, timeout: Duration) -> Self {
+        self.info.ready_timeout = Some(timeout);
+        self
+    }
+
+    /// Record every SDK call the connected device makes, and every event
+    /// the camera sends, to `path`
+    ///
+    /// The file can be opened later with `CameraDevice::replay()` to run
+    /// the same code without a camera. An existing file is replaced.
+    /// Requires the `record` feature.
+    #[cfg(feature = "record")]
+    pub fn record_to(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.info.record_path = Some(path.into());
+        self
+    }
+
+    /// Call `hook` after every successful `set_property()`
+    ///
+    /// The hook receives the property code, the value before and after the
+    /// write, and a timestamp, e.g. for an audit log of changes this program
+    /// made. It runs on the thread that called the setter, so keep it quick.
+    pub fn on_change(mut self, hook: impl Fn(ChangeRecord) + Send + Sync + 'static) -> Self {
+        self.on_change = Some(Arc::new(hook));
+        self
+    }
+
     /// Fetch SSH fingerprint from camera for user confirmation
     ///
     /// This stores the camera info internally and reuses it for connection.
@@ -817,6 +3704,14 @@ impl CameraDeviceBuilder {
 
     /// Connect to the camera (blocks until connected or error)
     pub fn connect(self) -> Result<CameraDevice> {
+        self.connect_cancellable(&AtomicBool::new(false))
+    }
+
+    /// Connect, giving up early once `cancel` is set
+    ///
+    /// Used by the async builder so that dropping its future aborts the
+    /// wait for the camera instead of leaving a half-open connection.
+    pub(crate) fn connect_cancellable(self, cancel: &AtomicBool) -> Result<CameraDevice> {
         let ip = self
             .info
             .ip_address
@@ -827,6 +3722,12 @@ impl CameraDeviceBuilder {
             .ok_or_else(|| Error::InvalidParameter("MAC address is required".to_string()))?;
         let model = self.info.model.unwrap_or(CameraModel::Fx3);
 
+        #[cfg(feature = "record")]
+        let session = match &self.info.record_path {
+            Some(path) => Some(Session::record(path)?),
+            None => None,
+        };
+
         ensure_sdk_initialized()?;
 
         let camera_info_ptr = match self.camera_info_ptr {
@@ -837,6 +3738,11 @@ impl CameraDeviceBuilder {
         // Create event channel and callback
         let (event_sender, event_receiver) = mpsc::unbounded_channel();
         let event_sender = EventSender::new(event_sender);
+        #[cfg(feature = "record")]
+        let event_sender = match session.as_ref().and_then(Session::recorder) {
+            Some(recorder) => event_sender.record_to(recorder),
+            None => event_sender,
+        };
         let event_sender_ptr = event_sender.into_raw();
 
         // Create the C++ callback that will forward events to our channel
@@ -894,14 +3800,74 @@ impl CameraDeviceBuilder {
             return Err(Error::from_sdk_error(result as u32));
         }
 
-        Ok(CameraDevice {
+        // From here on, dropping the device disconnects and cleans up
+        let mut device = CameraDevice {
             handle: device_handle,
             model,
             event_receiver,
             callback_ptr,
             event_sender_ptr,
-        })
+            on_change: self.on_change.clone(),
+            disconnected: AtomicBool::new(false),
+            #[cfg(feature = "record")]
+            session,
+        };
+
+        if let Some(timeout) = self.info.connect_timeout {
+            wait_for_connected(&mut device, timeout, cancel)?;
+        }
+        if let Some(timeout) = self.info.ready_timeout {
+            device.wait_ready(timeout)?;
+        }
+
+        Ok(device)
+    }
+}
+
+/// Wait for the camera to confirm a connection started with `Connect()`
+///
+/// Events consumed while waiting are re-queued so callers still see them.
+fn wait_for_connected(
+    device: &mut CameraDevice,
+    timeout: Duration,
+    cancel: &AtomicBool,
+) -> Result<()> {
+    let deadline = Instant::now() + timeout;
+    let mut seen = Vec::new();
+
+    loop {
+        match device.event_receiver.try_recv() {
+            Ok(event @ CameraEvent::Connected { .. }) => {
+                seen.push(event);
+                break;
+            }
+            Ok(CameraEvent::Error { code }) => return Err(Error::from_sdk_error(code)),
+            Ok(CameraEvent::Disconnected { error }) => {
+                if matches!(Error::from_sdk_error(error), Error::PairingRequired) {
+                    return Err(Error::PairingRequired);
+                }
+                return Err(Error::ConnectionFailed(format!(
+                    "camera disconnected while connecting (0x{:X})",
+                    error
+                )));
+            }
+            Ok(event) => seen.push(event),
+            Err(_) => {
+                if cancel.load(Ordering::Acquire) {
+                    return Err(Error::Other("connection cancelled".to_string()));
+                }
+                if Instant::now() >= deadline {
+                    return Err(Error::Timeout);
+                }
+                std::thread::sleep(CONNECT_POLL_INTERVAL);
+            }
+        }
+    }
+
+    for event in seen {
+        device.emit_event(event);
     }
+    Ok(())
 }
 
 impl Drop for CameraDeviceBuilder {
@@ -31,7 +31,14 @@
 //! }
 //! ```
 
+mod debounce;
 mod device;
 
+pub use crate::discovery::CameraDelta;
 pub use crate::event::CameraEvent;
-pub use device::{discover_cameras, CameraDevice, CameraDeviceBuilder};
+pub use debounce::DebouncedProperty;
+pub(crate) use device::run_camera_watch;
+pub use device::{
+    discover_cameras, discover_cameras_debug, watch_cameras, CameraDevice, CameraDeviceBuilder,
+    CameraWatcher,
+};
@@ -0,0 +1,219 @@
+//! Capture result types
+
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::error::{Error, Result};
+use crate::event::CameraEvent;
+use crate::property::{
+    Aperture, DeviceProperty, Iso, PropertyValue, StillImageStoreDestination, WhiteBalance,
+};
+use crate::settings::Shutter;
+use crsdk_sys::DevicePropertyCode;
+
+/// Warning code the camera sends when transferring a shot to the host fails
+const WARNING_GET_IMAGE_FAILED: u32 = 0x00020005;
+
+/// A captured still along with the settings that were active when it was taken
+///
+/// Returned by `CameraDevice::capture_with_metadata()`. The settings come from
+/// a single property snapshot taken right before the shutter is released, so
+/// they can't drift between the capture and a later read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptureResult {
+    /// File the camera transferred to the host; `None` for card-only shots
+    pub path: Option<PathBuf>,
+    /// ISO sensitivity
+    pub iso: Option<Iso>,
+    /// Aperture (f-number)
+    pub aperture: Option<Aperture>,
+    /// Shutter speed, or angle when the camera is set to show angles
+    pub shutter: Option<Shutter>,
+    /// White balance mode
+    pub white_balance: Option<WhiteBalance>,
+    /// Host time at which the settings were read
+    pub timestamp: SystemTime,
+}
+
+impl CaptureResult {
+    /// Build a capture result from a property snapshot
+    ///
+    /// Settings that are absent from the snapshot or hold values this crate
+    /// doesn't recognize are left as `None`.
+    pub fn from_properties(props: &[DeviceProperty], timestamp: SystemTime) -> Self {
+        let value = |code: DevicePropertyCode| {
+            props
+                .iter()
+                .find(|p| p.code == code.as_raw())
+                .map(|p| p.current_value)
+        };
+
+        Self {
+            path: None,
+            iso: value(DevicePropertyCode::IsoSensitivity).and_then(Iso::from_raw),
+            aperture: value(DevicePropertyCode::FNumber).and_then(Aperture::from_raw),
+            shutter: Shutter::from_properties(props),
+            white_balance: value(DevicePropertyCode::WhiteBalance).and_then(WhiteBalance::from_raw),
+            timestamp,
+        }
+    }
+}
+
+/// Where a confirmed still capture ended up
+///
+/// Returned by `CameraDevice::capture_confirmed()` once the camera has
+/// reported the shot, so it also proves the shutter actually fired.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaptureOutcome {
+    /// Written to the memory card
+    SavedToCard,
+    /// Transferred to the host
+    TransferredToHost {
+        /// File name the camera reported for the transfer
+        filename: String,
+    },
+    /// Written to the card and transferred to the host
+    Both {
+        /// File name the camera reported for the transfer
+        filename: String,
+    },
+}
+
+impl CaptureOutcome {
+    /// File name of the host transfer, if the shot was transferred
+    pub fn filename(&self) -> Option<&str> {
+        match self {
+            Self::SavedToCard => None,
+            Self::TransferredToHost { filename } | Self::Both { filename } => Some(filename),
+        }
+    }
+}
+
+/// Follows the events after a shutter release until the shot is confirmed
+///
+/// A card write shows up as the slot's remaining shot count changing or the
+/// contents list growing; a host transfer as `DownloadComplete`. When saving
+/// to both, the transfer alone is taken as confirmation, since the camera
+/// only transfers shots it has also written.
+pub(crate) struct CaptureWatch {
+    destination: StillImageStoreDestination,
+    saved_to_card: bool,
+    filename: Option<String>,
+}
+
+impl CaptureWatch {
+    pub(crate) fn new(destination: StillImageStoreDestination) -> Self {
+        Self {
+            destination,
+            saved_to_card: false,
+            filename: None,
+        }
+    }
+
+    /// Feed the next event; returns the outcome once the shot is confirmed
+    pub(crate) fn observe(&mut self, event: &CameraEvent) -> Option<Result<CaptureOutcome>> {
+        match event {
+            CameraEvent::DownloadComplete { filename } => self.filename = Some(filename.clone()),
+            CameraEvent::ContentsListChanged { added, .. } if *added > 0 => {
+                self.saved_to_card = true
+            }
+            CameraEvent::PropertyChanged { codes } => {
+                if codes.iter().any(|c| {
+                    matches!(
+                        c,
+                        DevicePropertyCode::MediaSLOT1RemainingNumber
+                            | DevicePropertyCode::MediaSLOT2RemainingNumber
+                    )
+                }) {
+                    self.saved_to_card = true;
+                }
+            }
+            CameraEvent::Warning { code, .. } if *code == WARNING_GET_IMAGE_FAILED => {
+                return Some(Err(Error::Other(
+                    "camera failed to transfer the image".to_string(),
+                )));
+            }
+            _ => {}
+        }
+
+        match (self.destination, &self.filename) {
+            (StillImageStoreDestination::HostPC, Some(filename)) => {
+                Some(Ok(CaptureOutcome::TransferredToHost {
+                    filename: filename.clone(),
+                }))
+            }
+            (StillImageStoreDestination::Both, Some(filename)) => Some(Ok(CaptureOutcome::Both {
+                filename: filename.clone(),
+            })),
+            (StillImageStoreDestination::MemoryCard, _) if self.saved_to_card => {
+                Some(Ok(CaptureOutcome::SavedToCard))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::prop;
+
+    #[test]
+    fn test_from_properties() {
+        let props = vec![
+            prop(DevicePropertyCode::IsoSensitivity, 800),
+            prop(DevicePropertyCode::FNumber, 280),
+        ];
+        let timestamp = SystemTime::UNIX_EPOCH;
+        let result = CaptureResult::from_properties(&props, timestamp);
+
+        assert_eq!(result.path, None);
+        assert_eq!(result.iso.map(|iso| iso.value()), Some(Some(800)));
+        assert_eq!(
+            result.aperture.map(|a| a.to_string()),
+            Some("f/2.8".to_string())
+        );
+        assert_eq!(result.shutter, None);
+        assert_eq!(result.white_balance, None);
+        assert_eq!(result.timestamp, timestamp);
+    }
+
+    #[test]
+    fn test_capture_watch_card() {
+        let mut watch = CaptureWatch::new(StillImageStoreDestination::MemoryCard);
+        let unrelated = CameraEvent::PropertyChanged {
+            codes: vec![DevicePropertyCode::FNumber],
+        };
+        assert!(watch.observe(&unrelated).is_none());
+
+        let written = CameraEvent::PropertyChanged {
+            codes: vec![DevicePropertyCode::MediaSLOT1RemainingNumber],
+        };
+        assert_eq!(
+            watch.observe(&written).unwrap().unwrap(),
+            CaptureOutcome::SavedToCard
+        );
+    }
+
+    #[test]
+    fn test_capture_watch_host() {
+        let mut watch = CaptureWatch::new(StillImageStoreDestination::Both);
+        let download = CameraEvent::DownloadComplete {
+            filename: "DSC00001.JPG".to_string(),
+        };
+        assert_eq!(
+            watch.observe(&download).unwrap().unwrap(),
+            CaptureOutcome::Both {
+                filename: "DSC00001.JPG".to_string()
+            }
+        );
+        assert_eq!(CaptureOutcome::SavedToCard.filename(), None);
+
+        let mut watch = CaptureWatch::new(StillImageStoreDestination::HostPC);
+        let failed = CameraEvent::Warning {
+            code: WARNING_GET_IMAGE_FAILED,
+            params: None,
+        };
+        assert!(watch.observe(&failed).unwrap().is_err());
+    }
+}
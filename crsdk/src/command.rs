@@ -1,33 +1,219 @@
 //! Camera command types for shooting operations
+//!
+//! Commands are sent with `CameraDevice::send_command()`. See its
+//! documentation for which commands need a follow-up property read to
+//! confirm they took effect.
+
+use crate::error::{Error, Result};
+use crate::property::DeviceProperty;
+use crsdk_sys::DevicePropertyCode;
 
 /// Command IDs for camera operations
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u32)]
 pub enum CommandId {
     /// Full shutter release (take photo)
+    ///
+    /// Param: `Up`/`Down`. Fire-and-forget: send `Down` then `Up` to take a shot.
     Release = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_Release,
     /// Cancel ongoing shooting
+    ///
+    /// Param: `Up`/`Down` (press then release).
     CancelShooting = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_CancelShooting,
+    /// Full format of a memory card
+    ///
+    /// Param: slot number as a `CommandValue` (e.g. `CommandValue::from(1u16)`).
+    /// Destructive. Confirm completion via `MediaFormatProgressRate`.
+    MediaFormat = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_MediaFormat,
+    /// Quick format of a memory card
+    ///
+    /// Param: slot number as a `CommandValue` (e.g. `CommandValue::from(1u16)`).
+    /// Destructive. Confirm completion via `MediaFormatProgressRate`.
+    MediaQuickFormat = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_MediaQuickFormat,
+    /// Cancel an in-progress media format
+    ///
+    /// Param: `CommandValue::none()`.
+    CancelMediaFormat = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_CancelMediaFormat,
     /// Movie recording start/stop
+    ///
+    /// Param: `Down` starts and `Up` stops recording. Confirm via `RecordingState`.
     MovieRecord = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_MovieRecord,
     /// S1 (half-press) and release combined
+    ///
+    /// Param: `Up`/`Down`.
     S1AndRelease = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_S1andRelease,
     /// Toggle movie recording button
+    ///
+    /// Param: `Up`/`Down`. Confirm the resulting state via `RecordingState`.
     MovieRecButtonToggle = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_MovieRecButtonToggle,
     /// Cancel focus position
+    ///
+    /// Param: `Up`/`Down`.
     CancelFocusPosition = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_CancelFocusPosition,
     /// Enable tracking and AF
+    ///
+    /// Param: `Down` while held, `Up` to release.
     TrackingOnAndAfOn = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_TrackingOnAndAFOn,
+    /// Stream button (starts/stops network streaming)
+    ///
+    /// Param: `Down` then `Up` toggles streaming. Confirm via `StreamStatus`.
+    StreamButton = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_StreamButton,
+    /// Run pixel mapping to remap defective sensor pixels
+    ///
+    /// Param: `Down`. Check `PixelMappingEnableStatus` first; the body is busy
+    /// until it reads Enable again.
+    PixelMapping = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_PixelMapping,
+    /// Run the sensor cleaning (dust shake) cycle
+    ///
+    /// Param: `Down`. Check `SensorCleaningEnableStatus` first; the body is
+    /// busy until it reads Enable again.
+    SensorCleaning = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_SensorCleaning,
+    /// Reset file numbering so the next file starts again from 0001
+    ///
+    /// Param: `Down`. Only accepted while `ForcedFileNumberResetEnableStatus` reads Enable.
+    ForcedFileNumberReset = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_ForcedFileNumberReset,
+    /// Reset every camera setting, custom button assignments included, to factory defaults
+    ///
+    /// Param: `Down`. Check `CameraSettingsResetEnableStatus` first; the body
+    /// is busy until it reads Enable again.
+    CameraSettingsReset = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_CameraSettingsReset,
+    /// Reset the current picture profile to its defaults
+    ///
+    /// Param: `Down`. Check `PictureProfileResetEnableStatus` first.
+    PictureProfileReset = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_PictureProfileReset,
+    /// Reset the current creative look to its defaults
+    ///
+    /// Param: `Down`. Check `CreativeLookResetEnableStatus` first.
+    CreativeLookReset = crsdk_sys::SCRSDK::CrCommandId_CrCommandId_CreativeLookReset,
+    /// Temporarily boost the continuous shooting speed
+    ///
+    /// Param: `Down` while held, `Up` to release. Confirm via `ContinuousShootingSpotBoostStatus`.
+    ContinuousShootingSpotBoost =
+        crsdk_sys::SCRSDK::CrCommandId_CrCommandId_ContinuousShootingSpotBoost,
 }
 
 impl CommandId {
+    /// All commands, in declaration order
+    pub const ALL: &'static [CommandId] = &[
+        CommandId::Release,
+        CommandId::CancelShooting,
+        CommandId::MediaFormat,
+        CommandId::MediaQuickFormat,
+        CommandId::CancelMediaFormat,
+        CommandId::MovieRecord,
+        CommandId::S1AndRelease,
+        CommandId::MovieRecButtonToggle,
+        CommandId::CancelFocusPosition,
+        CommandId::TrackingOnAndAfOn,
+        CommandId::StreamButton,
+        CommandId::PixelMapping,
+        CommandId::SensorCleaning,
+        CommandId::ForcedFileNumberReset,
+        CommandId::CameraSettingsReset,
+        CommandId::PictureProfileReset,
+        CommandId::CreativeLookReset,
+        CommandId::ContinuousShootingSpotBoost,
+    ];
+
     /// Get the raw SDK value
     pub fn as_raw(self) -> u32 {
         self as u32
     }
+
+    /// Property a body reports only if it implements this command
+    ///
+    /// `None` for commands no status property tells apart: shooting and AF
+    /// commands every supported body accepts, and media formatting, whose
+    /// per-slot enable status says whether a card can be formatted now
+    /// rather than whether the body implements the command.
+    pub(crate) fn capability(self) -> Option<DevicePropertyCode> {
+        use DevicePropertyCode as C;
+        match self {
+            CommandId::Release
+            | CommandId::CancelShooting
+            | CommandId::S1AndRelease
+            | CommandId::CancelFocusPosition
+            | CommandId::TrackingOnAndAfOn
+            | CommandId::MediaFormat
+            | CommandId::MediaQuickFormat
+            | CommandId::CancelMediaFormat => None,
+            CommandId::MovieRecord | CommandId::MovieRecButtonToggle => Some(C::RecordingState),
+            CommandId::StreamButton => Some(C::StreamStatus),
+            CommandId::PixelMapping => Some(C::PixelMappingEnableStatus),
+            CommandId::SensorCleaning => Some(C::SensorCleaningEnableStatus),
+            CommandId::ForcedFileNumberReset => Some(C::ForcedFileNumberResetEnableStatus),
+            CommandId::CameraSettingsReset => Some(ResetScope::All.enable_status()),
+            CommandId::PictureProfileReset => Some(ResetScope::PictureProfile.enable_status()),
+            CommandId::CreativeLookReset => Some(ResetScope::CreativeLook.enable_status()),
+            CommandId::ContinuousShootingSpotBoost => {
+                Some(C::ContinuousShootingSpotBoostEnableStatus)
+            }
+        }
+    }
+}
+
+/// Commands a body implements, judged from a property snapshot
+pub(crate) fn supported_commands(props: &[DeviceProperty]) -> Vec<CommandId> {
+    CommandId::ALL
+        .iter()
+        .copied()
+        .filter(|cmd| match cmd.capability() {
+            Some(code) => props.iter().any(|p| p.code == code.as_raw()),
+            None => true,
+        })
+        .collect()
+}
+
+/// What `CameraDevice::reset_settings()` resets
+///
+/// The SDK has no reset limited to button assignments; they are only
+/// cleared by `All`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResetScope {
+    /// Every camera setting, including custom button assignments
+    All,
+    /// The current picture profile
+    PictureProfile,
+    /// The current creative look
+    CreativeLook,
+}
+
+impl ResetScope {
+    /// Command that performs this reset
+    pub(crate) fn command(self) -> CommandId {
+        match self {
+            ResetScope::All => CommandId::CameraSettingsReset,
+            ResetScope::PictureProfile => CommandId::PictureProfileReset,
+            ResetScope::CreativeLook => CommandId::CreativeLookReset,
+        }
+    }
+
+    /// Property that reads Enable while this reset can be issued
+    pub(crate) fn enable_status(self) -> DevicePropertyCode {
+        use DevicePropertyCode as C;
+        match self {
+            ResetScope::All => C::CameraSettingsResetEnableStatus,
+            ResetScope::PictureProfile => C::PictureProfileResetEnableStatus,
+            ResetScope::CreativeLook => C::CreativeLookResetEnableStatus,
+        }
+    }
+}
+
+impl std::fmt::Display for ResetScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResetScope::All => write!(f, "camera settings reset"),
+            ResetScope::PictureProfile => write!(f, "picture profile reset"),
+            ResetScope::CreativeLook => write!(f, "creative look reset"),
+        }
+    }
 }
 
 /// Command parameter for button press/release state
+///
+/// Button-style commands take `Up`/`Down`. Commands with a numeric argument
+/// (e.g. a slot number) take a [`CommandValue`] instead, sent with
+/// `CameraDevice::send_command_value()`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum CommandParam {
@@ -44,6 +230,83 @@ impl CommandParam {
     }
 }
 
+impl From<bool> for CommandParam {
+    /// `true` presses (`Down`), `false` releases (`Up`)
+    fn from(pressed: bool) -> Self {
+        if pressed {
+            CommandParam::Down
+        } else {
+            CommandParam::Up
+        }
+    }
+}
+
+/// Numeric command argument, e.g. a media slot number
+///
+/// The SDK passes every command parameter as a 16-bit value. Build one with
+/// `From<u16>`, or use [`CommandValue::none()`] for commands without an
+/// argument:
+///
+/// ```
+/// use crsdk::CommandValue;
+///
+/// assert_eq!(CommandValue::from(2u16).as_raw(), 2);
+/// assert_eq!(CommandValue::none().as_raw(), 0);
+/// ```
+///
+/// Wider integers convert with `TryFrom`, which fails with
+/// `Error::InvalidParameter` for values that don't fit in 16 bits rather
+/// than sending the camera a truncated one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CommandValue(u16);
+
+impl CommandValue {
+    /// Argument for commands that take none
+    ///
+    /// The SDK ignores the parameter for these commands; by convention 0 is
+    /// sent.
+    pub fn none() -> Self {
+        CommandValue(0)
+    }
+
+    /// Get the raw SDK value
+    pub fn as_raw(self) -> u32 {
+        self.0 as u32
+    }
+}
+
+impl From<u16> for CommandValue {
+    fn from(value: u16) -> Self {
+        CommandValue(value)
+    }
+}
+
+impl TryFrom<u32> for CommandValue {
+    type Error = Error;
+
+    fn try_from(value: u32) -> Result<Self> {
+        u16::try_from(value).map(CommandValue).map_err(|_| {
+            Error::InvalidParameter(format!("command value {} doesn't fit in 16 bits", value))
+        })
+    }
+}
+
+impl TryFrom<i32> for CommandValue {
+    type Error = Error;
+
+    fn try_from(value: i32) -> Result<Self> {
+        u16::try_from(value).map(CommandValue).map_err(|_| {
+            Error::InvalidParameter(format!("command value {} doesn't fit in 16 bits", value))
+        })
+    }
+}
+
+impl From<CommandParam> for CommandValue {
+    fn from(param: CommandParam) -> Self {
+        CommandValue(param.as_raw() as u16)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -55,11 +318,22 @@ mod tests {
         let all_commands = [
             CommandId::Release,
             CommandId::CancelShooting,
+            CommandId::MediaFormat,
+            CommandId::MediaQuickFormat,
+            CommandId::CancelMediaFormat,
             CommandId::MovieRecord,
             CommandId::S1AndRelease,
             CommandId::MovieRecButtonToggle,
             CommandId::CancelFocusPosition,
             CommandId::TrackingOnAndAfOn,
+            CommandId::StreamButton,
+            CommandId::PixelMapping,
+            CommandId::SensorCleaning,
+            CommandId::ForcedFileNumberReset,
+            CommandId::CameraSettingsReset,
+            CommandId::PictureProfileReset,
+            CommandId::CreativeLookReset,
+            CommandId::ContinuousShootingSpotBoost,
         ];
         for (i, cmd) in all_commands.iter().enumerate() {
             for (j, other) in all_commands.iter().enumerate() {
@@ -70,9 +344,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_supported_commands() {
+        use crate::test_util::prop;
+
+        let props = [
+            prop(DevicePropertyCode::RecordingState, 0),
+            prop(DevicePropertyCode::PixelMappingEnableStatus, 0),
+        ];
+
+        let supported = supported_commands(&props);
+        assert!(supported.contains(&CommandId::Release));
+        assert!(supported.contains(&CommandId::MovieRecord));
+        assert!(supported.contains(&CommandId::PixelMapping));
+        assert!(!supported.contains(&CommandId::StreamButton));
+        assert!(!supported.contains(&CommandId::SensorCleaning));
+        assert!(supported.contains(&CommandId::CancelMediaFormat));
+        assert_eq!(supported_commands(&[]).len(), 8);
+    }
+
     #[test]
     fn test_command_param_values() {
         assert_eq!(CommandParam::Up.as_raw(), 0);
         assert_eq!(CommandParam::Down.as_raw(), 1);
     }
+
+    #[test]
+    fn test_command_value_conversions() {
+        assert_eq!(CommandValue::from(2u16).as_raw(), 2);
+        assert_eq!(CommandValue::try_from(2u32).unwrap().as_raw(), 2);
+        assert_eq!(CommandValue::try_from(2i32).unwrap().as_raw(), 2);
+        assert!(matches!(
+            CommandValue::try_from(0x1_0002u32),
+            Err(Error::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            CommandValue::try_from(-1i32),
+            Err(Error::InvalidParameter(_))
+        ));
+        assert_eq!(CommandValue::from(CommandParam::Down).as_raw(), 1);
+        assert_eq!(CommandValue::none().as_raw(), 0);
+        assert_eq!(CommandParam::from(true), CommandParam::Down);
+        assert_eq!(CommandParam::from(false), CommandParam::Up);
+    }
 }
@@ -0,0 +1,197 @@
+//! Camera clock date/time
+
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Largest UTC offset in use anywhere (UTC+14:00)
+const MAX_UTC_OFFSET_MINUTES: i32 = 14 * 60;
+
+/// A reading of the camera's clock
+///
+/// The body keeps local time plus the UTC offset of its configured time
+/// zone; `time` is the corresponding instant. Returned by
+/// `CameraDevice::datetime()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CameraDateTime {
+    /// The instant the clock shows
+    pub time: SystemTime,
+    /// Offset of the camera's local time from UTC, in minutes
+    pub utc_offset_minutes: i32,
+}
+
+impl CameraDateTime {
+    /// Create a clock value, failing if the offset is beyond ±14 hours
+    pub fn new(time: SystemTime, utc_offset_minutes: i32) -> Option<Self> {
+        if utc_offset_minutes.abs() > MAX_UTC_OFFSET_MINUTES {
+            return None;
+        }
+        Some(CameraDateTime {
+            time,
+            utc_offset_minutes,
+        })
+    }
+
+    /// Parse the camera's ISO 8601 local time with offset
+    ///
+    /// Accepts both the extended (`2024-03-01T09:30:00+09:00`) and basic
+    /// (`20240301T093000+0900`) forms, with `Z` for UTC.
+    pub fn parse(s: &str) -> Option<Self> {
+        let (date, rest) = s.trim().split_once('T')?;
+        let (time, offset) = rest.split_at(rest.find(['+', '-', 'Z'])?);
+        let date = date.replace('-', "");
+        let time = time.replace(':', "");
+        let offset = offset.replace(':', "");
+        if date.len() != 8 || time.len() != 6 {
+            return None;
+        }
+
+        let field = |s: &str, range: std::ops::Range<usize>| s.get(range)?.parse::<i64>().ok();
+        let (year, month, day) = (
+            field(&date, 0..4)?,
+            field(&date, 4..6)?,
+            field(&date, 6..8)?,
+        );
+        let (hour, minute, second) = (
+            field(&time, 0..2)?,
+            field(&time, 2..4)?,
+            field(&time, 4..6)?,
+        );
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return None;
+        }
+        if hour > 23 || minute > 59 || second > 60 {
+            return None;
+        }
+
+        let offset_minutes = match offset.as_str() {
+            "Z" => 0,
+            _ if offset.len() == 5 => {
+                let minutes = field(&offset, 1..3)? * 60 + field(&offset, 3..5)?;
+                if offset.starts_with('-') {
+                    -minutes
+                } else {
+                    minutes
+                }
+            }
+            _ => return None,
+        };
+
+        let local = days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second;
+        let utc = local - offset_minutes * 60;
+        let time = if utc >= 0 {
+            UNIX_EPOCH + Duration::from_secs(utc as u64)
+        } else {
+            UNIX_EPOCH - Duration::from_secs(utc.unsigned_abs())
+        };
+        CameraDateTime::new(time, i32::try_from(offset_minutes).ok()?)
+    }
+}
+
+impl fmt::Display for CameraDateTime {
+    /// Formats as ISO 8601 local time with offset, e.g. `2024-03-01T09:30:00+09:00`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let utc = match self.time.duration_since(UNIX_EPOCH) {
+            Ok(d) => d.as_secs() as i64,
+            Err(e) => -(e.duration().as_secs_f64().ceil() as i64),
+        };
+        let local = utc + self.utc_offset_minutes as i64 * 60;
+        let (year, month, day) = civil_from_days(local.div_euclid(86_400));
+        let secs = local.rem_euclid(86_400);
+
+        let sign = if self.utc_offset_minutes < 0 {
+            '-'
+        } else {
+            '+'
+        };
+        let offset = self.utc_offset_minutes.abs();
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}{:02}:{:02}",
+            year,
+            month,
+            day,
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60,
+            sign,
+            offset / 60,
+            offset % 60
+        )
+    }
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian (year, month, day) for days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_camera_datetime_format() {
+        // 2024-02-29T23:30:00Z
+        let time = UNIX_EPOCH + Duration::from_secs(1_709_249_400);
+        let utc = CameraDateTime::new(time, 0).unwrap();
+        assert_eq!(utc.to_string(), "2024-02-29T23:30:00+00:00");
+
+        let tokyo = CameraDateTime::new(time, 9 * 60).unwrap();
+        assert_eq!(tokyo.to_string(), "2024-03-01T08:30:00+09:00");
+
+        let newfoundland = CameraDateTime::new(time, -(3 * 60 + 30)).unwrap();
+        assert_eq!(newfoundland.to_string(), "2024-02-29T20:00:00-03:30");
+
+        assert!(CameraDateTime::new(time, 15 * 60).is_none());
+    }
+
+    #[test]
+    fn test_camera_datetime_parse() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_709_249_400);
+        let expected = CameraDateTime::new(time, 9 * 60).unwrap();
+        assert_eq!(
+            CameraDateTime::parse("2024-03-01T08:30:00+09:00"),
+            Some(expected)
+        );
+        assert_eq!(
+            CameraDateTime::parse("20240301T083000+0900"),
+            Some(expected)
+        );
+        assert_eq!(
+            CameraDateTime::parse("2024-02-29T23:30:00Z").map(|d| d.time),
+            Some(time)
+        );
+
+        assert_eq!(CameraDateTime::parse(""), None);
+        assert_eq!(CameraDateTime::parse("2024-13-01T08:30:00+09:00"), None);
+        assert_eq!(CameraDateTime::parse("2024-03-01T08:30:00"), None);
+    }
+
+    #[test]
+    fn test_civil_round_trip() {
+        for days in [-719_468, -1, 0, 59, 11_016, 19_782, 2_932_896] {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y, m, d), days);
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Debounced property writes for slider-style controls (async API)
+//!
+//! The blocking equivalent is `crate::blocking::DebouncedProperty`; both
+//! share the timing logic here.
+
+use std::time::{Duration, Instant};
+
+use crsdk_sys::DevicePropertyCode;
+
+use crate::device::CameraDevice;
+use crate::error::Result;
+use crate::log;
+
+/// How long a debounced property waits for writes to stop by default
+pub(crate) const DEFAULT_QUIET_PERIOD: Duration = Duration::from_millis(150);
+
+/// Coalesces rapid writes to one property, sending only the latest value
+///
+/// Created with `CameraDevice::set_property_debounced()`. `set()` only
+/// remembers the value; `settle()` waits out the quiet period and sends it,
+/// so dragging a slider lands on the final value without sending every
+/// intermediate one. `settle()` can be cancelled, e.g. by losing a
+/// `tokio::select!` to the next input, and simply starts over when called
+/// again. Unlike the blocking sink, dropping this one can't send a pending
+/// value; call `flush()` first.
+pub struct DebouncedProperty<'a> {
+    device: &'a CameraDevice,
+    code: DevicePropertyCode,
+    debounce: Debounce,
+}
+
+impl<'a> DebouncedProperty<'a> {
+    pub(crate) fn new(device: &'a CameraDevice, code: DevicePropertyCode) -> Self {
+        Self {
+            device,
+            code,
+            debounce: Debounce::new(DEFAULT_QUIET_PERIOD),
+        }
+    }
+
+    /// Change how long writes must stop before the value is sent (default 150ms)
+    pub fn with_quiet_period(mut self, quiet: Duration) -> Self {
+        self.debounce.quiet = quiet;
+        self
+    }
+
+    /// The property this sink writes
+    pub fn code(&self) -> DevicePropertyCode {
+        self.code
+    }
+
+    /// Queue `value`, replacing any value not sent yet
+    pub fn set(&mut self, value: u64) {
+        self.debounce.push(value, Instant::now());
+    }
+
+    /// Wait until the quiet period has passed, then send the pending value
+    ///
+    /// Never completes while nothing is pending, so it can sit in a
+    /// `select!` loop next to the input that feeds `set()`.
+    pub async fn settle(&mut self) -> Result<()> {
+        loop {
+            match self.debounce.deadline() {
+                Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+                None => std::future::pending().await,
+            }
+            if let Some(value) = self.debounce.take_due(Instant::now()) {
+                return self.device.set_property(self.code, value).await;
+            }
+        }
+    }
+
+    /// Send the pending value now, without waiting for the quiet period
+    pub async fn flush(&mut self) -> Result<()> {
+        match self.debounce.take() {
+            Some(value) => self.device.set_property(self.code, value).await,
+            None => Ok(()),
+        }
+    }
+
+    /// When `settle()` will send, if anything is pending
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.debounce.deadline()
+    }
+}
+
+impl Drop for DebouncedProperty<'_> {
+    fn drop(&mut self) {
+        if self.debounce.deadline().is_some() {
+            log::warn!(
+                "Dropped a debounced {} with a value not sent",
+                self.code.name()
+            );
+        }
+    }
+}
+
+/// The latest value and when it arrived
+pub(crate) struct Debounce {
+    pub(crate) quiet: Duration,
+    pending: Option<(u64, Instant)>,
+}
+
+impl Debounce {
+    pub(crate) fn new(quiet: Duration) -> Self {
+        Self {
+            quiet,
+            pending: None,
+        }
+    }
+
+    pub(crate) fn push(&mut self, value: u64, now: Instant) {
+        self.pending = Some((value, now));
+    }
+
+    pub(crate) fn deadline(&self) -> Option<Instant> {
+        self.pending.map(|(_, at)| at + self.quiet)
+    }
+
+    pub(crate) fn take_due(&mut self, now: Instant) -> Option<u64> {
+        if self.deadline()? <= now {
+            self.take()
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn take(&mut self) -> Option<u64> {
+        self.pending.take().map(|(value, _)| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debounce_sends_latest_after_quiet_period() {
+        let quiet = Duration::from_millis(100);
+        let start = Instant::now();
+        let mut debounce = Debounce::new(quiet);
+        assert_eq!(debounce.take_due(start), None);
+
+        debounce.push(280, start);
+        debounce.push(320, start + Duration::from_millis(50));
+        debounce.push(400, start + Duration::from_millis(90));
+        assert_eq!(
+            debounce.deadline(),
+            Some(start + Duration::from_millis(190))
+        );
+
+        assert_eq!(debounce.take_due(start + Duration::from_millis(150)), None);
+        assert_eq!(
+            debounce.take_due(start + Duration::from_millis(190)),
+            Some(400)
+        );
+        assert_eq!(debounce.take_due(start + Duration::from_secs(1)), None);
+        assert_eq!(debounce.deadline(), None);
+    }
+}
@@ -4,10 +4,20 @@
 //! For synchronous code, use `crsdk::blocking` instead.
 
 use crate::blocking;
+use crate::discovery::CameraDelta;
 use crate::error::{Error, Result};
-use crate::event::CameraEvent;
-use crate::types::{CameraModel, ConnectionInfo, DiscoveredCamera, MacAddr};
+use crate::event::{CameraEvent, ChangeHook, ChangeRecord};
+use crate::types::{
+    parse_ip_address, parse_mac_address, CameraModel, ConnectionInfo, DiscoveredCamera,
+    DiscoveryDiagnostics, MacAddr,
+};
+use futures_core::Stream;
 use std::net::Ipv4Addr;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
 use tokio::sync::mpsc;
 
 /// Discover cameras connected via network and USB (async version)
@@ -42,6 +52,44 @@ pub async fn discover_cameras(timeout_secs: u8) -> Result<Vec<DiscoveredCamera>>
         .map_err(|e| Error::Other(format!("Task join error: {}", e)))?
 }
 
+/// Discover cameras, also reporting what the SDK enumeration returned (async version)
+///
+/// See `blocking::discover_cameras_debug()`.
+pub async fn discover_cameras_debug(
+    timeout_secs: u8,
+) -> Result<(Vec<DiscoveredCamera>, DiscoveryDiagnostics)> {
+    tokio::task::spawn_blocking(move || blocking::discover_cameras_debug(timeout_secs))
+        .await
+        .map_err(|e| Error::Other(format!("Task join error: {}", e)))?
+}
+
+/// Watch for cameras being powered on, off or changing connection state (async version)
+///
+/// See `blocking::watch_cameras()`. Scanning stops soon after the stream
+/// is dropped.
+pub fn watch_cameras(timeout_secs: u8) -> impl Stream<Item = CameraDelta> {
+    let (tx, rx) = mpsc::unbounded_channel();
+    std::thread::spawn(move || {
+        blocking::run_camera_watch(
+            timeout_secs,
+            |delta| tx.send(delta).is_ok(),
+            || tx.is_closed(),
+        );
+    });
+    CameraDeltaStream(rx)
+}
+
+/// The stream returned by `watch_cameras()`
+struct CameraDeltaStream(mpsc::UnboundedReceiver<CameraDelta>);
+
+impl Stream for CameraDeltaStream {
+    type Item = CameraDelta;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<CameraDelta>> {
+        self.0.poll_recv(cx)
+    }
+}
+
 /// A connected camera device (async API)
 ///
 /// This wraps the blocking `CameraDevice` for use with async runtimes.
@@ -59,6 +107,19 @@ impl CameraDevice {
         CameraDeviceBuilder::new()
     }
 
+    /// Open a recorded session as a device, without a camera
+    ///
+    /// See `blocking::CameraDevice::replay()`. Requires the `record` feature.
+    #[cfg(feature = "record")]
+    pub fn replay(path: impl AsRef<std::path::Path>, model: crate::CameraModel) -> Result<Self> {
+        let mut inner = blocking::CameraDevice::replay(path, model)?;
+        let event_receiver = Some(inner.take_event_receiver());
+        Ok(CameraDevice {
+            inner,
+            event_receiver,
+        })
+    }
+
     /// Get the underlying blocking device
     pub fn into_inner(self) -> blocking::CameraDevice {
         self.inner
@@ -108,12 +169,61 @@ impl CameraDevice {
     pub fn take_event_receiver(&mut self) -> Option<mpsc::UnboundedReceiver<CameraEvent>> {
         self.event_receiver.take()
     }
+
+    /// Take the event receiver with bursts of property changes merged
+    ///
+    /// `PropertyChanged` events arriving within `window` of each other are
+    /// delivered as one event listing each code once, which avoids redrawing
+    /// per event during the flood that follows connecting. Other events pass
+    /// through unchanged and in order. Like `take_event_receiver()`, this
+    /// consumes the receiver and returns `None` if it was already taken.
+    /// Merging runs on one helper thread that exits when the returned
+    /// receiver is dropped or the device disconnects.
+    pub fn events_coalesced(
+        &mut self,
+        window: Duration,
+    ) -> Option<mpsc::UnboundedReceiver<CameraEvent>> {
+        // Events are read from a dispatcher subscription instead, so the
+        // plain receiver is dropped rather than bridged on another thread
+        self.event_receiver.take()?;
+        let events = self.inner.events();
+        let (tx, rx) = mpsc::unbounded_channel();
+        std::thread::spawn(move || {
+            crate::event::coalesce_events(events, window, |event| tx.send(event).is_ok());
+        });
+        Some(rx)
+    }
+
+    /// Get a sink that coalesces rapid writes to `code`
+    ///
+    /// For slider-driven controls: each `set()` on the sink replaces the
+    /// pending value, and `settle()` sends only the latest once writes stop
+    /// for the quiet period. See `DebouncedProperty`.
+    pub fn set_property_debounced(
+        &self,
+        code: crate::DevicePropertyCode,
+    ) -> crate::DebouncedProperty<'_> {
+        crate::DebouncedProperty::new(self, code)
+    }
+
+    /// Fetch a live view frame and compute its histogram
+    ///
+    /// Requires the `image` feature. Decoding runs on the blocking thread
+    /// pool so it doesn't stall other tasks.
+    #[cfg(feature = "image")]
+    pub async fn live_view_histogram(&self) -> Result<crate::Histogram> {
+        let frame = self.get_live_view().await?;
+        tokio::task::spawn_blocking(move || frame.histogram())
+            .await
+            .map_err(|e| Error::Other(format!("Task join error: {}", e)))?
+    }
 }
 
 /// Builder for configuring and connecting to a camera (async API)
 #[derive(Default)]
 pub struct CameraDeviceBuilder {
     info: ConnectionInfo,
+    on_change: Option<ChangeHook>,
 }
 
 impl CameraDeviceBuilder {
@@ -134,6 +244,22 @@ impl CameraDeviceBuilder {
         self
     }
 
+    /// Set the camera's IP address from a string (e.g. CLI or env input)
+    ///
+    /// Returns `Error::InvalidParameter` naming the offending input instead
+    /// of panicking when it is not a valid IPv4 address.
+    pub fn try_ip_address(self, ip: &str) -> Result<Self> {
+        Ok(self.ip_address(parse_ip_address(ip)?))
+    }
+
+    /// Set the camera's MAC address from a string (e.g. CLI or env input)
+    ///
+    /// Returns `Error::InvalidParameter` describing what is wrong with the
+    /// input (wrong number of octets, bad hex) instead of panicking.
+    pub fn try_mac_address(self, mac: &str) -> Result<Self> {
+        Ok(self.mac_address(parse_mac_address(mac)?))
+    }
+
     /// Set the camera model
     pub fn model(mut self, model: CameraModel) -> Self {
         self.info.model = Some(model);
@@ -160,6 +286,48 @@ impl CameraDeviceBuilder {
         self
     }
 
+    /// Limit how long `connect()` waits for the camera
+    ///
+    /// With a timeout set, `connect()` waits until the camera confirms the
+    /// connection and returns `Error::Timeout` (after disconnecting) if it
+    /// doesn't within `timeout`. Without one, `connect()` returns as soon as
+    /// the SDK accepts the request.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.info.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Wait for the camera's initial properties before `connect()` returns
+    ///
+    /// Runs `wait_ready(timeout)` after connecting, so the first property
+    /// reads don't come back empty. `connect()` fails with `Error::Timeout`
+    /// if the properties don't arrive in time. Without this, `connect()`
+    /// returns immediately and callers can call `wait_ready()` themselves.
+    pub fn ready_timeout(mut self, timeout: Duration) -> Self {
+        self.info.ready_timeout = Some(timeout);
+        self
+    }
+
+    /// Record every SDK call the connected device makes to `path`
+    ///
+    /// See `blocking::CameraDeviceBuilder::record_to()`. Requires the
+    /// `record` feature.
+    #[cfg(feature = "record")]
+    pub fn record_to(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.info.record_path = Some(path.into());
+        self
+    }
+
+    /// Call `hook` after every successful `set_property()`
+    ///
+    /// The hook receives the property code, the value before and after the
+    /// write, and a timestamp, e.g. for an audit log of changes this program
+    /// made. It runs on a runtime worker thread, so keep it quick.
+    pub fn on_change(mut self, hook: impl Fn(ChangeRecord) + Send + Sync + 'static) -> Self {
+        self.on_change = Some(Arc::new(hook));
+        self
+    }
+
     /// Fetch SSH fingerprint from camera for user confirmation
     pub async fn fetch_ssh_fingerprint(&mut self) -> Result<String> {
         let info = self.info.clone();
@@ -189,8 +357,15 @@ impl CameraDeviceBuilder {
     }
 
     /// Connect to the camera asynchronously
+    ///
+    /// The returned future is cancellation-safe: if it is dropped before
+    /// completing, the pending connection is torn down instead of being left
+    /// half-open.
     pub async fn connect(self) -> Result<CameraDevice> {
         let info = self.info;
+        let on_change = self.on_change;
+        let cancel = Arc::new(AtomicBool::new(false));
+        let _cancel_on_drop = CancelOnDrop(cancel.clone());
 
         let inner = tokio::task::spawn_blocking(move || {
             let mut builder = blocking::CameraDeviceBuilder::new();
@@ -215,13 +390,28 @@ impl CameraDeviceBuilder {
             if let Some(fp) = info.ssh_fingerprint {
                 builder = builder.ssh_fingerprint(fp);
             }
+            if let Some(timeout) = info.connect_timeout {
+                builder = builder.connect_timeout(timeout);
+            }
+            if let Some(timeout) = info.ready_timeout {
+                builder = builder.ready_timeout(timeout);
+            }
+            #[cfg(feature = "record")]
+            if let Some(path) = info.record_path {
+                builder = builder.record_to(path);
+            }
+            if let Some(hook) = on_change {
+                builder = builder.on_change(move |record| hook(record));
+            }
 
             // For SSH, we need to fetch fingerprint again since we can't reuse across threads
             if info.ssh_enabled && info.ssh_user.is_some() {
                 builder.fetch_ssh_fingerprint()?;
             }
 
-            builder.connect()
+            // If the future is dropped anyway, tokio discards this result and
+            // dropping the device disconnects it
+            builder.connect_cancellable(&cancel)
         })
         .await
         .map_err(|e| Error::Other(format!("Task join error: {}", e)))??;
@@ -237,6 +427,16 @@ impl CameraDeviceBuilder {
     }
 }
 
+/// Sets the flag when dropped, so a blocking connect in progress notices
+/// that the future waiting for it is gone
+struct CancelOnDrop(Arc<AtomicBool>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Release);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -251,4 +451,37 @@ mod tests {
         assert!(builder.info.ip_address.is_some());
         assert!(builder.info.mac_address.is_some());
     }
+
+    #[test]
+    fn test_builder_connect_timeout() {
+        let builder = CameraDevice::builder();
+        assert_eq!(builder.info.connect_timeout, None);
+
+        let builder = builder.connect_timeout(Duration::from_secs(5));
+        assert_eq!(builder.info.connect_timeout, Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_builder_ready_timeout() {
+        let builder = CameraDevice::builder();
+        assert_eq!(builder.info.ready_timeout, None);
+
+        let builder = builder.ready_timeout(Duration::from_secs(10));
+        assert_eq!(builder.info.ready_timeout, Some(Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn test_builder_rejects_bad_addresses() {
+        let err = CameraDevice::builder()
+            .try_ip_address("192.168.1")
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::InvalidParameter(_)));
+
+        let err = CameraDevice::builder()
+            .try_mac_address("00:00:00:00:00:zz")
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("'zz'"));
+    }
 }
@@ -0,0 +1,112 @@
+//! Watching for cameras appearing and disappearing
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::types::DiscoveredCamera;
+
+/// Pause between enumerations while watching for cameras
+pub(crate) const WATCH_RESCAN_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A change in the set of cameras visible to the SDK
+///
+/// Emitted by `watch_cameras()`. The first scan reports every camera already
+/// present as `Added`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CameraDelta {
+    /// A camera showed up
+    Added(DiscoveredCamera),
+    /// A camera is no longer reported
+    Removed(DiscoveredCamera),
+    /// A known camera is still there but changed, e.g. its IP address or
+    /// whether another host is connected to it
+    Updated(DiscoveredCamera),
+}
+
+impl fmt::Display for CameraDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CameraDelta::Added(camera) => write!(f, "Added: {}", camera),
+            CameraDelta::Removed(camera) => write!(f, "Removed: {}", camera),
+            CameraDelta::Updated(camera) => write!(f, "Updated: {}", camera),
+        }
+    }
+}
+
+/// Whether two enumeration entries describe the same body
+///
+/// Network cameras are matched by MAC address, which survives a DHCP
+/// renewal; everything else by how it is reached.
+fn same_camera(a: &DiscoveredCamera, b: &DiscoveredCamera) -> bool {
+    match (a.mac_address, b.mac_address) {
+        (Some(a), Some(b)) => a == b,
+        _ => {
+            a.connection_type == b.connection_type
+                && a.model == b.model
+                && a.name == b.name
+                && a.ip_address == b.ip_address
+                && a.usb_pid == b.usb_pid
+        }
+    }
+}
+
+/// Changes between two enumerations: removals first, then additions and updates
+pub(crate) fn diff_cameras(old: &[DiscoveredCamera], new: &[DiscoveredCamera]) -> Vec<CameraDelta> {
+    let mut deltas: Vec<CameraDelta> = old
+        .iter()
+        .filter(|o| !new.iter().any(|n| same_camera(o, n)))
+        .map(|o| CameraDelta::Removed(o.clone()))
+        .collect();
+
+    for camera in new {
+        match old.iter().find(|o| same_camera(o, camera)) {
+            None => deltas.push(CameraDelta::Added(camera.clone())),
+            Some(previous) if previous != camera => {
+                deltas.push(CameraDelta::Updated(camera.clone()))
+            }
+            Some(_) => {}
+        }
+    }
+    deltas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ConnectionStatus, ConnectionType, MacAddr};
+    use std::net::Ipv4Addr;
+
+    fn camera(mac: u8, ip: u8) -> DiscoveredCamera {
+        DiscoveredCamera {
+            model: "ILME-FX3".to_string(),
+            name: format!("FX3-{}", mac),
+            connection_type: ConnectionType::Network,
+            ip_address: Some(Ipv4Addr::new(192, 168, 1, ip)),
+            mac_address: Some(MacAddr([0, 0, 0, 0, 0, mac])),
+            ssh_supported: true,
+            usb_pid: None,
+            connection_status: ConnectionStatus::Disconnected,
+        }
+    }
+
+    #[test]
+    fn test_diff_cameras() {
+        assert_eq!(
+            diff_cameras(&[], &[camera(1, 10)]),
+            vec![CameraDelta::Added(camera(1, 10))]
+        );
+        assert!(diff_cameras(&[camera(1, 10)], &[camera(1, 10)]).is_empty());
+
+        // Same MAC on a new address is the same body
+        let old = [camera(1, 10), camera(2, 20)];
+        let new = [camera(1, 11), camera(3, 30)];
+        assert_eq!(
+            diff_cameras(&old, &new),
+            vec![
+                CameraDelta::Removed(camera(2, 20)),
+                CameraDelta::Updated(camera(1, 11)),
+                CameraDelta::Added(camera(3, 30)),
+            ]
+        );
+    }
+}
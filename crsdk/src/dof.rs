@@ -0,0 +1,190 @@
+//! Depth of field from lens and focus settings
+
+use std::fmt;
+
+use crate::live_view::{is_sensor_cropped, APSC_CROP_FACTOR};
+use crate::property::{is_unavailable, DeviceProperty};
+use crate::types::CameraModel;
+use crsdk_sys::DevicePropertyCode;
+
+/// Near and far limits of acceptable sharpness, in meters
+///
+/// Returned by `CameraDevice::depth_of_field()`, or computed directly with
+/// [`DepthOfField::compute()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DepthOfField {
+    /// Distance focused at
+    pub focus_distance_m: f64,
+    /// Closest distance in acceptable focus
+    pub near_m: f64,
+    /// Furthest distance in acceptable focus, `None` when it extends to infinity
+    pub far_m: Option<f64>,
+    /// Focusing here keeps everything from half this distance to infinity sharp
+    pub hyperfocal_m: f64,
+}
+
+impl DepthOfField {
+    /// Compute the depth of field for a lens setting
+    ///
+    /// `coc_mm` is the circle of confusion for the sensor area in use, see
+    /// `CameraModel::circle_of_confusion_mm()`. Returns `None` if any input
+    /// isn't positive; a `focus_distance_m` of infinity gives a far limit of
+    /// `None` and a near limit at the hyperfocal distance.
+    pub fn compute(
+        focal_length_mm: f64,
+        f_number: f64,
+        focus_distance_m: f64,
+        coc_mm: f64,
+    ) -> Option<Self> {
+        if [focal_length_mm, f_number, focus_distance_m, coc_mm]
+            .iter()
+            .any(|v| v.is_nan() || *v <= 0.0)
+        {
+            return None;
+        }
+
+        let f = focal_length_mm;
+        let hyperfocal = f * f / (f_number * coc_mm) + f;
+        if focus_distance_m.is_infinite() {
+            return Some(Self {
+                focus_distance_m,
+                near_m: hyperfocal / 1000.0,
+                far_m: None,
+                hyperfocal_m: hyperfocal / 1000.0,
+            });
+        }
+
+        let s = focus_distance_m * 1000.0;
+        let near = s * (hyperfocal - f) / (hyperfocal + s - 2.0 * f);
+        let far = (s < hyperfocal).then(|| s * (hyperfocal - f) / (hyperfocal - s) / 1000.0);
+        Some(Self {
+            focus_distance_m,
+            near_m: near / 1000.0,
+            far_m: far,
+            hyperfocal_m: hyperfocal / 1000.0,
+        })
+    }
+
+    /// Compute the depth of field from a property snapshot
+    ///
+    /// Reads the focal length in mm (`ZoomDistance`), the f-number ×100
+    /// (`FNumber`) and the focus distance (`FocalDistanceInMeter`, taken to
+    /// be in millimeters with `0xFFFFFFFF` meaning infinity). The circle of
+    /// confusion is `model`'s, scaled down when a full frame body is cropped
+    /// to Super 35mm. Returns `None` if any reading is missing or unavailable.
+    pub fn from_properties(props: &[DeviceProperty], model: CameraModel) -> Option<Self> {
+        let value = |code: DevicePropertyCode| {
+            props
+                .iter()
+                .find(|p| p.code == code.as_raw())
+                .map(|p| p.current_value)
+                .filter(|&v| v != 0 && !is_unavailable(code, v))
+        };
+
+        let focal_length = value(DevicePropertyCode::ZoomDistance)?;
+        let f_number = value(DevicePropertyCode::FNumber).filter(|&v| v < 0xFFFE)?;
+        let distance = match value(DevicePropertyCode::FocalDistanceInMeter)? {
+            0xFFFFFFFF => f64::INFINITY,
+            mm => mm as f64 / 1000.0,
+        };
+
+        let coc = if model.is_full_frame() && is_sensor_cropped(props) {
+            model.circle_of_confusion_mm() / f64::from(APSC_CROP_FACTOR)
+        } else {
+            model.circle_of_confusion_mm()
+        };
+
+        Self::compute(focal_length as f64, f_number as f64 / 100.0, distance, coc)
+    }
+
+    /// Total depth of field in meters, `None` when it extends to infinity
+    pub fn total_m(&self) -> Option<f64> {
+        self.far_m.map(|far| far - self.near_m)
+    }
+}
+
+impl fmt::Display for DepthOfField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2}m - ", self.near_m)?;
+        match self.far_m {
+            Some(far) => write!(f, "{:.2}m", far)?,
+            None => write!(f, "∞")?,
+        }
+        write!(f, " (hyperfocal {:.2}m)", self.hyperfocal_m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::{ImagerScanMode, PropertyValue, APSC_S35};
+    use crate::test_util::prop;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 0.01,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_from_properties() {
+        let mut props = vec![
+            prop(DevicePropertyCode::ZoomDistance, 50),
+            prop(DevicePropertyCode::FNumber, 280),
+            prop(DevicePropertyCode::FocalDistanceInMeter, 3000),
+        ];
+        let dof = DepthOfField::from_properties(&props, CameraModel::Fx3).unwrap();
+        assert_close(dof.focus_distance_m, 3.0);
+        assert_close(dof.hyperfocal_m, 29.81);
+
+        // Super 35mm on a full frame body uses the smaller circle of confusion,
+        // the same one the APS-C FX30 always uses
+        props.push(prop(
+            DevicePropertyCode::ImagerScanMode,
+            ImagerScanMode::Super35mm.to_raw(),
+        ));
+        let cropped = DepthOfField::from_properties(&props, CameraModel::Fx3).unwrap();
+        let fx30 = DepthOfField::from_properties(&props, CameraModel::Fx30).unwrap();
+        assert_close(cropped.hyperfocal_m, fx30.hyperfocal_m);
+        assert!(cropped.hyperfocal_m > dof.hyperfocal_m);
+
+        // Bodies without ImagerScanMode fall back to the APS-C/S35 setting
+        props[3] = prop(DevicePropertyCode::APSCS35, APSC_S35::On.to_raw());
+        let setting = DepthOfField::from_properties(&props, CameraModel::Fx3).unwrap();
+        assert_close(setting.hyperfocal_m, cropped.hyperfocal_m);
+
+        props[2] = prop(DevicePropertyCode::FocalDistanceInMeter, 0xFFFFFFFF);
+        let dof = DepthOfField::from_properties(&props, CameraModel::Fx3).unwrap();
+        assert_eq!(dof.far_m, None);
+
+        props.remove(0);
+        assert_eq!(
+            DepthOfField::from_properties(&props, CameraModel::Fx3),
+            None
+        );
+    }
+
+    #[test]
+    fn test_compute() {
+        // 50mm f/2.8 at 3m on full frame
+        let dof = DepthOfField::compute(50.0, 2.8, 3.0, 0.030).unwrap();
+        assert_close(dof.hyperfocal_m, 29.81);
+        assert_close(dof.near_m, 2.73);
+        assert_close(dof.far_m.unwrap(), 3.33);
+        assert_close(dof.total_m().unwrap(), 0.60);
+
+        // Beyond the hyperfocal distance everything to infinity is sharp
+        let dof = DepthOfField::compute(24.0, 8.0, 5.0, 0.030).unwrap();
+        assert_eq!(dof.far_m, None);
+        assert_eq!(dof.total_m(), None);
+
+        let dof = DepthOfField::compute(24.0, 8.0, f64::INFINITY, 0.030).unwrap();
+        assert_eq!(dof.near_m, dof.hyperfocal_m);
+
+        assert_eq!(DepthOfField::compute(50.0, 0.0, 3.0, 0.030), None);
+        assert_eq!(DepthOfField::compute(50.0, 2.8, f64::NAN, 0.030), None);
+    }
+}
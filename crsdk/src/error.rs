@@ -48,6 +48,17 @@ pub enum Error {
     #[error("Device disconnected unexpectedly")]
     Disconnected,
 
+    /// Camera connection isn't live (not yet confirmed, or lost)
+    #[error("Camera is not connected")]
+    NotConnected,
+
+    /// Camera refused remote control because it isn't enabled on the body
+    #[error(
+        "Camera refused the connection: enable remote control on the camera \
+         (Network > PC Remote Function > PC Remote: On) and connect again"
+    )]
+    PairingRequired,
+
     /// Property is not writable
     #[error("Property is not writable")]
     PropertyNotWritable,
@@ -60,6 +71,18 @@ pub enum Error {
     #[error("Invalid property value")]
     InvalidPropertyValue,
 
+    /// Operation or value not supported by this camera
+    #[error("Not supported by this camera: {0}")]
+    Unsupported(String),
+
+    /// No new live view frame since the last fetch
+    #[error("Live view frame not updated")]
+    FrameNotUpdated,
+
+    /// Camera is in a state that doesn't allow the operation
+    #[error("Invalid state: {0}")]
+    InvalidState(String),
+
     /// I/O error
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
@@ -73,14 +96,62 @@ pub enum Error {
     Other(String),
 }
 
+/// Broad class of an [`Error`], for mapping errors onto e.g. HTTP statuses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// The camera or property doesn't exist (404)
+    NotFound,
+    /// The caller asked for something invalid or unsupported (400/422)
+    InvalidInput,
+    /// The camera's current state doesn't allow it; may succeed later (409)
+    Busy,
+    /// The camera didn't respond in time (504)
+    Timeout,
+    /// The camera isn't connected or reachable (503)
+    Unavailable,
+    /// SDK, I/O or other failure on this side (500)
+    Internal,
+}
+
 impl Error {
+    /// Broad class of this error
+    ///
+    /// Every variant is mapped explicitly, so adding a variant means
+    /// deciding its category here.
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Error::CameraNotFound | Error::PropertyNotSupported => ErrorCategory::NotFound,
+            Error::InvalidParameter(_)
+            | Error::InvalidPropertyValue
+            | Error::Unsupported(_)
+            | Error::AddrParse(_)
+            | Error::SshAuthFailed => ErrorCategory::InvalidInput,
+            Error::PropertyNotWritable | Error::InvalidState(_) | Error::FrameNotUpdated => {
+                ErrorCategory::Busy
+            }
+            Error::Timeout => ErrorCategory::Timeout,
+            Error::ConnectionFailed(_)
+            | Error::Disconnected
+            | Error::NotConnected
+            | Error::PairingRequired => ErrorCategory::Unavailable,
+            Error::InitFailed
+            | Error::SdkError(_)
+            | Error::AdapterError(_)
+            | Error::OutOfMemory
+            | Error::Io(_)
+            | Error::Other(_) => ErrorCategory::Internal,
+        }
+    }
+
     /// Create error from SDK error code
     pub fn from_sdk_error(code: u32) -> Self {
         match code {
             0x0000 => Self::Other("Success (not an error)".to_string()),
+            CONNECT_REJECTED => Self::PairingRequired,
             0x8200..=0x82FF => Self::ConnectionFailed(format!("Error code: 0x{:X}", code)),
             0x8300..=0x83FF => Self::OutOfMemory,
             0x8700..=0x87FF => Self::AdapterError(adapter_error_message(code)),
+            0x00020010 => Self::FrameNotUpdated,
             _ => Self::SdkError(code),
         }
     }
@@ -95,6 +166,10 @@ impl Error {
     }
 }
 
+/// `CrError_Connect_FailRejected`: the body turned the connection down,
+/// which is what happens when PC Remote isn't enabled in its menu
+const CONNECT_REJECTED: u32 = 0x8203;
+
 fn adapter_error_message(code: u32) -> String {
     let base_msg = match code {
         0x8700 => "Unknown adapter error",
@@ -127,6 +202,41 @@ mod tests {
         assert!(matches!(err, Error::ConnectionFailed(_)));
     }
 
+    #[test]
+    fn test_pairing_required() {
+        let err = Error::from_sdk_error(0x8203);
+        assert!(matches!(err, Error::PairingRequired));
+        assert!(err.to_string().contains("PC Remote"));
+        assert_eq!(err.category(), ErrorCategory::Unavailable);
+    }
+
+    #[test]
+    fn test_frame_not_updated() {
+        let err = Error::from_sdk_error(0x00020010);
+        assert!(matches!(err, Error::FrameNotUpdated));
+    }
+
+    #[test]
+    fn test_error_category() {
+        assert_eq!(
+            Error::PropertyNotSupported.category(),
+            ErrorCategory::NotFound
+        );
+        assert_eq!(
+            Error::InvalidParameter("clip name".to_string()).category(),
+            ErrorCategory::InvalidInput
+        );
+        assert_eq!(
+            Error::InvalidState("recording".to_string()).category(),
+            ErrorCategory::Busy
+        );
+        assert_eq!(Error::NotConnected.category(), ErrorCategory::Unavailable);
+        assert_eq!(
+            Error::from_sdk_error(0x8703).category(),
+            ErrorCategory::Internal
+        );
+    }
+
     #[test]
     fn test_adapter_error() {
         let err = Error::from_sdk_error(0x8703);
@@ -3,10 +3,15 @@
 //! Events are delivered asynchronously when the camera state changes.
 //! Use `CameraDevice::events()` to receive them.
 
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
 use crsdk_sys::DevicePropertyCode;
 
 /// Events received from the camera via SDK callbacks
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum CameraEvent {
     /// Connection established with the camera
@@ -26,6 +31,7 @@ pub enum CameraEvent {
     /// Call `camera.get_property()` to read the new values.
     PropertyChanged {
         /// Property codes that changed
+        #[cfg_attr(feature = "serde", serde(with = "codes_by_name"))]
         codes: Vec<DevicePropertyCode>,
     },
 
@@ -95,72 +101,234 @@ pub enum CameraEvent {
         added: u32,
     },
 
+    /// New shots were written to a memory card
+    ///
+    /// Sent right after the matching `ContentsListChanged`, so a host can
+    /// start pulling new files as soon as they exist instead of polling the
+    /// card.
+    ContentAdded(ContentInfo),
+
     /// Firmware update progress
     FirmwareUpdateProgress {
         /// Notification type
         notify: u32,
     },
+
+    /// Media format progress (emitted by `CameraDevice::format_media()`)
+    MediaFormatProgress {
+        /// Slot number being formatted
+        slot: u32,
+        /// Progress percentage (0-100)
+        percent: u32,
+    },
+}
+
+/// New content reported by [`CameraEvent::ContentAdded`]
+///
+/// The SDK's contents notification only says where and how many; look the
+/// files up on the card to get their names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContentInfo {
+    /// Memory card slot the content was written to
+    pub slot: u32,
+    /// Number of items added
+    pub count: u32,
+}
+
+/// A property change issued by this program, as reported to the
+/// `on_change` hook
+///
+/// Unlike [`CameraEvent::PropertyChanged`], which reports changes the camera
+/// notices (including ones made on the body), these record writes made
+/// through `CameraDevice::set_property()`, `set_property_string()` and the
+/// setters built on them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeRecord {
+    /// Property that was written
+    pub code: DevicePropertyCode,
+    /// Raw value read just before the write
+    pub old_value: u64,
+    /// Raw value that was written (0 for string writes)
+    pub new_value: u64,
+    /// For string properties, the text read just before the write
+    pub old_string: Option<String>,
+    /// For string properties, the text that was written
+    pub new_string: Option<String>,
+    /// Host time at which the camera accepted the write
+    pub timestamp: SystemTime,
+}
+
+/// Callback invoked with each [`ChangeRecord`]
+pub(crate) type ChangeHook = Arc<dyn Fn(ChangeRecord) + Send + Sync>;
+
+/// A warning code the camera can report through `CameraEvent::Warning`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WarningInfo {
+    /// Raw SDK warning code
+    pub code: u32,
+    /// Short name, as returned by [`warning_code_name`]
+    pub name: &'static str,
+    /// What the warning means
+    pub description: &'static str,
+}
+
+/// A known meaning of a warning's first parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WarningParamInfo {
+    /// Raw SDK warning code the parameter belongs to
+    pub code: u32,
+    /// Value of the first parameter
+    pub param: i32,
+    /// Meaning, as returned by [`warning_param_description`]
+    pub description: &'static str,
+}
+
+/// Every warning code this crate knows, in code order
+pub const WARNING_CODES: &[WarningInfo] = &[
+    // Standard warnings (0x0002xxxx)
+    warning(0x00020000, "Unknown", "Unspecified warning"),
+    warning(
+        0x00020001,
+        "Reconnected",
+        "The connection dropped and was re-established",
+    ),
+    warning(
+        0x00020002,
+        "Reconnecting",
+        "The connection dropped; the SDK is retrying",
+    ),
+    warning(
+        0x00020003,
+        "Storage Full",
+        "The host download folder is out of space",
+    ),
+    warning(
+        0x00020004,
+        "SetFileName Failed",
+        "The requested save file name was rejected",
+    ),
+    warning(
+        0x00020005,
+        "GetImage Failed",
+        "Transferring a captured image to the host failed",
+    ),
+    warning(
+        0x00020007,
+        "Network Error",
+        "A network error interrupted communication",
+    ),
+    warning(
+        0x00020008,
+        "Network Recovered",
+        "Communication resumed after a network error",
+    ),
+    warning(
+        0x00020009,
+        "Format Failed",
+        "Formatting the memory card failed",
+    ),
+    warning(
+        0x0002000A,
+        "Format Invalid",
+        "The memory card can't be formatted in its current state",
+    ),
+    warning(
+        0x0002000B,
+        "Format Complete",
+        "Formatting the memory card finished",
+    ),
+    warning(
+        0x00020010,
+        "Frame Not Updated",
+        "No new live view frame since the last request",
+    ),
+    warning(
+        0x00020012,
+        "Already Connected",
+        "Another host already holds the connection",
+    ),
+    // Extended warnings (0x0006xxxx)
+    warning(0x00060000, "Ext Unknown", "Unspecified extended warning"),
+    warning(
+        0x00060001,
+        "AF Status",
+        "Autofocus state changed; the parameter holds the state",
+    ),
+    warning(
+        0x00060002,
+        "Operation Results",
+        "Result of a remote operation; the parameter holds the outcome",
+    ),
+    warning(
+        0x00060003,
+        "Operation Invalid",
+        "The camera rejected an operation in its current state",
+    ),
+    warning(
+        0x00060004,
+        "PTZF Result",
+        "Result of a pan/tilt/zoom/focus operation",
+    ),
+    warning(0x00060005, "Preset PTZF Clear", "A PTZF preset was cleared"),
+    warning(0x00060006, "Preset PTZF Set", "A PTZF preset was stored"),
+    warning(
+        0x00060007,
+        "Preset PTZF Event",
+        "A PTZF preset was recalled",
+    ),
+];
+
+/// Every warning parameter meaning this crate knows, grouped by code
+pub const WARNING_PARAMS: &[WarningParamInfo] = &[
+    // AF Status (0x00060001)
+    param(0x00060001, 0x01, "Unlocked"),
+    param(0x00060001, 0x02, "Focused (AF-S)"),
+    param(0x00060001, 0x03, "Not Focused (AF-S)"),
+    param(0x00060001, 0x05, "Tracking Subject (AF-C)"),
+    param(0x00060001, 0x06, "Focused (AF-C)"),
+    param(0x00060001, 0x07, "Not Focused (AF-C)"),
+    param(0x00060001, 0x08, "Unpaused"),
+    param(0x00060001, 0x09, "Paused"),
+    // Operation Results (0x00060002)
+    param(0x00060002, 0, "Invalid"),
+    param(0x00060002, 1, "OK"),
+    param(0x00060002, 2, "NG"),
+    param(0x00060002, 3, "Invalid Parameter"),
+    param(0x00060002, 4, "Camera Status Error"),
+    param(0x00060002, 5, "Canceled"),
+];
+
+const fn warning(code: u32, name: &'static str, description: &'static str) -> WarningInfo {
+    WarningInfo {
+        code,
+        name,
+        description,
+    }
+}
+
+const fn param(code: u32, param: i32, description: &'static str) -> WarningParamInfo {
+    WarningParamInfo {
+        code,
+        param,
+        description,
+    }
 }
 
 /// Get a human-readable name for a warning code
 pub fn warning_code_name(code: u32) -> &'static str {
-    match code {
-        // Standard warnings (0x0002xxxx)
-        0x00020000 => "Unknown",
-        0x00020001 => "Reconnected",
-        0x00020002 => "Reconnecting",
-        0x00020003 => "Storage Full",
-        0x00020004 => "SetFileName Failed",
-        0x00020005 => "GetImage Failed",
-        0x00020007 => "Network Error",
-        0x00020008 => "Network Recovered",
-        0x00020009 => "Format Failed",
-        0x0002000A => "Format Invalid",
-        0x0002000B => "Format Complete",
-        0x00020010 => "Frame Not Updated",
-        0x00020012 => "Already Connected",
-
-        // Extended warnings (0x0006xxxx)
-        0x00060000 => "Ext Unknown",
-        0x00060001 => "AF Status",
-        0x00060002 => "Operation Results",
-        0x00060003 => "Operation Invalid",
-        0x00060004 => "PTZF Result",
-        0x00060005 => "Preset PTZF Clear",
-        0x00060006 => "Preset PTZF Set",
-        0x00060007 => "Preset PTZF Event",
-
-        _ => "Unknown Warning",
-    }
+    WARNING_CODES
+        .iter()
+        .find(|w| w.code == code)
+        .map_or("Unknown Warning", |w| w.name)
 }
 
 /// Get a human-readable description for warning parameters
 pub fn warning_param_description(code: u32, p1: i32) -> Option<&'static str> {
-    match code {
-        // AF Status (0x00060001)
-        0x00060001 => match p1 {
-            0x01 => Some("Unlocked"),
-            0x02 => Some("Focused (AF-S)"),
-            0x03 => Some("Not Focused (AF-S)"),
-            0x05 => Some("Tracking Subject (AF-C)"),
-            0x06 => Some("Focused (AF-C)"),
-            0x07 => Some("Not Focused (AF-C)"),
-            0x08 => Some("Unpaused"),
-            0x09 => Some("Paused"),
-            _ => None,
-        },
-        // Operation Results (0x00060002)
-        0x00060002 => match p1 {
-            0 => Some("Invalid"),
-            1 => Some("OK"),
-            2 => Some("NG"),
-            3 => Some("Invalid Parameter"),
-            4 => Some("Camera Status Error"),
-            5 => Some("Canceled"),
-            _ => None,
-        },
-        _ => None,
-    }
+    WARNING_PARAMS
+        .iter()
+        .find(|p| p.code == code && p.param == p1)
+        .map(|p| p.description)
 }
 
 impl std::fmt::Display for CameraEvent {
@@ -203,13 +371,112 @@ impl std::fmt::Display for CameraEvent {
             CameraEvent::ContentsListChanged { added, .. } => {
                 write!(f, "ContentsListChanged: {} items added", added)
             }
+            CameraEvent::ContentAdded(info) => {
+                write!(
+                    f,
+                    "ContentAdded: {} items in slot {}",
+                    info.count, info.slot
+                )
+            }
             CameraEvent::FirmwareUpdateProgress { notify } => {
                 write!(f, "FirmwareUpdateProgress (notify: {})", notify)
             }
+            CameraEvent::MediaFormatProgress { slot, percent } => {
+                write!(f, "MediaFormatProgress: slot {} {}%", slot, percent)
+            }
         }
     }
 }
 
+/// Forward events from `rx` to `emit`, merging bursts of property changes
+///
+/// `PropertyChanged` events arriving within `window` of the first one are
+/// merged into a single event listing each code once. Any other event
+/// flushes the pending merge first, so ordering is preserved. Returns when
+/// `rx` disconnects or `emit` returns `false`.
+pub(crate) fn coalesce_events(
+    rx: Receiver<CameraEvent>,
+    window: Duration,
+    mut emit: impl FnMut(CameraEvent) -> bool,
+) {
+    let mut pending: Vec<DevicePropertyCode> = Vec::new();
+    let mut deadline = Instant::now();
+
+    loop {
+        let next = if pending.is_empty() {
+            rx.recv().map_err(|_| RecvTimeoutError::Disconnected)
+        } else {
+            rx.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+        };
+
+        match next {
+            Ok(CameraEvent::PropertyChanged { codes }) => {
+                if pending.is_empty() {
+                    deadline = Instant::now() + window;
+                }
+                for code in codes {
+                    if !pending.contains(&code) {
+                        pending.push(code);
+                    }
+                }
+            }
+            Ok(event) => {
+                if !pending.is_empty() {
+                    let codes = std::mem::take(&mut pending);
+                    if !emit(CameraEvent::PropertyChanged { codes }) {
+                        return;
+                    }
+                }
+                if !emit(event) {
+                    return;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let codes = std::mem::take(&mut pending);
+                if !emit(CameraEvent::PropertyChanged { codes }) {
+                    return;
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                if !pending.is_empty() {
+                    emit(CameraEvent::PropertyChanged { codes: pending });
+                }
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod codes_by_name {
+    use crsdk_sys::DevicePropertyCode;
+    use serde::ser::SerializeSeq;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        codes: &[DevicePropertyCode],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(codes.len()))?;
+        for code in codes {
+            seq.serialize_element(&format!("{:?}", code))?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<DevicePropertyCode>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .into_iter()
+            .map(|name| {
+                DevicePropertyCode::from_name(&name)
+                    .ok_or_else(|| de::Error::custom(format!("unknown property: {}", name)))
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -227,6 +494,12 @@ mod tests {
 
         let event = CameraEvent::PropertyChanged { codes: vec![] };
         assert_eq!(event.to_string(), "PropertyChanged (0 properties)");
+
+        let event = CameraEvent::MediaFormatProgress {
+            slot: 1,
+            percent: 40,
+        };
+        assert_eq!(event.to_string(), "MediaFormatProgress: slot 1 40%");
     }
 
     #[test]
@@ -243,4 +516,64 @@ mod tests {
             panic!("Clone failed");
         }
     }
+
+    #[test]
+    fn test_coalesce_events() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let changed = |codes: &[DevicePropertyCode]| CameraEvent::PropertyChanged {
+            codes: codes.to_vec(),
+        };
+        tx.send(changed(&[DevicePropertyCode::FNumber])).unwrap();
+        tx.send(changed(&[
+            DevicePropertyCode::IsoSensitivity,
+            DevicePropertyCode::FNumber,
+        ]))
+        .unwrap();
+        tx.send(CameraEvent::Warning {
+            code: 1,
+            params: None,
+        })
+        .unwrap();
+        tx.send(changed(&[DevicePropertyCode::IsoSensitivity]))
+            .unwrap();
+        drop(tx);
+
+        let mut out = Vec::new();
+        coalesce_events(rx, Duration::from_secs(60), |event| {
+            out.push(event);
+            true
+        });
+
+        assert_eq!(out.len(), 3);
+        match &out[0] {
+            CameraEvent::PropertyChanged { codes } => assert_eq!(
+                codes,
+                &[
+                    DevicePropertyCode::FNumber,
+                    DevicePropertyCode::IsoSensitivity
+                ]
+            ),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(matches!(out[1], CameraEvent::Warning { code: 1, .. }));
+        assert!(matches!(
+            &out[2],
+            CameraEvent::PropertyChanged { codes } if codes == &[DevicePropertyCode::IsoSensitivity]
+        ));
+    }
+
+    #[test]
+    fn test_warning_tables() {
+        assert_eq!(warning_code_name(0x00020003), "Storage Full");
+        assert_eq!(warning_code_name(0x0002FFFF), "Unknown Warning");
+        assert_eq!(warning_param_description(0x00060002, 1), Some("OK"));
+        assert_eq!(warning_param_description(0x00060002, 9), None);
+
+        for (i, w) in WARNING_CODES.iter().enumerate() {
+            assert!(WARNING_CODES[i + 1..].iter().all(|o| o.code != w.code));
+        }
+        for p in WARNING_PARAMS {
+            assert!(WARNING_CODES.iter().any(|w| w.code == p.code));
+        }
+    }
 }
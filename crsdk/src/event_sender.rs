@@ -2,7 +2,8 @@
 //!
 //! This module provides the FFI functions that C++ callbacks call to send events
 //! to Rust. The EventSender wraps a tokio::sync::mpsc::UnboundedSender and is
-//! passed to C++ as a void pointer.
+//! passed to C++ as a void pointer. Blocking consumers can additionally
+//! subscribe with a std channel; every event is copied to each subscriber.
 //!
 //! # Safety
 //!
@@ -10,9 +11,15 @@
 //! pointer obtained from `EventSender::into_raw()`, and must not use the pointer
 //! after calling `EventSender::from_raw()` to reclaim it.
 
-use crate::event::CameraEvent;
+use crate::event::{CameraEvent, ContentInfo};
+#[cfg(feature = "record")]
+use crate::session::{Interaction, Recorder};
 use crsdk_sys::DevicePropertyCode;
 use std::ffi::c_void;
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "record")]
+use std::sync::Arc;
+use std::sync::{mpsc as std_mpsc, Mutex};
 use tokio::sync::mpsc::UnboundedSender;
 
 /// Wrapper around a channel sender for passing to C++
@@ -21,12 +28,31 @@ use tokio::sync::mpsc::UnboundedSender;
 /// C++ callback functions will call back into Rust with this pointer.
 pub struct EventSender {
     sender: UnboundedSender<CameraEvent>,
+    subscribers: Mutex<Vec<std_mpsc::Sender<CameraEvent>>>,
+    /// Tracks the latest Connected/Disconnected event
+    connected: AtomicBool,
+    /// Session file every event is also written to
+    #[cfg(feature = "record")]
+    recorder: Option<Arc<Recorder>>,
 }
 
 impl EventSender {
     /// Create a new EventSender wrapping the given channel sender
     pub fn new(sender: UnboundedSender<CameraEvent>) -> Self {
-        Self { sender }
+        Self {
+            sender,
+            subscribers: Mutex::new(Vec::new()),
+            connected: AtomicBool::new(false),
+            #[cfg(feature = "record")]
+            recorder: None,
+        }
+    }
+
+    /// Also write every event to a session recording
+    #[cfg(feature = "record")]
+    pub(crate) fn record_to(mut self, recorder: Arc<Recorder>) -> Self {
+        self.recorder = Some(recorder);
+        self
     }
 
     /// Convert to a raw pointer for passing to C++
@@ -52,9 +78,49 @@ impl EventSender {
     ///
     /// This is non-blocking and will never fail (unbounded channel).
     /// If the receiver is dropped, the event is silently discarded.
-    fn send(&self, event: CameraEvent) {
+    pub(crate) fn send(&self, event: CameraEvent) {
+        match event {
+            CameraEvent::Connected { .. } => self.connected.store(true, Ordering::Release),
+            CameraEvent::Disconnected { .. } => self.connected.store(false, Ordering::Release),
+            _ => {}
+        }
+        if let Ok(mut subscribers) = self.subscribers.lock() {
+            // Drop subscribers whose receiver has gone away
+            subscribers.retain(|tx| tx.send(event.clone()).is_ok());
+        }
         let _ = self.sender.send(event);
     }
+
+    /// Send an event that came from the SDK callbacks
+    ///
+    /// Same as `send()`, but the event is also written to the session
+    /// recording, if any. Events the host emits itself (e.g. format progress)
+    /// go through `send()`, since a replay produces those again.
+    pub(crate) fn send_from_camera(&self, event: CameraEvent) {
+        #[cfg(feature = "record")]
+        if let Some(recorder) = &self.recorder {
+            recorder.append(&Interaction::Event {
+                event: event.clone(),
+            });
+        }
+        self.send(event);
+    }
+
+    /// Whether the last connection event was `Connected`
+    pub(crate) fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Acquire)
+    }
+
+    /// Register a std channel that receives a copy of every event
+    ///
+    /// The receiver disconnects once this EventSender is dropped.
+    pub(crate) fn subscribe(&self) -> std_mpsc::Receiver<CameraEvent> {
+        let (tx, rx) = std_mpsc::channel();
+        if let Ok(mut subscribers) = self.subscribers.lock() {
+            subscribers.push(tx);
+        }
+        rx
+    }
 }
 
 // =============================================================================
@@ -74,7 +140,7 @@ pub extern "C" fn crsdk_event_connected(ctx: *mut c_void, version: u32) {
     }
     // SAFETY: C++ guarantees ctx is a valid EventSender pointer
     let sender = unsafe { &*(ctx as *const EventSender) };
-    sender.send(CameraEvent::Connected { version });
+    sender.send_from_camera(CameraEvent::Connected { version });
 }
 
 #[no_mangle]
@@ -84,7 +150,7 @@ pub extern "C" fn crsdk_event_disconnected(ctx: *mut c_void, error: u32) {
     }
     // SAFETY: C++ guarantees ctx is a valid EventSender pointer
     let sender = unsafe { &*(ctx as *const EventSender) };
-    sender.send(CameraEvent::Disconnected { error });
+    sender.send_from_camera(CameraEvent::Disconnected { error });
 }
 
 #[no_mangle]
@@ -106,7 +172,7 @@ pub extern "C" fn crsdk_event_property_changed(ctx: *mut c_void, num: u32, codes
             .collect()
     };
 
-    sender.send(CameraEvent::PropertyChanged { codes });
+    sender.send_from_camera(CameraEvent::PropertyChanged { codes });
 }
 
 #[no_mangle]
@@ -124,7 +190,7 @@ pub extern "C" fn crsdk_event_lv_property_changed(ctx: *mut c_void, num: u32, co
         unsafe { std::slice::from_raw_parts(codes, num as usize).to_vec() }
     };
 
-    sender.send(CameraEvent::LiveViewPropertyChanged { codes });
+    sender.send_from_camera(CameraEvent::LiveViewPropertyChanged { codes });
 }
 
 #[no_mangle]
@@ -146,7 +212,7 @@ pub extern "C" fn crsdk_event_download_complete(ctx: *mut c_void, filename: *con
         }
     };
 
-    sender.send(CameraEvent::DownloadComplete { filename });
+    sender.send_from_camera(CameraEvent::DownloadComplete { filename });
 }
 
 #[no_mangle]
@@ -173,7 +239,7 @@ pub extern "C" fn crsdk_event_contents_transfer(
         })
     };
 
-    sender.send(CameraEvent::ContentsTransfer {
+    sender.send_from_camera(CameraEvent::ContentsTransfer {
         notify,
         handle,
         filename,
@@ -187,7 +253,7 @@ pub extern "C" fn crsdk_event_warning(ctx: *mut c_void, warning: u32) {
     }
     // SAFETY: C++ guarantees ctx is a valid EventSender pointer
     let sender = unsafe { &*(ctx as *const EventSender) };
-    sender.send(CameraEvent::Warning {
+    sender.send_from_camera(CameraEvent::Warning {
         code: warning,
         params: None,
     });
@@ -206,7 +272,7 @@ pub extern "C" fn crsdk_event_warning_ext(
     }
     // SAFETY: C++ guarantees ctx is a valid EventSender pointer
     let sender = unsafe { &*(ctx as *const EventSender) };
-    sender.send(CameraEvent::Warning {
+    sender.send_from_camera(CameraEvent::Warning {
         code: warning,
         params: Some((p1, p2, p3)),
     });
@@ -219,7 +285,7 @@ pub extern "C" fn crsdk_event_error(ctx: *mut c_void, error: u32) {
     }
     // SAFETY: C++ guarantees ctx is a valid EventSender pointer
     let sender = unsafe { &*(ctx as *const EventSender) };
-    sender.send(CameraEvent::Error { code: error });
+    sender.send_from_camera(CameraEvent::Error { code: error });
 }
 
 #[no_mangle]
@@ -245,7 +311,7 @@ pub extern "C" fn crsdk_event_remote_transfer_progress(
         })
     };
 
-    sender.send(CameraEvent::RemoteTransferProgress {
+    sender.send_from_camera(CameraEvent::RemoteTransferProgress {
         notify,
         percent,
         filename,
@@ -273,7 +339,7 @@ pub extern "C" fn crsdk_event_remote_transfer_data(
         unsafe { std::slice::from_raw_parts(data, size as usize).to_vec() }
     };
 
-    sender.send(CameraEvent::RemoteTransferData {
+    sender.send_from_camera(CameraEvent::RemoteTransferData {
         notify,
         percent,
         data,
@@ -292,11 +358,17 @@ pub extern "C" fn crsdk_event_contents_list_changed(
     }
     // SAFETY: C++ guarantees ctx is a valid EventSender pointer
     let sender = unsafe { &*(ctx as *const EventSender) };
-    sender.send(CameraEvent::ContentsListChanged {
+    sender.send_from_camera(CameraEvent::ContentsListChanged {
         notify,
         slot,
         added,
     });
+    if added > 0 {
+        sender.send_from_camera(CameraEvent::ContentAdded(ContentInfo {
+            slot,
+            count: added,
+        }));
+    }
 }
 
 #[no_mangle]
@@ -306,7 +378,7 @@ pub extern "C" fn crsdk_event_firmware_update(ctx: *mut c_void, notify: u32) {
     }
     // SAFETY: C++ guarantees ctx is a valid EventSender pointer
     let sender = unsafe { &*(ctx as *const EventSender) };
-    sender.send(CameraEvent::FirmwareUpdateProgress { notify });
+    sender.send_from_camera(CameraEvent::FirmwareUpdateProgress { notify });
 }
 
 #[cfg(test)]
@@ -332,6 +404,34 @@ mod tests {
         let _ = unsafe { EventSender::from_raw(ptr) };
     }
 
+    #[test]
+    fn test_event_sender_content_added() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let sender = EventSender::new(tx);
+        let ptr = sender.into_raw();
+
+        crsdk_event_contents_list_changed(ptr, 0, 1, 0);
+        crsdk_event_contents_list_changed(ptr, 0, 2, 3);
+
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            CameraEvent::ContentsListChanged { added: 0, .. }
+        ));
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            CameraEvent::ContentsListChanged { added: 3, .. }
+        ));
+        match rx.try_recv().unwrap() {
+            CameraEvent::ContentAdded(info) => {
+                assert_eq!(info, ContentInfo { slot: 2, count: 3 })
+            }
+            other => panic!("Expected ContentAdded event, got {:?}", other),
+        }
+        assert!(rx.try_recv().is_err());
+
+        let _ = unsafe { EventSender::from_raw(ptr) };
+    }
+
     #[test]
     fn test_event_sender_disconnected() {
         let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
@@ -375,6 +475,49 @@ mod tests {
         let _ = unsafe { EventSender::from_raw(ptr) };
     }
 
+    #[test]
+    fn test_event_sender_tracks_connection() {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let sender = EventSender::new(tx);
+        assert!(!sender.is_connected());
+
+        sender.send(CameraEvent::Connected { version: 1 });
+        assert!(sender.is_connected());
+
+        sender.send(CameraEvent::Warning {
+            code: 1,
+            params: None,
+        });
+        assert!(sender.is_connected());
+
+        sender.send(CameraEvent::Disconnected { error: 0 });
+        assert!(!sender.is_connected());
+    }
+
+    #[test]
+    fn test_event_sender_subscribers() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let sender = EventSender::new(tx);
+        let first = sender.subscribe();
+        let second = sender.subscribe();
+        drop(second);
+        let ptr = sender.into_raw();
+
+        crsdk_event_connected(ptr, 7);
+
+        assert!(matches!(
+            first.try_recv().unwrap(),
+            CameraEvent::Connected { version: 7 }
+        ));
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            CameraEvent::Connected { version: 7 }
+        ));
+
+        let _ = unsafe { EventSender::from_raw(ptr) };
+        assert!(matches!(first.recv(), Err(std::sync::mpsc::RecvError)));
+    }
+
     #[test]
     fn test_event_sender_null_ctx_no_panic() {
         crsdk_event_connected(std::ptr::null_mut(), 1);
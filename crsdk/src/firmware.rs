@@ -0,0 +1,80 @@
+//! Camera firmware version reporting
+
+use std::fmt;
+
+use crate::property::DeviceProperty;
+use crsdk_sys::DevicePropertyCode;
+
+/// Body firmware version and whether it can be updated right now
+///
+/// Returned by `CameraDevice::firmware()`, e.g. to audit which bodies in a
+/// fleet are behind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FirmwareInfo {
+    /// Firmware version as the camera reports it, e.g. `"4.01"`
+    pub version: String,
+    /// Version of the firmware update command set, if the body reports one
+    pub update_command_version: Option<u64>,
+    /// Whether the body would accept a firmware update now, `None` when it
+    /// doesn't report `UpdateBodyStatus`
+    pub update_possible: Option<bool>,
+}
+
+impl FirmwareInfo {
+    /// Read firmware information from a property snapshot
+    ///
+    /// Returns `None` if the snapshot has no firmware version.
+    pub fn from_properties(props: &[DeviceProperty]) -> Option<Self> {
+        let find = |code: DevicePropertyCode| props.iter().find(|p| p.code == code.as_raw());
+
+        let version = find(DevicePropertyCode::SoftwareVersion)?
+            .current_string
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())?
+            .to_string();
+
+        Some(Self {
+            version,
+            update_command_version: find(DevicePropertyCode::FirmwareUpdateCommandVersion)
+                .map(|p| p.current_value),
+            // Status: 0x01 = an update can be started
+            update_possible: find(DevicePropertyCode::UpdateBodyStatus)
+                .map(|p| p.current_value == 0x01),
+        })
+    }
+}
+
+impl fmt::Display for FirmwareInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "firmware {}", self.version)?;
+        match self.update_possible {
+            Some(true) => write!(f, " (update possible)"),
+            Some(false) => write!(f, " (update not possible)"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{prop, string_prop};
+
+    #[test]
+    fn test_from_properties() {
+        let props = vec![
+            string_prop(DevicePropertyCode::SoftwareVersion, 0, "4.01"),
+            prop(DevicePropertyCode::UpdateBodyStatus, 0x01),
+        ];
+        let firmware = FirmwareInfo::from_properties(&props).unwrap();
+        assert_eq!(firmware.version, "4.01");
+        assert_eq!(firmware.update_command_version, None);
+        assert_eq!(firmware.update_possible, Some(true));
+        assert_eq!(firmware.to_string(), "firmware 4.01 (update possible)");
+
+        let props = vec![string_prop(DevicePropertyCode::SoftwareVersion, 0, "")];
+        assert_eq!(FirmwareInfo::from_properties(&props), None);
+        assert_eq!(FirmwareInfo::from_properties(&[]), None);
+    }
+}
@@ -0,0 +1,133 @@
+//! Autofocus status
+
+use std::fmt;
+
+use crate::property::{
+    DeviceProperty, FocusDrivingStatus, FocusIndicator, FocusTrackingStatus, PropertyValue,
+};
+use crsdk_sys::DevicePropertyCode;
+
+/// The camera's autofocus state, read from a single property snapshot
+///
+/// Returned by `CameraDevice::focus_state()`. Reading every field from one
+/// snapshot avoids the races of polling each property separately, e.g. seeing
+/// "focused" from one read and "driving" from the next. Each field is `None`
+/// when the body doesn't report the property.
+///
+/// Per-frame focus state (`FocusFrameState`) is reported with live view
+/// frame information rather than as a device property, so it isn't part of
+/// this snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusState {
+    /// AF lock indicator (`FocusIndication`)
+    pub indicator: Option<FocusIndicator>,
+    /// Subject tracking status (`FocusTrackingStatus`)
+    pub tracking: Option<FocusTrackingStatus>,
+    /// Whether the focus motor is moving (`FocusDrivingStatus`)
+    pub driving: Option<FocusDrivingStatus>,
+}
+
+impl FocusState {
+    /// Read the focus state from a property snapshot
+    pub fn from_properties(props: &[DeviceProperty]) -> Self {
+        let value = |code: DevicePropertyCode| {
+            props
+                .iter()
+                .find(|p| p.code == code.as_raw())
+                .map(|p| p.current_value)
+        };
+
+        Self {
+            indicator: value(DevicePropertyCode::FocusIndication)
+                .and_then(FocusIndicator::from_raw),
+            tracking: value(DevicePropertyCode::FocusTrackingStatus)
+                .and_then(FocusTrackingStatus::from_raw),
+            driving: value(DevicePropertyCode::FocusDrivingStatus)
+                .and_then(FocusDrivingStatus::from_raw),
+        }
+    }
+
+    /// Whether the camera reports focus, or is tracking a subject
+    ///
+    /// Uses `FocusIndication` when the body reports it, otherwise
+    /// `FocusTrackingStatus`.
+    pub fn is_focused(&self) -> bool {
+        match self.indicator {
+            Some(indicator) => matches!(
+                indicator,
+                FocusIndicator::FocusedAfS
+                    | FocusIndicator::FocusedAfC
+                    | FocusIndicator::TrackingAfC
+            ),
+            None => self.tracking == Some(FocusTrackingStatus::Tracking),
+        }
+    }
+
+    /// Whether the camera is focused and the focus motor has stopped
+    ///
+    /// Bodies that don't report `FocusDrivingStatus` are treated as stopped.
+    pub fn is_settled(&self) -> bool {
+        self.is_focused() && self.driving != Some(FocusDrivingStatus::Driving)
+    }
+}
+
+impl fmt::Display for FocusState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(indicator) = self.indicator {
+            parts.push(indicator.to_string());
+        }
+        if let Some(tracking) = self.tracking {
+            parts.push(format!("tracking {}", tracking));
+        }
+        if let Some(driving) = self.driving {
+            parts.push(format!("motor {}", driving));
+        }
+        if parts.is_empty() {
+            write!(f, "Unknown")
+        } else {
+            write!(f, "{}", parts.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::prop;
+
+    #[test]
+    fn test_from_properties() {
+        let props = vec![
+            prop(
+                DevicePropertyCode::FocusIndication,
+                FocusIndicator::FocusedAfS.to_raw(),
+            ),
+            prop(
+                DevicePropertyCode::FocusDrivingStatus,
+                FocusDrivingStatus::Driving.to_raw(),
+            ),
+        ];
+        let state = FocusState::from_properties(&props);
+        assert_eq!(state.indicator, Some(FocusIndicator::FocusedAfS));
+        assert_eq!(state.tracking, None);
+        assert!(state.is_focused());
+        assert!(!state.is_settled());
+        assert_eq!(state.to_string(), "Focused (AF-S), motor Driving");
+    }
+
+    #[test]
+    fn test_tracking_fallback() {
+        let props = vec![prop(
+            DevicePropertyCode::FocusTrackingStatus,
+            FocusTrackingStatus::Tracking.to_raw(),
+        )];
+        let state = FocusState::from_properties(&props);
+        assert!(state.is_focused());
+        assert!(state.is_settled());
+
+        let state = FocusState::from_properties(&[]);
+        assert!(!state.is_focused());
+        assert_eq!(state.to_string(), "Unknown");
+    }
+}
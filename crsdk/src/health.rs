@@ -0,0 +1,210 @@
+//! Camera health status
+
+use std::fmt;
+
+use crate::property::{DeviceOverheatingState, DeviceProperty, PowerSource, PropertyValue};
+use crsdk_sys::DevicePropertyCode;
+
+/// Snapshot of the camera's power, temperature and error state
+///
+/// Returned by `CameraDevice::health()`. Each field is `None` when the camera
+/// doesn't report the underlying property (or reports an unknown value), so
+/// the same struct works across bodies with different status properties.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CameraHealth {
+    /// Overheating state
+    pub overheating: Option<DeviceOverheatingState>,
+    /// Battery charge in percent
+    pub battery_percent: Option<u8>,
+    /// Charge of the second battery in percent, on bodies with a dual-battery grip
+    pub second_battery_percent: Option<u8>,
+    /// Combined charge of all batteries in percent
+    ///
+    /// Only reported with a grip attached; without one the primary battery's
+    /// `battery_percent` is the total.
+    pub total_battery_percent: Option<u8>,
+    /// Estimated remaining battery life in minutes
+    pub battery_minutes: Option<u32>,
+    /// Current power source
+    pub power_source: Option<PowerSource>,
+    /// Whether a lens with electronic contacts is attached
+    pub lens_attached: Option<bool>,
+    /// Raw `CameraErrorCautionStatus` value (0 means no active error or caution)
+    pub error_caution: Option<u64>,
+    /// Raw `SystemErrorCautionStatus` value (0 means no active system error)
+    pub system_error_caution: Option<u64>,
+    /// Raw `CameraSystemErrorInfo` code (0 means none)
+    pub system_error: Option<u64>,
+}
+
+impl CameraHealth {
+    /// Build a health summary from a property snapshot
+    pub fn from_properties(props: &[DeviceProperty]) -> Self {
+        let find = |code: DevicePropertyCode| props.iter().find(|p| p.code == code.as_raw());
+        let value = |code| find(code).map(|p| p.current_value);
+
+        Self {
+            overheating: value(DevicePropertyCode::DeviceOverheatingState)
+                .and_then(DeviceOverheatingState::from_raw),
+            // Some bodies pack a status word above the level, see `BatteryLevel`
+            battery_percent: value(DevicePropertyCode::BatteryRemain).and_then(percent),
+            second_battery_percent: value(DevicePropertyCode::SecondBatteryRemain)
+                .and_then(percent),
+            total_battery_percent: value(DevicePropertyCode::TotalBatteryRemain).and_then(percent),
+            battery_minutes: value(DevicePropertyCode::BatteryRemainingInMinutes).map(|v| v as u32),
+            power_source: value(DevicePropertyCode::PowerSource).and_then(PowerSource::from_raw),
+            lens_attached: find(DevicePropertyCode::LensModelName)
+                .map(|p| p.current_string.as_deref().is_some_and(|s| !s.is_empty())),
+            error_caution: value(DevicePropertyCode::CameraErrorCautionStatus),
+            system_error_caution: value(DevicePropertyCode::SystemErrorCautionStatus),
+            system_error: value(DevicePropertyCode::CameraSystemErrorInfo),
+        }
+    }
+
+    /// Every active error or caution status word
+    ///
+    /// Individual bits aren't decoded, so each nonzero word comes back with
+    /// its raw value for the caller to log or show.
+    pub fn cautions(&self) -> Vec<CameraCaution> {
+        let mut cautions = Vec::new();
+        if let Some(raw) = self.error_caution.filter(|&v| v != 0) {
+            cautions.push(CameraCaution::ErrorCaution(raw));
+        }
+        if let Some(raw) = self.system_error_caution.filter(|&v| v != 0) {
+            cautions.push(CameraCaution::SystemCaution(raw));
+        }
+        if let Some(code) = self.system_error.filter(|&v| v != 0) {
+            cautions.push(CameraCaution::SystemError(code));
+        }
+        cautions
+    }
+
+    /// The charge left across the whole rig: the grip total when reported,
+    /// otherwise the primary battery
+    pub fn rig_battery_percent(&self) -> Option<u8> {
+        self.total_battery_percent.or(self.battery_percent)
+    }
+
+    /// Whether the camera is overheating or reports an active error/caution
+    pub fn has_warning(&self) -> bool {
+        matches!(
+            self.overheating,
+            Some(DeviceOverheatingState::PreOverheating | DeviceOverheatingState::Overheating)
+        ) || !self.cautions().is_empty()
+    }
+}
+
+/// An active error or caution status word reported by the camera
+///
+/// Returned by `CameraHealth::cautions()`. Each variant carries the raw
+/// value of the property it came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CameraCaution {
+    /// Active `CameraErrorCautionStatus` value
+    ErrorCaution(u64),
+    /// Active `SystemErrorCautionStatus` value
+    SystemCaution(u64),
+    /// Active `CameraSystemErrorInfo` code, as shown on the body's error screen
+    SystemError(u64),
+}
+
+impl fmt::Display for CameraCaution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CameraCaution::ErrorCaution(raw) => write!(f, "Error/caution 0x{:X}", raw),
+            CameraCaution::SystemCaution(raw) => write!(f, "System caution 0x{:X}", raw),
+            CameraCaution::SystemError(code) => write!(f, "System error 0x{:X}", code),
+        }
+    }
+}
+
+/// A battery charge percentage, `None` for the "unknown" sentinels
+fn percent(raw: u64) -> Option<u8> {
+    Some(raw & 0xFFFF).filter(|&v| v <= 100).map(|v| v as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{prop, string_prop};
+
+    #[test]
+    fn test_from_properties() {
+        let props = vec![
+            prop(DevicePropertyCode::DeviceOverheatingState, 0x00),
+            prop(DevicePropertyCode::BatteryRemain, 73),
+            prop(DevicePropertyCode::PowerSource, 0x02),
+            string_prop(
+                DevicePropertyCode::LensModelName,
+                0,
+                "FE 24-70mm F2.8 GM II",
+            ),
+            prop(DevicePropertyCode::CameraErrorCautionStatus, 0),
+        ];
+        let health = CameraHealth::from_properties(&props);
+
+        assert_eq!(
+            health.overheating,
+            Some(DeviceOverheatingState::NotOverheating)
+        );
+        assert_eq!(health.battery_percent, Some(73));
+        assert_eq!(health.battery_minutes, None);
+        assert_eq!(health.second_battery_percent, None);
+        assert_eq!(health.rig_battery_percent(), Some(73));
+        assert_eq!(health.power_source, Some(PowerSource::Battery));
+        assert_eq!(health.lens_attached, Some(true));
+        assert!(!health.has_warning());
+    }
+
+    #[test]
+    fn test_battery_grip() {
+        let props = vec![
+            prop(DevicePropertyCode::BatteryRemain, 20),
+            prop(DevicePropertyCode::SecondBatteryRemain, 90),
+            prop(DevicePropertyCode::TotalBatteryRemain, 55),
+        ];
+        let health = CameraHealth::from_properties(&props);
+        assert_eq!(health.battery_percent, Some(20));
+        assert_eq!(health.second_battery_percent, Some(90));
+        assert_eq!(health.total_battery_percent, Some(55));
+        assert_eq!(health.rig_battery_percent(), Some(55));
+
+        // Empty grip slot
+        let props = vec![prop(DevicePropertyCode::SecondBatteryRemain, 0xFFFF)];
+        let health = CameraHealth::from_properties(&props);
+        assert_eq!(health.second_battery_percent, None);
+    }
+
+    #[test]
+    fn test_warning() {
+        let props = vec![
+            prop(DevicePropertyCode::DeviceOverheatingState, 0x01),
+            prop(DevicePropertyCode::LensModelName, 0),
+        ];
+        let health = CameraHealth::from_properties(&props);
+        assert_eq!(health.lens_attached, Some(false));
+        assert!(health.has_warning());
+    }
+
+    #[test]
+    fn test_cautions() {
+        let props = vec![
+            prop(DevicePropertyCode::CameraErrorCautionStatus, 0x0105),
+            prop(DevicePropertyCode::SystemErrorCautionStatus, 0),
+            prop(DevicePropertyCode::CameraSystemErrorInfo, 0x3101),
+        ];
+        let health = CameraHealth::from_properties(&props);
+        assert_eq!(
+            health.cautions(),
+            vec![
+                CameraCaution::ErrorCaution(0x0105),
+                CameraCaution::SystemError(0x3101),
+            ]
+        );
+        assert!(health.has_warning());
+        assert_eq!(
+            CameraCaution::ErrorCaution(0x0105).to_string(),
+            "Error/caution 0x105"
+        );
+    }
+}
@@ -38,6 +38,23 @@
 //! - Result-based error handling
 //! - Async operations via Tokio
 //!
+//! ## Cargo Features
+//!
+//! The library never depends on terminal/TUI crates (those live in `sonyctl`).
+//! Optional integrations are gated behind features:
+//!
+//! - `tracing` *(default)* - emit diagnostics via the `tracing` crate
+//! - `image` - decode live view frames to compute a [`Histogram`]
+//! - `serde` - `Serialize`/`Deserialize` for [`Preset`] and property snapshots
+//! - `record` - record a camera session to a file and replay it without
+//!   hardware (see [`session`])
+//!
+//! For the leanest headless build, disable default features:
+//!
+//! ```toml
+//! crsdk = { version = "0.1", default-features = false }
+//! ```
+//!
 //! ## Implemented Features
 //!
 //! ✅ SDK initialization and lifecycle
@@ -58,35 +75,88 @@
 #![warn(missing_docs)]
 
 pub mod blocking;
+mod capture;
 mod command;
+mod datetime;
+mod debounce;
 mod device;
+mod discovery;
+mod dof;
 mod error;
 mod event;
 mod event_sender;
+mod firmware;
+mod focus;
+mod health;
+mod live_view;
+mod log;
+mod media;
+mod preset;
 pub mod property;
 mod sdk;
+#[cfg(feature = "record")]
+pub mod session;
+mod settings;
+#[cfg(test)]
+mod test_util;
 mod types;
 
+/// Property conversion entry points for the benchmarks in `benches/`; not a
+/// stable API
+#[doc(hidden)]
+pub mod __bench {
+    pub use crate::property::core::device_property::{
+        device_property_current_from_sdk, device_property_from_sdk,
+    };
+}
+
 // Re-exports for async API (default)
-pub use command::{CommandId, CommandParam};
-pub use device::{discover_cameras, CameraDevice, CameraDeviceBuilder};
-pub use error::{Error, Result};
-pub use event::{warning_code_name, warning_param_description, CameraEvent};
+pub use capture::{CaptureOutcome, CaptureResult};
+pub use command::{CommandId, CommandParam, CommandValue, ResetScope};
+pub use datetime::CameraDateTime;
+pub use debounce::DebouncedProperty;
+pub use device::{
+    discover_cameras, discover_cameras_debug, watch_cameras, CameraDevice, CameraDeviceBuilder,
+};
+pub use discovery::CameraDelta;
+pub use dof::DepthOfField;
+pub use error::{Error, ErrorCategory, Result};
+pub use event::{
+    warning_code_name, warning_param_description, CameraEvent, ChangeRecord, ContentInfo,
+    WarningInfo, WarningParamInfo, WARNING_CODES, WARNING_PARAMS,
+};
+pub use firmware::FirmwareInfo;
+pub use focus::FocusState;
+pub use health::{CameraCaution, CameraHealth};
+pub use live_view::{
+    CropRect, FocusMagnifier, Histogram, LiveViewFrame, LiveViewFrameMeta, LiveViewRate,
+};
+pub use media::MediaSlotInfo;
+pub use preset::{Preset, PresetReport, PresetValue};
 pub use property::{
-    property_value_type, AspectRatio, AutoManual, DataType, DeviceProperty, DriveMode, EnableFlag,
-    ExposureCtrlType, ExposureProgram, FileType, FlashMode, FocusArea, FocusMode,
-    FocusTrackingStatus, ImageQuality, ImageSize, IntervalRecShutterType, LiveViewDisplayEffect,
-    LockIndicator, MeteringMode, MovieFileFormat, MovieQuality, OnOff, PrioritySetInAF,
-    PrioritySetInAWB, PropertyValue, PropertyValueType, ShutterMode, ShutterModeStatus,
-    SilentModeApertureDrive, SubjectRecognitionAF, Switch, TypedValue, ValueConstraint,
-    WhiteBalance,
+    property_value_type, AspectRatio, AutoManual, ColorTemperature, CompressionFileFormat,
+    DataType, DeviceProperty, DriveMode, EnableFlag, ExposureComp, ExposureCtrlType,
+    ExposureProgram, FileType, FlashMode, FocusArea, FocusMode, FocusTrackingStatus, Gain,
+    GainUnitSetting, ImageQuality, ImageSize, IntervalRecAETrackingSensitivity,
+    IntervalRecShutterType, LiveViewDisplayEffect, LiveViewQuality, LiveViewStatus, LockIndicator,
+    MeteringMode, MonitoringOutputFormat, MovieFileFormat, MovieQuality, MovieResolution, OnOff,
+    PrioritySetInAF, PrioritySetInAWB, ProgramShiftStatus, PropertyValue, PropertyValueType,
+    RecordingFrameRate, ShutterMode, ShutterModeStatus, SilentModeApertureDrive, SpotBoostStatus,
+    SubjectRecognitionAF, SubjectTarget, Switch, TypedValue, ValueConstraint, WhiteBalance,
 };
 pub(crate) use sdk::Sdk;
-pub use types::{CameraModel, ConnectionInfo, ConnectionType, DiscoveredCamera, MacAddr};
+pub use settings::{
+    AudioRouting, CreativeLookSettings, HdmiOutputConfig, IntervalConfig, PictureProfileSettings,
+    ProgramShift, Shutter, Stabilization, StreamConfig, VideoFormat, VideoMode,
+};
+pub use types::{
+    CameraModel, ConnectionInfo, ConnectionStatus, ConnectionType, DiscoveredCamera,
+    DiscoveryDiagnostics, MacAddr,
+};
 
 // Re-export generated property codes (complete SDK coverage)
 pub use crsdk_sys::DevicePropertyCode;
 pub use property::{
     all_categories, property_category, property_description, property_display_name,
-    PropertyCategoryId,
+    CategorySummary, PropertyCategoryId,
 };
@@ -0,0 +1,397 @@
+//! Live view frame types
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::property::{DeviceProperty, ImagerScanMode, PropertyValue, APSC_S35};
+use crsdk_sys::DevicePropertyCode;
+
+use DevicePropertyCode as C;
+
+/// Linear crop factor of APS-C/Super 35mm relative to full frame
+pub(crate) const APSC_CROP_FACTOR: f32 = 1.5;
+
+/// Width of the grid `FocusMagnifierSetting` positions are given on
+const MAGNIFIER_GRID_WIDTH: f32 = 639.0;
+
+/// Height of the grid `FocusMagnifierSetting` positions are given on
+const MAGNIFIER_GRID_HEIGHT: f32 = 479.0;
+
+/// Window `LiveViewRate` averages frame delivery over
+const RATE_WINDOW: Duration = Duration::from_secs(2);
+
+/// A single live view frame
+///
+/// Frames are JPEG images whose size follows the camera's live view quality
+/// (see `CameraDevice::set_live_view_quality()`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiveViewFrame {
+    /// JPEG-encoded image data
+    pub data: Vec<u8>,
+    /// Frame number assigned by the camera
+    pub frame_number: u32,
+}
+
+/// Metadata for a live view frame fetched into a caller-provided buffer
+///
+/// Returned by `CameraDevice::get_live_view_into()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LiveViewFrameMeta {
+    /// Frame number assigned by the camera
+    pub frame_number: u32,
+    /// Size of the JPEG data in bytes
+    pub size: usize,
+}
+
+/// Measured live view delivery rate
+///
+/// The SDK has no notion of a frame rate: callers poll `get_live_view()`
+/// and get `FrameNotUpdated` until the camera has a new frame. Feed every
+/// frame that does arrive to `record()`; `fps()` averages over the last two
+/// seconds, so it falls to zero soon after delivery stops, and
+/// `since_last_frame()` says how long the picture has been frozen. A frame
+/// number seen twice in a row is counted once. `sonyctl live-view` drives
+/// its polling loop this way.
+#[derive(Debug, Clone, Default)]
+pub struct LiveViewRate {
+    arrivals: VecDeque<Instant>,
+    last_frame_number: Option<u32>,
+}
+
+impl LiveViewRate {
+    /// Create a meter with no frames recorded
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Note that a frame with `frame_number` just arrived
+    pub fn record(&mut self, frame_number: u32) {
+        self.record_at(frame_number, Instant::now());
+    }
+
+    /// Frames per second over the last two seconds
+    pub fn fps(&self) -> f32 {
+        self.fps_at(Instant::now())
+    }
+
+    /// Time since the last new frame, or `None` before the first one
+    pub fn since_last_frame(&self) -> Option<Duration> {
+        self.arrivals.back().map(|at| at.elapsed())
+    }
+
+    /// Whether no new frame has arrived for `threshold`
+    ///
+    /// False before the first frame, so a viewer that is still starting up
+    /// isn't reported as stalled.
+    pub fn is_stalled(&self, threshold: Duration) -> bool {
+        self.since_last_frame().is_some_and(|age| age >= threshold)
+    }
+
+    fn record_at(&mut self, frame_number: u32, now: Instant) {
+        if self.last_frame_number == Some(frame_number) {
+            return;
+        }
+        self.last_frame_number = Some(frame_number);
+        self.arrivals.push_back(now);
+        while self.arrivals.len() > 1
+            && now.saturating_duration_since(self.arrivals[0]) > RATE_WINDOW
+        {
+            self.arrivals.pop_front();
+        }
+    }
+
+    fn fps_at(&self, now: Instant) -> f32 {
+        let count = self
+            .arrivals
+            .iter()
+            .filter(|at| now.saturating_duration_since(**at) <= RATE_WINDOW)
+            .count();
+        if count == 0 {
+            return 0.0;
+        }
+        count as f32 / RATE_WINDOW.as_secs_f32()
+    }
+}
+
+/// Per-channel brightness histogram of a live view frame
+///
+/// Each array has 256 bins counting pixels per 8-bit level. The SDK doesn't
+/// deliver histogram data itself, so this is computed from the decoded JPEG
+/// (see `LiveViewFrame::histogram()`, behind the `image` feature).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    /// Red channel
+    pub red: [u32; 256],
+    /// Green channel
+    pub green: [u32; 256],
+    /// Blue channel
+    pub blue: [u32; 256],
+    /// Luma, using Rec. 709 weights
+    pub luma: [u32; 256],
+}
+
+impl Histogram {
+    /// Compute a histogram from packed 8-bit RGB pixels
+    ///
+    /// A trailing partial pixel is ignored.
+    pub fn from_rgb8(pixels: &[u8]) -> Self {
+        let mut hist = Histogram {
+            red: [0; 256],
+            green: [0; 256],
+            blue: [0; 256],
+            luma: [0; 256],
+        };
+        for px in pixels.chunks_exact(3) {
+            let (r, g, b) = (px[0] as usize, px[1] as usize, px[2] as usize);
+            hist.red[r] += 1;
+            hist.green[g] += 1;
+            hist.blue[b] += 1;
+            hist.luma[(2126 * r + 7152 * g + 722 * b + 5000) / 10_000] += 1;
+        }
+        hist
+    }
+
+    /// Number of pixels counted
+    pub fn total(&self) -> u32 {
+        self.luma.iter().sum()
+    }
+}
+
+#[cfg(feature = "image")]
+impl LiveViewFrame {
+    /// Decode the frame and compute its histogram
+    pub fn histogram(&self) -> crate::Result<Histogram> {
+        let image = image::load_from_memory_with_format(&self.data, image::ImageFormat::Jpeg)
+            .map_err(|e| crate::Error::Other(format!("failed to decode live view frame: {}", e)))?;
+        Ok(Histogram::from_rgb8(image.to_rgb8().as_raw()))
+    }
+}
+
+/// Area of the sensor shown in live view, normalized to the full sensor
+///
+/// `(0.0, 0.0)` is the top-left corner of the sensor and `(1.0, 1.0)` the
+/// bottom-right. Returned by `CameraDevice::live_view_crop()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CropRect {
+    /// Left edge
+    pub x: f32,
+    /// Top edge
+    pub y: f32,
+    /// Width
+    pub width: f32,
+    /// Height
+    pub height: f32,
+}
+
+impl CropRect {
+    /// The whole sensor
+    pub const FULL: CropRect = CropRect {
+        x: 0.0,
+        y: 0.0,
+        width: 1.0,
+        height: 1.0,
+    };
+
+    /// A centered crop magnified by `scale` (values below 1.0 are treated as 1.0)
+    pub fn centered(scale: f32) -> Self {
+        let size = 1.0 / scale.max(1.0);
+        let offset = (1.0 - size) / 2.0;
+        CropRect {
+            x: offset,
+            y: offset,
+            width: size,
+            height: size,
+        }
+    }
+
+    /// Work out the live view crop from a property snapshot
+    ///
+    /// Combines the APS-C/Super 35mm crop (from `ImagerScanMode`, falling
+    /// back to `APSCS35`) with `DigitalZoomScale`, which is reported ×1000.
+    /// Both crops are assumed centered; missing properties count as no crop.
+    pub fn from_properties(props: &[DeviceProperty]) -> Self {
+        let value = |code: DevicePropertyCode| {
+            props
+                .iter()
+                .find(|p| p.code == code.as_raw())
+                .map(|p| p.current_value)
+        };
+
+        let crop_factor = if is_sensor_cropped(props) {
+            APSC_CROP_FACTOR
+        } else {
+            1.0
+        };
+
+        let digital_zoom = value(C::DigitalZoomScale)
+            .filter(|&raw| raw > 0)
+            .map_or(1.0, |raw| raw as f32 / 1000.0);
+
+        Self::centered(crop_factor * digital_zoom)
+    }
+
+    /// Map a point in live view coordinates (0.0-1.0) to sensor coordinates
+    pub fn to_sensor(&self, x: f32, y: f32) -> (f32, f32) {
+        (self.x + x * self.width, self.y + y * self.height)
+    }
+}
+
+/// Whether the sensor is in its APS-C/Super 35mm crop
+///
+/// Reads `ImagerScanMode`, which reflects what the sensor is actually doing,
+/// and falls back to the `APSCS35` setting on bodies that don't report it.
+pub(crate) fn is_sensor_cropped(props: &[DeviceProperty]) -> bool {
+    let value = |code: DevicePropertyCode| {
+        props
+            .iter()
+            .find(|p| p.code == code.as_raw())
+            .map(|p| p.current_value)
+    };
+
+    match value(C::ImagerScanMode).and_then(ImagerScanMode::from_raw) {
+        Some(ImagerScanMode::Super35mm) => true,
+        Some(ImagerScanMode::FullFrame) => false,
+        _ => value(C::APSCS35).and_then(APSC_S35::from_raw) == Some(APSC_S35::On),
+    }
+}
+
+/// The focus magnifier's zoom and where it is looking
+///
+/// Read with `CameraDevice::focus_magnifier()`. The position is the center
+/// of the magnified region on the same 0.0-1.0 scale as [`CropRect`].
+/// Whether the magnifier is shown at all is `EnlargeScreenSetting`.
+///
+/// `FocusMagnifierSetting` packs ratio and position into one value, as the
+/// Camera Remote SDK API reference describes it: bits 63-32 hold the ratio
+/// ×10 (0 when there is none), bits 31-16 the x position (0-639) and bits
+/// 15-0 the y position (0-479), on the same 640×480 grid as the SDK's
+/// other live view positions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FocusMagnifier {
+    /// Magnification, e.g. 4.0 for ×4
+    pub ratio: f32,
+    /// Horizontal center of the magnified region
+    pub x: f32,
+    /// Vertical center of the magnified region
+    pub y: f32,
+}
+
+impl FocusMagnifier {
+    /// Decode a `FocusMagnifierSetting` value, `None` if the magnifier is off
+    pub fn from_raw(raw: u64) -> Option<Self> {
+        let ratio = (raw >> 32) as u32;
+        if ratio == 0 {
+            return None;
+        }
+        Some(FocusMagnifier {
+            ratio: ratio as f32 / 10.0,
+            x: ((raw >> 16) & 0xFFFF) as f32 / MAGNIFIER_GRID_WIDTH,
+            y: (raw & 0xFFFF) as f32 / MAGNIFIER_GRID_HEIGHT,
+        })
+    }
+
+    /// Encode as a `FocusMagnifierSetting` value
+    ///
+    /// The position is clamped to the frame.
+    pub fn to_raw(&self) -> u64 {
+        let ratio = (self.ratio * 10.0).round() as u64;
+        let x = (self.x.clamp(0.0, 1.0) * MAGNIFIER_GRID_WIDTH).round() as u64;
+        let y = (self.y.clamp(0.0, 1.0) * MAGNIFIER_GRID_HEIGHT).round() as u64;
+        (ratio << 32) | (x << 16) | y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::prop;
+
+    #[test]
+    fn test_live_view_rate() {
+        let start = Instant::now();
+        let mut rate = LiveViewRate::new();
+        assert_eq!(rate.fps_at(start), 0.0);
+
+        // 25 fps for four seconds, with every frame polled twice
+        for i in 0..100u32 {
+            let at = start + Duration::from_millis(40 * i as u64);
+            rate.record_at(i, at);
+            rate.record_at(i, at);
+        }
+        let last = start + Duration::from_millis(40 * 99);
+        assert!((rate.fps_at(last) - 25.0).abs() < 1.0);
+
+        // Delivery stopped: the rate decays to zero once the window passes
+        assert!(rate.fps_at(last + Duration::from_secs(1)) < 15.0);
+        assert_eq!(rate.fps_at(last + Duration::from_secs(3)), 0.0);
+    }
+
+    #[test]
+    fn test_crop_rect_full_frame() {
+        assert_eq!(CropRect::from_properties(&[]), CropRect::FULL);
+
+        let props = vec![
+            prop(C::ImagerScanMode, ImagerScanMode::FullFrame.to_raw()),
+            prop(C::APSCS35, APSC_S35::On.to_raw()),
+        ];
+        assert_eq!(CropRect::from_properties(&props), CropRect::FULL);
+    }
+
+    #[test]
+    fn test_crop_rect_apsc_and_digital_zoom() {
+        let props = vec![prop(C::APSCS35, APSC_S35::On.to_raw())];
+        let rect = CropRect::from_properties(&props);
+        assert!((rect.width - 1.0 / 1.5).abs() < 1e-6);
+        assert!((rect.x - (1.0 - rect.width) / 2.0).abs() < 1e-6);
+
+        let props = vec![
+            prop(C::ImagerScanMode, ImagerScanMode::FullFrame.to_raw()),
+            prop(C::DigitalZoomScale, 2000),
+        ];
+        let rect = CropRect::from_properties(&props);
+        assert_eq!(rect, CropRect::centered(2.0));
+        assert_eq!(rect.to_sensor(0.5, 0.5), (0.5, 0.5));
+        assert_eq!(rect.to_sensor(0.0, 1.0), (0.25, 0.75));
+    }
+
+    #[test]
+    fn test_focus_magnifier_raw_round_trip() {
+        assert_eq!(FocusMagnifier::from_raw(0), None);
+        assert_eq!(FocusMagnifier::from_raw(0x0000_0000_013F_00EF), None);
+
+        let centered = FocusMagnifier {
+            ratio: 4.0,
+            x: 0.5,
+            y: 0.5,
+        };
+        let raw = centered.to_raw();
+        assert_eq!(raw, (40 << 32) | (320 << 16) | 240);
+
+        let decoded = FocusMagnifier::from_raw(raw).unwrap();
+        assert_eq!(decoded.ratio, 4.0);
+        assert!((decoded.x - 0.5).abs() < 0.01);
+        assert!((decoded.y - 0.5).abs() < 0.01);
+
+        let outside = FocusMagnifier {
+            ratio: 12.5,
+            x: 2.0,
+            y: -1.0,
+        };
+        assert_eq!(outside.to_raw(), (125 << 32) | (639 << 16));
+    }
+
+    #[test]
+    fn test_histogram_from_rgb8() {
+        let pixels = [255, 0, 0, 0, 0, 0, 255, 255, 255, 7];
+        let hist = Histogram::from_rgb8(&pixels);
+
+        assert_eq!(hist.total(), 3);
+        assert_eq!(hist.red[255], 2);
+        assert_eq!(hist.red[0], 1);
+        assert_eq!(hist.green[0], 2);
+        assert_eq!(hist.blue[255], 1);
+        assert_eq!(hist.luma[0], 1);
+        assert_eq!(hist.luma[54], 1);
+        assert_eq!(hist.luma[255], 1);
+    }
+}
@@ -0,0 +1,25 @@
+//! Internal logging macros
+//!
+//! These forward to `tracing` when the `tracing` feature is enabled and
+//! compile to nothing (while still type-checking their arguments) otherwise.
+
+macro_rules! warn {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "tracing")]
+        ::tracing::warn!($($arg)*);
+        #[cfg(not(feature = "tracing"))]
+        let _ = format_args!($($arg)*);
+    }};
+}
+
+macro_rules! debug {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "tracing")]
+        ::tracing::debug!($($arg)*);
+        #[cfg(not(feature = "tracing"))]
+        let _ = format_args!($($arg)*);
+    }};
+}
+
+pub(crate) use debug;
+pub(crate) use warn;
@@ -0,0 +1,188 @@
+//! Memory card slot status
+
+use crate::property::{DeviceProperty, PlaybackMedia, PropertyValue, RecordingMedia, SlotStatus};
+use crsdk_sys::DevicePropertyCode;
+
+use DevicePropertyCode as C;
+
+/// Status, remaining shots and remaining time codes for each slot
+///
+/// Slot 3 (FX6/FX9 CFexpress) has no remaining shot count.
+const SLOT_CODES: [(PlaybackMedia, C, Option<C>, C); 3] = [
+    (
+        PlaybackMedia::Slot1,
+        C::MediaSLOT1Status,
+        Some(C::MediaSLOT1RemainingNumber),
+        C::MediaSLOT1RemainingTime,
+    ),
+    (
+        PlaybackMedia::Slot2,
+        C::MediaSLOT2Status,
+        Some(C::MediaSLOT2RemainingNumber),
+        C::MediaSLOT2RemainingTime,
+    ),
+    (
+        PlaybackMedia::Slot3,
+        C::MediaSLOT3Status,
+        None,
+        C::MediaSLOT3RemainingTime,
+    ),
+];
+
+/// Status of a single memory card slot
+///
+/// Returned by `CameraDevice::media_status()`, one entry per slot the camera
+/// reports, so two-slot and three-slot bodies are handled the same way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaSlotInfo {
+    /// Which slot this is
+    pub slot: PlaybackMedia,
+    /// Card status, `None` if the camera reported an unknown value
+    pub status: Option<SlotStatus>,
+    /// Remaining number of still images
+    pub remaining_photos: Option<u32>,
+    /// Remaining recording time in seconds
+    pub remaining_time_sec: Option<u32>,
+}
+
+impl MediaSlotInfo {
+    /// Build slot status from a property snapshot
+    ///
+    /// Slots whose status property is absent are omitted.
+    pub fn from_properties(props: &[DeviceProperty]) -> Vec<Self> {
+        let value = |code: C| {
+            props
+                .iter()
+                .find(|p| p.code == code.as_raw())
+                .map(|p| p.current_value)
+        };
+
+        SLOT_CODES
+            .iter()
+            .filter_map(|&(slot, status_code, photos_code, time_code)| {
+                let status = value(status_code)?;
+                Some(Self {
+                    slot,
+                    status: SlotStatus::from_raw(status),
+                    remaining_photos: photos_code.and_then(value).map(|v| v as u32),
+                    remaining_time_sec: value(time_code).map(|v| v as u32),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Estimate how many more stills fit on the card(s) stills are recorded to
+///
+/// Uses the camera's own per-slot remaining counts, which already account
+/// for the current image size, quality and file format. Which slots count
+/// depends on `RecordingMedia`:
+/// - a single slot: that slot's count
+/// - simultaneous or sorted recording: the smaller count, since both cards
+///   must have room
+/// - not reported: the first slot that is ready
+///
+/// Returns `None` if no slot in use reports a count.
+pub fn estimate_remaining_shots(props: &[DeviceProperty]) -> Option<u32> {
+    let slots = MediaSlotInfo::from_properties(props);
+    let remaining = |slot: PlaybackMedia| {
+        slots
+            .iter()
+            .find(|s| s.slot == slot && s.status == Some(SlotStatus::Ok))
+            .and_then(|s| s.remaining_photos)
+    };
+
+    let media = props
+        .iter()
+        .find(|p| p.code == C::RecordingMedia.as_raw())
+        .and_then(|p| RecordingMedia::from_raw(p.current_value));
+
+    match media {
+        Some(RecordingMedia::Slot1) => remaining(PlaybackMedia::Slot1),
+        Some(RecordingMedia::Slot2) => remaining(PlaybackMedia::Slot2),
+        Some(RecordingMedia::Simultaneous | RecordingMedia::Sort) => {
+            match (
+                remaining(PlaybackMedia::Slot1),
+                remaining(PlaybackMedia::Slot2),
+            ) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            }
+        }
+        None => slots
+            .iter()
+            .filter(|s| s.status == Some(SlotStatus::Ok))
+            .find_map(|s| s.remaining_photos),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::prop;
+
+    #[test]
+    fn test_media_slots_two_slot_body() {
+        let props = vec![
+            prop(C::MediaSLOT1Status, SlotStatus::Ok.to_raw()),
+            prop(C::MediaSLOT1RemainingNumber, 1200),
+            prop(C::MediaSLOT1RemainingTime, 3600),
+            prop(C::MediaSLOT2Status, SlotStatus::NoCard.to_raw()),
+        ];
+
+        let slots = MediaSlotInfo::from_properties(&props);
+        assert_eq!(slots.len(), 2);
+        assert_eq!(slots[0].slot, PlaybackMedia::Slot1);
+        assert_eq!(slots[0].status, Some(SlotStatus::Ok));
+        assert_eq!(slots[0].remaining_photos, Some(1200));
+        assert_eq!(slots[0].remaining_time_sec, Some(3600));
+        assert_eq!(slots[1].status, Some(SlotStatus::NoCard));
+        assert_eq!(slots[1].remaining_photos, None);
+    }
+
+    #[test]
+    fn test_media_slots_three_slot_body() {
+        let props = vec![
+            prop(C::MediaSLOT1Status, SlotStatus::Ok.to_raw()),
+            prop(C::MediaSLOT2Status, SlotStatus::Ok.to_raw()),
+            prop(C::MediaSLOT3Status, SlotStatus::Ok.to_raw()),
+            prop(C::MediaSLOT3RemainingTime, 7200),
+        ];
+
+        let slots = MediaSlotInfo::from_properties(&props);
+        assert_eq!(slots.len(), 3);
+        assert_eq!(slots[2].slot, PlaybackMedia::Slot3);
+        assert_eq!(slots[2].remaining_time_sec, Some(7200));
+    }
+
+    #[test]
+    fn test_estimate_remaining_shots() {
+        let mut props = vec![
+            prop(C::MediaSLOT1Status, SlotStatus::Ok.to_raw()),
+            prop(C::MediaSLOT1RemainingNumber, 1200),
+            prop(C::MediaSLOT2Status, SlotStatus::Ok.to_raw()),
+            prop(C::MediaSLOT2RemainingNumber, 300),
+        ];
+        assert_eq!(estimate_remaining_shots(&props), Some(1200));
+
+        props.push(prop(C::RecordingMedia, RecordingMedia::Slot2.to_raw()));
+        assert_eq!(estimate_remaining_shots(&props), Some(300));
+
+        props.pop();
+        props.push(prop(
+            C::RecordingMedia,
+            RecordingMedia::Simultaneous.to_raw(),
+        ));
+        assert_eq!(estimate_remaining_shots(&props), Some(300));
+    }
+
+    #[test]
+    fn test_estimate_remaining_shots_no_card() {
+        let props = vec![
+            prop(C::MediaSLOT1Status, SlotStatus::NoCard.to_raw()),
+            prop(C::MediaSLOT1RemainingNumber, 0),
+            prop(C::RecordingMedia, RecordingMedia::Slot1.to_raw()),
+        ];
+        assert_eq!(estimate_remaining_shots(&props), None);
+    }
+}
@@ -0,0 +1,148 @@
+//! Named groups of property values applied in one call
+
+use std::fmt;
+
+use crate::error::Error;
+use crsdk_sys::DevicePropertyCode;
+
+/// A named set of property values, e.g. "interview" or "run-and-gun"
+///
+/// Applied with `CameraDevice::apply_preset()`. Values are raw SDK values,
+/// the same ones `set_property()` takes. With the `serde` feature enabled
+/// presets can be stored as files; property codes are written by name
+/// (e.g. `"IsoSensitivity"`) so the files stay readable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Preset {
+    /// Name shown to the user
+    pub name: String,
+    /// Values in the order they are applied
+    pub values: Vec<PresetValue>,
+}
+
+/// A single property value within a [`Preset`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PresetValue {
+    /// Property to set
+    #[cfg_attr(feature = "serde", serde(with = "code_by_name"))]
+    pub code: DevicePropertyCode,
+    /// Raw SDK value
+    pub value: u64,
+}
+
+impl Preset {
+    /// Create an empty preset
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Add a value, replacing an earlier one for the same property
+    pub fn with(mut self, code: DevicePropertyCode, value: u64) -> Self {
+        match self.values.iter_mut().find(|v| v.code == code) {
+            Some(existing) => existing.value = value,
+            None => self.values.push(PresetValue { code, value }),
+        }
+        self
+    }
+}
+
+/// Outcome of `CameraDevice::apply_preset()`, per property
+#[derive(Debug, Default)]
+pub struct PresetReport {
+    /// Properties that were changed
+    pub applied: Vec<DevicePropertyCode>,
+    /// Properties left alone: already at the value, absent or read-only
+    pub skipped: Vec<DevicePropertyCode>,
+    /// Properties the camera refused, with the reason
+    pub failed: Vec<(DevicePropertyCode, Error)>,
+}
+
+impl PresetReport {
+    /// Whether every property is now at the preset's value
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+impl fmt::Display for PresetReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} applied, {} skipped, {} failed",
+            self.applied.len(),
+            self.skipped.len(),
+            self.failed.len()
+        )?;
+        for (code, err) in &self.failed {
+            write!(f, "\n  {}: {}", code.name(), err)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+mod code_by_name {
+    use crsdk_sys::DevicePropertyCode;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        code: &DevicePropertyCode,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:?}", code))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DevicePropertyCode, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        DevicePropertyCode::from_name(&name)
+            .ok_or_else(|| de::Error::custom(format!("unknown property: {}", name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preset_with_replaces_duplicates() {
+        let preset = Preset::new("interview")
+            .with(DevicePropertyCode::IsoSensitivity, 800)
+            .with(DevicePropertyCode::FNumber, 280)
+            .with(DevicePropertyCode::IsoSensitivity, 1600);
+
+        assert_eq!(preset.name, "interview");
+        assert_eq!(
+            preset.values,
+            vec![
+                PresetValue {
+                    code: DevicePropertyCode::IsoSensitivity,
+                    value: 1600,
+                },
+                PresetValue {
+                    code: DevicePropertyCode::FNumber,
+                    value: 280,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_preset_report_display() {
+        let report = PresetReport {
+            applied: vec![DevicePropertyCode::FNumber],
+            skipped: vec![DevicePropertyCode::IsoSensitivity],
+            failed: vec![(DevicePropertyCode::ShutterSpeed, Error::PropertyNotWritable)],
+        };
+        assert!(!report.is_complete());
+        assert_eq!(
+            report.to_string().lines().next(),
+            Some("1 applied, 1 skipped, 1 failed")
+        );
+    }
+}
@@ -123,8 +123,8 @@ impl Category for Display {
         PropertyDef::new(
             C::EnlargeScreenSetting,
             "Enlarge Screen",
-            "Magnification setting for enlarged preview.",
-            Some(V::Integer),
+            "Turns the enlarged (focus magnifier) view on or off.",
+            Some(V::Switch),
         ),
         PropertyDef::new(
             C::LiveViewStatus,
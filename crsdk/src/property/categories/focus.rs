@@ -1,4 +1,13 @@
 //! Focus category: autofocus and manual focus properties.
+//!
+//! Focus peaking (on/off, level and color) is not among the properties the
+//! Camera Remote SDK exposes, so it can only be changed on the camera. The
+//! closest remote MF aid is `FocusMagnifierSetting`.
+//!
+//! Likewise, the lens focus range limiter and macro switch are physical
+//! switches whose state the SDK does not report, so there is no way to check
+//! them from software. `FocusBracketFocusRange` and `FocusSpeedRange` are
+//! unrelated: they configure focus bracketing and power-focus speed.
 
 use super::{Category, PropertyDef, PropertyValueType};
 use crsdk_sys::DevicePropertyCode;
@@ -220,7 +220,7 @@ impl Category for Image {
             C::LiveViewImageQuality,
             "LV Quality",
             "Quality setting for live view stream.",
-            Some(V::Integer),
+            Some(V::LiveViewQuality),
         ),
         PropertyDef::new(
             C::ImagerScanMode,
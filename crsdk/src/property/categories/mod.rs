@@ -29,6 +29,7 @@ use crsdk_sys::DevicePropertyCode;
 use linkme::distributed_slice;
 
 use super::values::PropertyValueType;
+use super::{DeviceProperty, EnableFlag};
 
 /// Distributed slice collecting all registered categories.
 #[distributed_slice]
@@ -46,6 +47,58 @@ impl PropertyCategoryId {
     pub const fn name(self) -> &'static str {
         self.0
     }
+
+    /// Position of this category in [`DISPLAY_ORDER`].
+    ///
+    /// Categories missing from the list sort after all listed ones.
+    pub fn order(self) -> usize {
+        DISPLAY_ORDER
+            .iter()
+            .position(|id| *id == self)
+            .unwrap_or(DISPLAY_ORDER.len())
+    }
+}
+
+/// The order categories are listed in, most commonly adjusted first.
+///
+/// Registration order depends on the linker, so anything shown to users should
+/// sort by this instead (via [`PropertyCategoryId::order`] or `Ord`).
+pub const DISPLAY_ORDER: &[PropertyCategoryId] = &[
+    <exposure::Exposure as Category>::ID,
+    <focus::Focus as Category>::ID,
+    <white_balance::WhiteBalance as Category>::ID,
+    <image::Image as Category>::ID,
+    <movie::Movie as Category>::ID,
+    <media::Media as Category>::ID,
+    <drive::Drive as Category>::ID,
+    <metering::Metering as Category>::ID,
+    <flash::Flash as Category>::ID,
+    <zoom::Zoom as Category>::ID,
+    <lens::Lens as Category>::ID,
+    <audio::Audio as Category>::ID,
+    <picture_profile::PictureProfile as Category>::ID,
+    <nd_filter::NdFilter as Category>::ID,
+    <stabilization::Stabilization as Category>::ID,
+    <display::Display as Category>::ID,
+    <power::Power as Category>::ID,
+    <custom_buttons::CustomButtons as Category>::ID,
+    <silent::Silent as Category>::ID,
+    <other::Other as Category>::ID,
+];
+
+impl PartialOrd for PropertyCategoryId {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PropertyCategoryId {
+    /// Orders by [`DISPLAY_ORDER`], then by name for unlisted categories.
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.order()
+            .cmp(&other.order())
+            .then_with(|| self.0.cmp(other.0))
+    }
 }
 
 impl core::fmt::Display for PropertyCategoryId {
@@ -166,9 +219,57 @@ pub fn value_type(code: DevicePropertyCode) -> PropertyValueType {
         .unwrap_or(PropertyValueType::Unknown)
 }
 
-/// Get all registered category IDs.
+/// Get all registered category IDs, in [`DISPLAY_ORDER`].
 pub fn all_categories() -> impl Iterator<Item = PropertyCategoryId> {
-    CATEGORIES.iter().map(|reg| reg.id)
+    let mut ids: Vec<_> = CATEGORIES.iter().map(|reg| reg.id).collect();
+    ids.sort();
+    ids.into_iter()
+}
+
+/// Property counts for a single category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CategorySummary {
+    /// The category these counts are for.
+    pub category: PropertyCategoryId,
+    /// Number of properties the camera reported in this category.
+    pub total: usize,
+    /// Number of those that can currently be written.
+    pub writable: usize,
+    /// Number of those that are display-only.
+    pub read_only: usize,
+}
+
+/// Count properties per category.
+///
+/// Categories are returned in display order (the same as [`all_categories`]),
+/// and categories with no properties present are omitted.
+pub fn summarize(properties: &[DeviceProperty]) -> Vec<CategorySummary> {
+    let mut summaries: Vec<CategorySummary> = all_categories()
+        .map(|category| CategorySummary {
+            category,
+            total: 0,
+            writable: 0,
+            read_only: 0,
+        })
+        .collect();
+
+    for prop in properties {
+        let category = DevicePropertyCode::from_raw(prop.code)
+            .map(property_category)
+            .unwrap_or(other::Other::ID);
+        if let Some(summary) = summaries.iter_mut().find(|s| s.category == category) {
+            summary.total += 1;
+            if prop.enable_flag.is_writable() {
+                summary.writable += 1;
+            }
+            if prop.enable_flag == EnableFlag::ReadOnly {
+                summary.read_only += 1;
+            }
+        }
+    }
+
+    summaries.retain(|s| s.total > 0);
+    summaries
 }
 
 #[cfg(test)]
@@ -208,6 +309,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_display_order_covers_all_categories() {
+        for reg in CATEGORIES {
+            assert!(
+                DISPLAY_ORDER.contains(&reg.id),
+                "{} missing from DISPLAY_ORDER",
+                reg.id
+            );
+        }
+
+        let ids: Vec<_> = all_categories().collect();
+        assert_eq!(ids.first(), Some(&exposure::Exposure::ID));
+        assert_eq!(ids.last(), Some(&other::Other::ID));
+        assert!(focus::Focus::ID < white_balance::WhiteBalance::ID);
+    }
+
     #[test]
     fn test_all_properties_have_category() {
         let mut categorized: HashSet<DevicePropertyCode> = HashSet::new();
@@ -273,4 +390,35 @@ mod tests {
             missing
         );
     }
+
+    #[test]
+    fn test_summarize() {
+        use crate::property::{DataType, ValueConstraint};
+
+        let prop = |code: DevicePropertyCode, enable_flag| DeviceProperty {
+            code: code.as_raw(),
+            data_type: DataType::UInt16,
+            enable_flag,
+            current_value: 0,
+            current_string: None,
+            constraint: ValueConstraint::None,
+            values: Vec::new(),
+        };
+        let props = vec![
+            prop(DevicePropertyCode::FNumber, EnableFlag::ReadWrite),
+            prop(DevicePropertyCode::ShutterSpeed, EnableFlag::ReadOnly),
+            prop(DevicePropertyCode::FocusMode, EnableFlag::ReadWrite),
+        ];
+
+        let summaries = summarize(&props);
+        assert_eq!(summaries.len(), 2);
+        let exposure = summaries
+            .iter()
+            .find(|s| s.category == property_category(DevicePropertyCode::FNumber))
+            .unwrap();
+        assert_eq!(
+            (exposure.total, exposure.writable, exposure.read_only),
+            (2, 1, 1)
+        );
+    }
 }
@@ -28,19 +28,19 @@ impl Category for Movie {
             C::MovieRecordingFrameRateSetting,
             "Movie FPS",
             "Recording frame rate. 24p for cinema look, 30p for broadcast, 60p for smooth motion, 120p for slow motion.",
-            Some(V::Integer),
+            Some(V::RecordingFrameRate),
         ),
         PropertyDef::new(
             C::MovieRecordingResolutionForMain,
             "Main Resolution",
             "Main recording resolution (4K, 1080p, etc.).",
-            Some(V::Integer),
+            Some(V::MovieResolution),
         ),
         PropertyDef::new(
             C::MovieRecordingResolutionForProxy,
             "Proxy Resolution",
             "Resolution for proxy files used in editing.",
-            Some(V::Integer),
+            Some(V::MovieResolution),
         ),
         PropertyDef::new(
             C::MovieRecordingFrameRateProxySetting,
@@ -124,7 +124,7 @@ impl Category for Movie {
             C::SQRecordingFrameRateSetting,
             "S&Q Rec FPS",
             "Slow & Quick mode recording frame rate.",
-            Some(V::Integer),
+            Some(V::RecordingFrameRate),
         ),
         PropertyDef::new(
             C::SQFrameRate,
@@ -214,7 +214,7 @@ impl Category for Movie {
             C::MovieHDMIOutputRAW,
             "HDMI RAW",
             "RAW video output over HDMI.",
-            Some(V::Integer),
+            Some(V::Switch),
         ),
         PropertyDef::new(
             C::MovieHDMIOutputRawSetting,
@@ -232,13 +232,13 @@ impl Category for Movie {
             C::MovieHDMIOutputTimeCode,
             "HDMI TC",
             "Embed timecode in HDMI output.",
-            Some(V::Integer),
+            Some(V::Switch),
         ),
         PropertyDef::new(
             C::MovieHDMIOutputRecControl,
             "HDMI Rec Control",
             "Recording control via HDMI.",
-            Some(V::Integer),
+            Some(V::Switch),
         ),
         PropertyDef::new(
             C::MovieHDMIOutputAudioCH,
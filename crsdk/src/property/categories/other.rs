@@ -259,13 +259,13 @@ impl Category for Other {
             C::IntervalRecAETrackingSensitivity,
             "Interval AE Track",
             "AE tracking sensitivity during interval shooting.",
-            Some(V::Integer),
+            Some(V::IntervalRecAETrackingSensitivity),
         ),
         PropertyDef::new(
             C::StreamModeSetting,
             "Stream Mode",
             "Configures the camera for live streaming output.",
-            Some(V::Integer),
+            Some(V::Switch),
         ),
         PropertyDef::new(
             C::StreamStatus,
@@ -589,7 +589,7 @@ impl Category for Other {
             C::SubjectRecognitionPriorityOnRegisteredFace,
             "Registered Face",
             "Priority for registered face recognition.",
-            Some(V::Integer),
+            Some(V::Switch),
         ),
         PropertyDef::new(
             C::APSCOrFullSwitchingEnableStatus,
@@ -613,7 +613,7 @@ impl Category for Other {
             C::ContinuousShootingSpotBoostStatus,
             "Boost Status",
             "Continuous shooting spot boost status.",
-            Some(V::Integer),
+            Some(V::SpotBoostStatus),
         ),
         PropertyDef::new(
             C::ContinuousShootingSpotBoostFrameSpeed,
@@ -637,7 +637,7 @@ impl Category for Other {
             C::SimulRecSetting,
             "Simul Rec",
             "Simultaneous recording to multiple slots/formats.",
-            Some(V::Integer),
+            Some(V::Switch),
         ),
         PropertyDef::new(
             C::ColorSpace,
@@ -1447,7 +1447,7 @@ impl Category for Other {
             C::ProgramShiftStatus,
             "P Shift Status",
             "Program shift mode status.",
-            Some(V::Integer),
+            Some(V::ProgramShiftStatus),
         ),
         PropertyDef::new(
             C::PushAFModeSetting,
@@ -1465,7 +1465,7 @@ impl Category for Other {
             C::RecognitionTarget,
             "Recog Target",
             "Current recognition target type.",
-            Some(V::Integer),
+            Some(V::SubjectTarget),
         ),
         PropertyDef::new(
             C::RemoteTouchOperationEnableStatus,
@@ -6,6 +6,7 @@
 /// - **Discrete**: A list of specific allowed values
 /// - **Range**: A min/max/step triplet defining a numeric range
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ValueConstraint {
     /// No constraint information available
     #[default]
@@ -67,6 +68,66 @@ impl ValueConstraint {
         }
     }
 
+    /// Map a value onto 0.0 (min) ..= 1.0 (max) of a range constraint
+    ///
+    /// Returns `None` if this is not a range constraint or the range is empty.
+    /// Values outside the range are clamped.
+    pub fn normalize(&self, value: i64) -> Option<f32> {
+        match self {
+            Self::Range { min, max, .. } if max > min => {
+                let clamped = value.clamp(*min, *max);
+                Some(((clamped - min) as f64 / (max - min) as f64) as f32)
+            }
+            _ => None,
+        }
+    }
+
+    /// Map 0.0 (min) ..= 1.0 (max) back onto a range constraint
+    ///
+    /// The result is clamped to the range and snapped to the nearest step,
+    /// so it is always a valid value. Returns `None` if this is not a range
+    /// constraint or `position` is not a finite number.
+    pub fn denormalize(&self, position: f32) -> Option<i64> {
+        match self {
+            Self::Range { min, max, step } if position.is_finite() => {
+                let position = position.clamp(0.0, 1.0) as f64;
+                let span = (max - min).max(0);
+                let offset = (span as f64 * position).round() as i64;
+                let offset = if *step > 0 {
+                    ((offset as f64 / *step as f64).round() as i64 * step).min(span / step * step)
+                } else {
+                    offset
+                };
+                Some(min + offset)
+            }
+            _ => None,
+        }
+    }
+
+    /// The valid value closest to `value`
+    ///
+    /// Ranges clamp to their bounds and round to the nearest step; discrete
+    /// lists pick the nearest entry. Without constraint information `value`
+    /// is returned unchanged.
+    pub fn snap(&self, value: i64) -> i64 {
+        match self {
+            Self::Range { min, max, step } => {
+                let clamped = value.clamp(*min, (*max).max(*min));
+                if *step <= 0 {
+                    return clamped;
+                }
+                let steps = ((clamped - min) as f64 / *step as f64).round() as i64;
+                (min + steps * step).min(min + (max - min) / step * step)
+            }
+            Self::Discrete(values) => values
+                .iter()
+                .map(|&v| v as i64)
+                .min_by_key(|&v| v.abs_diff(value))
+                .unwrap_or(value),
+            Self::None => value,
+        }
+    }
+
     /// Expand a range constraint into discrete values (for UI display)
     ///
     /// Returns `None` if this is not a range constraint or if the range
@@ -133,4 +194,44 @@ mod tests {
         let expanded = constraint.expand_range().unwrap();
         assert_eq!(expanded, vec![-10, -5, 0, 5, 10]);
     }
+
+    #[test]
+    fn test_value_constraint_normalize() {
+        let constraint = ValueConstraint::Range {
+            min: 100,
+            max: 300,
+            step: 10,
+        };
+        assert_eq!(constraint.normalize(100), Some(0.0));
+        assert_eq!(constraint.normalize(200), Some(0.5));
+        assert_eq!(constraint.normalize(300), Some(1.0));
+        assert_eq!(constraint.normalize(999), Some(1.0));
+
+        assert_eq!(constraint.denormalize(0.0), Some(100));
+        assert_eq!(constraint.denormalize(0.5), Some(200));
+        assert_eq!(constraint.denormalize(0.52), Some(200));
+        assert_eq!(constraint.denormalize(1.5), Some(300));
+        assert_eq!(constraint.denormalize(f32::NAN), None);
+
+        assert_eq!(ValueConstraint::None.normalize(5), None);
+        assert_eq!(ValueConstraint::Discrete(vec![1, 2]).denormalize(0.5), None);
+    }
+
+    #[test]
+    fn test_value_constraint_snap() {
+        let kelvin = ValueConstraint::Range {
+            min: 2500,
+            max: 9900,
+            step: 100,
+        };
+        assert_eq!(kelvin.snap(5673), 5700);
+        assert_eq!(kelvin.snap(5649), 5600);
+        assert_eq!(kelvin.snap(1000), 2500);
+        assert_eq!(kelvin.snap(12000), 9900);
+
+        let discrete = ValueConstraint::Discrete(vec![3200, 4300, 5600]);
+        assert_eq!(discrete.snap(5000), 5600);
+        assert_eq!(discrete.snap(3000), 3200);
+        assert_eq!(ValueConstraint::None.snap(5673), 5673);
+    }
 }
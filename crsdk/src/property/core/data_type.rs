@@ -4,6 +4,7 @@ use crate::types::FromCrsdk;
 
 /// SDK data type classification for property values.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataType {
     /// 8-bit unsigned integer
     UInt8,
@@ -33,6 +34,19 @@ impl DataType {
         matches!(self, Self::Int8 | Self::Int16 | Self::Int32 | Self::Int64)
     }
 
+    /// Interpret a raw u64 value as a signed integer if this is a signed type.
+    ///
+    /// The value is sign-extended from the type's width, so both zero- and
+    /// sign-extended raw values decode the same. Unsigned values are returned as-is.
+    pub fn to_signed(&self, raw: u64) -> i64 {
+        match self {
+            Self::Int8 => raw as i8 as i64,
+            Self::Int16 => raw as i16 as i64,
+            Self::Int32 => raw as i32 as i64,
+            _ => raw as i64,
+        }
+    }
+
     /// Format a raw u64 value as a signed integer string if this is a signed type.
     /// For unsigned types, returns the value as-is.
     pub fn format_raw(&self, raw: u64) -> String {
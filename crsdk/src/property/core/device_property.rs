@@ -1,10 +1,13 @@
 //! DeviceProperty struct and SDK parsing utilities.
 
 use super::{DataType, EnableFlag, ValueConstraint};
+use crate::error::{Error, Result};
+use crate::property::PropertyValue;
 use crate::types::FromCrsdk;
 
 /// A camera property with its current value and metadata.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeviceProperty {
     /// Property code (raw SDK value)
     pub code: u32,
@@ -18,6 +21,11 @@ pub struct DeviceProperty {
     pub current_string: Option<String>,
     /// Constraint on what values this property can be set to
     pub constraint: ValueConstraint,
+    /// Element values, for properties the SDK reports as an array type
+    ///
+    /// Each element is sign-extended according to `data_type`, so signed
+    /// 16-bit arrays keep their negative values. Empty for scalar properties.
+    pub values: Vec<i64>,
 }
 
 impl DeviceProperty {
@@ -31,6 +39,21 @@ impl DeviceProperty {
         self.enable_flag.is_writable()
     }
 
+    /// Check if this property is usable at all (not disabled or unsupported)
+    pub fn is_available(&self) -> bool {
+        self.enable_flag.is_available()
+    }
+
+    /// Check if the SDK reported this property as an array type
+    pub fn is_array(&self) -> bool {
+        !self.values.is_empty()
+    }
+
+    /// Current value, sign-extended if `data_type` is signed
+    pub fn current_signed(&self) -> i64 {
+        self.data_type.to_signed(self.current_value)
+    }
+
     /// Check if a value is valid for this property
     pub fn is_valid_value(&self, value: u64) -> bool {
         self.constraint.is_valid(value)
@@ -55,8 +78,32 @@ impl DeviceProperty {
     pub fn range_params(&self) -> Option<(i64, i64, i64)> {
         self.constraint.range_params()
     }
+
+    /// Check that this property currently offers `value`
+    ///
+    /// Returns `Error::Unsupported` naming `what` and listing the offered
+    /// values otherwise. Passes if the camera doesn't report a list.
+    pub(crate) fn check_offered<T: PropertyValue>(&self, value: T, what: &str) -> Result<()> {
+        match self.possible_values() {
+            Some(values) if !values.is_empty() && !values.contains(&value.to_raw()) => {
+                let available: Vec<String> = values
+                    .iter()
+                    .filter_map(|&raw| T::from_raw(raw))
+                    .map(|v| v.to_string())
+                    .collect();
+                Err(Error::Unsupported(format!(
+                    "{} {} (available: {})",
+                    what,
+                    value,
+                    available.join(", ")
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
+const ARRAY_BIT: u32 = 0x2000;
 const RANGE_BIT: u32 = 0x4000;
 
 /// Parse raw values from SDK property data as u64 (for discrete values)
@@ -245,6 +292,21 @@ pub(crate) fn parse_constraint(
     }
 }
 
+/// Parse the element values of an array-typed property
+///
+/// Returns an empty list unless the SDK flagged the type as an array.
+pub(crate) fn parse_array_values(
+    raw_value_type: u32,
+    data_type: DataType,
+    values_ptr: *mut u8,
+    values_size: u32,
+) -> Vec<i64> {
+    if (raw_value_type & ARRAY_BIT) == 0 || (raw_value_type & RANGE_BIT) != 0 {
+        return Vec::new();
+    }
+    parse_raw_values_signed(data_type, values_ptr, values_size)
+}
+
 /// Parse UTF-16 string from SDK's currentStr pointer
 unsafe fn parse_current_string(str_ptr: *const u16) -> Option<String> {
     if str_ptr.is_null() {
@@ -266,7 +328,10 @@ unsafe fn parse_current_string(str_ptr: *const u16) -> Option<String> {
 }
 
 /// Convert SDK CrDeviceProperty to our DeviceProperty
-pub(crate) unsafe fn device_property_from_sdk(
+///
+/// # Safety
+/// `prop`'s pointers must be null or point to buffers of the sizes it reports.
+pub unsafe fn device_property_from_sdk(
     prop: &crsdk_sys::SCRSDK::CrDeviceProperty,
 ) -> DeviceProperty {
     let data_type = DataType::from_crsdk(prop.valueType).unwrap();
@@ -283,6 +348,7 @@ pub(crate) unsafe fn device_property_from_sdk(
     };
 
     let current_string = unsafe { parse_current_string(prop.currentStr) };
+    let values = parse_array_values(prop.valueType, data_type, prop.values, prop.valuesSize);
 
     DeviceProperty {
         code: prop.code,
@@ -291,9 +357,45 @@ pub(crate) unsafe fn device_property_from_sdk(
         current_value: prop.currentValue,
         current_string,
         constraint,
+        values,
+    }
+}
+
+/// Convert only the current value of an SDK CrDeviceProperty
+///
+/// Skips the allowed values and array elements (`constraint` is
+/// `ValueConstraint::None` and `values` is empty), which is most of the work
+/// for properties with long discrete lists.
+///
+/// # Safety
+/// `prop.currentStr` must be null or point to an SDK string.
+pub unsafe fn device_property_current_from_sdk(
+    prop: &crsdk_sys::SCRSDK::CrDeviceProperty,
+) -> DeviceProperty {
+    DeviceProperty {
+        code: prop.code,
+        data_type: DataType::from_crsdk(prop.valueType).unwrap(),
+        enable_flag: EnableFlag::from_crsdk(prop.enableFlag).unwrap(),
+        current_value: prop.currentValue,
+        current_string: unsafe { parse_current_string(prop.currentStr) },
+        constraint: ValueConstraint::None,
+        values: Vec::new(),
     }
 }
 
+/// Copy of the property's raw value buffer, exactly as the SDK returned it
+///
+/// # Safety
+///
+/// `prop.values` must be null or point to `prop.valuesSize` readable bytes.
+pub(crate) unsafe fn raw_values_bytes(prop: &crsdk_sys::SCRSDK::CrDeviceProperty) -> Vec<u8> {
+    if prop.values.is_null() || prop.valuesSize == 0 {
+        return Vec::new();
+    }
+    // SAFETY: guaranteed by the caller
+    unsafe { std::slice::from_raw_parts(prop.values, prop.valuesSize as usize) }.to_vec()
+}
+
 /// Convert SDK CrDeviceProperty to our DeviceProperty with debug info
 pub(crate) unsafe fn device_property_from_sdk_debug(
     prop: &crsdk_sys::SCRSDK::CrDeviceProperty,
@@ -317,9 +419,10 @@ pub(crate) unsafe fn device_property_from_sdk_debug(
     };
 
     let current_string = unsafe { parse_current_string(prop.currentStr) };
+    let values = parse_array_values(prop.valueType, data_type, prop.values, prop.valuesSize);
 
     let debug_info = format!(
-        "dataType={:?}(raw={:#06x}) is_range={} valuesSize={} values_ptr={:?} getSetValuesSize={} getSetValues_ptr={:?} values={:?} getSetValues={:?} constraint={:?} currentStr={:?}",
+        "dataType={:?}(raw={:#06x}) is_range={} valuesSize={} values_ptr={:?} getSetValuesSize={} getSetValues_ptr={:?} values={:?} getSetValues={:?} constraint={:?} currentStr={:?} arrayValues={:?}",
         data_type,
         prop.valueType,
         is_range,
@@ -331,6 +434,7 @@ pub(crate) unsafe fn device_property_from_sdk_debug(
         get_set_values,
         constraint,
         current_string,
+        values,
     );
 
     let device_prop = DeviceProperty {
@@ -340,6 +444,7 @@ pub(crate) unsafe fn device_property_from_sdk_debug(
         current_value: prop.currentValue,
         current_string,
         constraint,
+        values,
     };
 
     (device_prop, debug_info)
@@ -348,6 +453,62 @@ pub(crate) unsafe fn device_property_from_sdk_debug(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::property::ExposureProgram;
+    use crate::test_util::prop;
+    use crsdk_sys::DevicePropertyCode;
+
+    #[test]
+    fn test_check_offered() {
+        let mut program = prop(
+            DevicePropertyCode::ExposureProgramMode,
+            ExposureProgram::Manual.to_raw(),
+        );
+        // No list reported: nothing to check against
+        assert!(program
+            .check_offered(ExposureProgram::Auto, "exposure program")
+            .is_ok());
+
+        program.constraint = ValueConstraint::Discrete(vec![
+            ExposureProgram::Manual.to_raw(),
+            ExposureProgram::AperturePriority.to_raw(),
+            0xFFFF,
+        ]);
+        assert!(program
+            .check_offered(ExposureProgram::AperturePriority, "exposure program")
+            .is_ok());
+
+        // Unknown raw values are left out of the list
+        let err = program
+            .check_offered(ExposureProgram::Auto, "exposure program")
+            .unwrap_err();
+        assert!(matches!(err, Error::Unsupported(_)));
+        assert_eq!(
+            err.to_string(),
+            "Not supported by this camera: exposure program Auto (available: M, A)"
+        );
+    }
+
+    #[test]
+    fn test_raw_values_bytes() {
+        let mut buf: Vec<u8> = vec![0x64, 0x00, 0xC8, 0x00];
+        let mut prop = crsdk_sys::SCRSDK::CrDeviceProperty {
+            code: 0,
+            valueType: crsdk_sys::SCRSDK::CrDataType_CrDataType_UInt16,
+            enableFlag: 0,
+            variableFlag: 0,
+            currentValue: 100,
+            currentStr: std::ptr::null_mut(),
+            valuesSize: buf.len() as u32,
+            values: buf.as_mut_ptr(),
+            getSetValuesSize: 0,
+            getSetValues: std::ptr::null_mut(),
+        };
+        // SAFETY: values points to buf, which outlives the call
+        assert_eq!(unsafe { raw_values_bytes(&prop) }, buf);
+
+        prop.values = std::ptr::null_mut();
+        assert!(unsafe { raw_values_bytes(&prop) }.is_empty());
+    }
 
     #[test]
     fn test_device_property_is_valid_value_discrete() {
@@ -358,6 +519,7 @@ mod tests {
             current_value: 100,
             current_string: None,
             constraint: ValueConstraint::Discrete(vec![100, 200, 400, 800]),
+            values: Vec::new(),
         };
         assert!(prop.is_valid_value(100));
         assert!(prop.is_valid_value(400));
@@ -375,6 +537,7 @@ mod tests {
             current_value: 100,
             current_string: None,
             constraint: ValueConstraint::None,
+            values: Vec::new(),
         };
         assert!(prop_empty.is_valid_value(999));
         assert!(prop_empty.possible_values().is_none());
@@ -393,6 +556,7 @@ mod tests {
                 max: 7,
                 step: 1,
             },
+            values: Vec::new(),
         };
         assert!(prop_range.is_valid_value(1));
         assert!(prop_range.is_valid_value(4));
@@ -403,4 +567,29 @@ mod tests {
         assert_eq!(prop_range.range_params(), Some((1, 7, 1)));
         assert!(prop_range.possible_values().is_none());
     }
+
+    #[test]
+    fn test_parse_array_values_signed() {
+        let mut bytes: Vec<u8> = [-100i16, 0, 250]
+            .iter()
+            .flat_map(|v| v.to_ne_bytes())
+            .collect();
+        let int16_array = 0x2000 | crsdk_sys::SCRSDK::CrDataType_CrDataType_Int16;
+
+        let values = parse_array_values(
+            int16_array,
+            DataType::Int16,
+            bytes.as_mut_ptr(),
+            bytes.len() as u32,
+        );
+        assert_eq!(values, vec![-100, 0, 250]);
+
+        let scalar = parse_array_values(
+            crsdk_sys::SCRSDK::CrDataType_CrDataType_Int16,
+            DataType::Int16,
+            bytes.as_mut_ptr(),
+            bytes.len() as u32,
+        );
+        assert!(scalar.is_empty());
+    }
 }
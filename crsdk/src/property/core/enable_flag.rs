@@ -4,6 +4,7 @@ use crate::types::FromCrsdk;
 
 /// Property enable/writable status.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EnableFlag {
     /// Property is not supported by this camera
     NotSupported,
@@ -14,6 +15,11 @@ pub enum EnableFlag {
     /// Property is read-only (display only)
     ReadOnly,
     /// Property is write-only
+    ///
+    /// The property can be set, but its current value isn't meaningful:
+    /// `get_property()` still succeeds and returns the entry the SDK reports,
+    /// but `current_value` should not be relied on (it is typically zero or
+    /// the last value the SDK cached, not the camera's actual state).
     WriteOnly,
 }
 
@@ -33,14 +39,26 @@ impl FromCrsdk<i16> for EnableFlag {
 
 impl EnableFlag {
     /// Check if the property is readable
+    ///
+    /// True for `ReadWrite` and `ReadOnly`, i.e. when `current_value` reflects
+    /// the camera's state.
     pub fn is_readable(self) -> bool {
         matches!(self, Self::ReadWrite | Self::ReadOnly)
     }
 
     /// Check if the property is writable
+    ///
+    /// True for `ReadWrite` and `WriteOnly`.
     pub fn is_writable(self) -> bool {
         matches!(self, Self::ReadWrite | Self::WriteOnly)
     }
+
+    /// Check if the property is usable at all (readable, writable, or both)
+    ///
+    /// False for `Disabled` and `NotSupported`.
+    pub fn is_available(self) -> bool {
+        !matches!(self, Self::Disabled | Self::NotSupported)
+    }
 }
 
 #[cfg(test)]
@@ -58,4 +76,13 @@ mod tests {
         assert!(!EnableFlag::NotSupported.is_readable());
         assert!(!EnableFlag::NotSupported.is_writable());
     }
+
+    #[test]
+    fn test_enable_flag_available() {
+        assert!(EnableFlag::ReadWrite.is_available());
+        assert!(EnableFlag::ReadOnly.is_available());
+        assert!(EnableFlag::WriteOnly.is_available());
+        assert!(!EnableFlag::Disabled.is_available());
+        assert!(!EnableFlag::NotSupported.is_available());
+    }
 }
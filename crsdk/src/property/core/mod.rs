@@ -8,7 +8,7 @@
 
 mod constraint;
 mod data_type;
-mod device_property;
+pub(crate) mod device_property;
 mod enable_flag;
 
 pub use constraint::ValueConstraint;
@@ -16,4 +16,7 @@ pub use data_type::DataType;
 pub use device_property::DeviceProperty;
 pub use enable_flag::EnableFlag;
 
-pub(crate) use device_property::{device_property_from_sdk, device_property_from_sdk_debug};
+pub(crate) use device_property::{
+    device_property_current_from_sdk, device_property_from_sdk, device_property_from_sdk_debug,
+    raw_values_bytes,
+};
@@ -20,42 +20,53 @@
 //! - All property codes are explicitly categorized
 
 pub mod categories;
-mod core;
+pub(crate) mod core;
 mod traits;
 mod typed_value;
 pub mod values;
 
 // Re-export core infrastructure types
-pub(crate) use core::{device_property_from_sdk, device_property_from_sdk_debug};
+pub(crate) use core::{
+    device_property_current_from_sdk, device_property_from_sdk, device_property_from_sdk_debug,
+    raw_values_bytes,
+};
 pub use core::{DataType, DeviceProperty, EnableFlag, ValueConstraint};
 
 // Re-export core trait and typed value
 pub use traits::PropertyValue;
+pub(crate) use typed_value::is_unavailable;
 pub use typed_value::TypedValue;
 
 // Re-export category types from new categories module
 pub use categories::{
     all_categories, description as property_description, display_name as property_display_name,
-    property_category, value_type as property_value_type, Category, PropertyCategoryId,
+    property_category, summarize as summarize_categories, value_type as property_value_type,
+    Category, CategorySummary, PropertyCategoryId,
 };
 
 // Re-export all value types from values/
 pub use values::{
-    AspectRatio, AutoManual, FileType, FlashMode, FocusArea, FocusBracketOrder,
+    Aperture, ExposureComp, ExposureCtrlType, ExposureProgram, Gain, GainUnitSetting, Iso, IsoMode,
+    ProgramShiftStatus, ShutterAngle, ShutterSpeed,
+};
+pub use values::{
+    AspectRatio, AutoManual, ColorTemperature, FileType, FlashMode, FocusArea, FocusBracketOrder,
     FocusBracketShootingStatus, FocusDrivingStatus, FocusFrameState, FocusIndicator, FocusMode,
     FocusTouchSpotStatus, FocusTrackingStatus, ImageQuality, ImageSize, LiveViewDisplayEffect,
-    LiveViewProtocol, LockIndicator, MeteringMode, OnOff, PrioritySetInAF, PrioritySetInAWB,
-    PropertyValueType, PushAutoFocus, ShutterMode, ShutterModeStatus, SilentModeApertureDrive,
-    SubjectRecognitionAF, Switch, TrackingFrameType, WhiteBalance, WhiteBalanceSwitch,
+    LiveViewProtocol, LiveViewQuality, LockIndicator, MeteringMode, OnOff, PrioritySetInAF,
+    PrioritySetInAWB, PropertyValueType, PushAutoFocus, ShutterMode, ShutterModeStatus,
+    SilentModeApertureDrive, SubjectRecognitionAF, SubjectTarget, Switch, TrackingFrameType,
+    WhiteBalance, WhiteBalanceSwitch,
 };
-pub use values::{ExposureCtrlType, ExposureProgram, GainUnitSetting};
 
 // Re-export drive and movie types from values/
 pub use values::{
-    DriveMode, IntervalRecShutterType, MovieFileFormat, MoviePlayingState, MovieQuality,
-    MovieRecReviewPlayingState, MovieShootingModeColorGamut, MovieShootingModeTargetDisplay,
-    PlaybackContentsGammaType, RecorderSaveDestination, RecorderStatus, RecordingFolderFormat,
-    RecordingState, TimeCodeFormat, TimeCodeMake, TimeCodeRun, VideoRecordingFormatQuality,
+    DriveMode, IntervalRecAETrackingSensitivity, IntervalRecShutterType, MovieFileFormat,
+    MoviePlayingState, MovieQuality, MovieRecReviewPlayingState, MovieResolution,
+    MovieShootingModeColorGamut, MovieShootingModeTargetDisplay, PlaybackContentsGammaType,
+    RecorderSaveDestination, RecorderStatus, RecordingFolderFormat, RecordingFrameRate,
+    RecordingState, SpotBoostStatus, TimeCodeFormat, TimeCodeMake, TimeCodeRun,
+    VideoRecordingFormatQuality,
 };
 
 // Re-export media types from values/
@@ -13,17 +13,18 @@ use super::values::{
     ExposureCtrlType, ExposureProgram, FaceFrameType, FileType, FlashMode, FocusArea,
     FocusBracketOrder, FocusBracketShootingStatus, FocusDrivingStatus, FocusFrameState,
     FocusFrameType, FocusIndicator, FocusMode, FocusTouchSpotStatus, FocusTrackingStatus,
-    FrameInfoType, GainUnitSetting, GridLineType, ImageQuality, ImageSize, Integer, Iso,
-    LiveViewDisplayEffect, LiveViewProtocol, LockIndicator, MeterLevel, MeteringMode,
-    MoviePlayingState, MovieQuality, MovieRecReviewPlayingState, MovieShootingMode,
-    MovieShootingModeColorGamut, MovieShootingModeTargetDisplay, OnOff, Percentage, PictureProfile,
-    PlaybackContentsGammaType, PrioritySetInAF, PrioritySetInAWB, PushAutoFocus,
-    RecorderSaveDestination, RecordingFolderFormat, ShutterAngle, ShutterMode, ShutterModeStatus,
-    ShutterSpeed, SilentModeApertureDrive, SubjectRecognitionAF, Switch, TrackingFrameType,
+    FrameInfoType, Gain, GainUnitSetting, GridLineType, ImageQuality, ImageSize, Integer, Iso,
+    LiveViewDisplayEffect, LiveViewProtocol, LiveViewQuality, LockIndicator, MeterLevel,
+    MeteringMode, MoviePlayingState, MovieQuality, MovieRecReviewPlayingState, MovieResolution,
+    MovieShootingMode, MovieShootingModeColorGamut, MovieShootingModeTargetDisplay, OnOff,
+    Percentage, PictureProfile, PlaybackContentsGammaType, PrioritySetInAF, PrioritySetInAWB,
+    ProgramShiftStatus, PushAutoFocus, RecorderSaveDestination, RecordingFolderFormat,
+    RecordingFrameRate, ShutterAngle, ShutterMode, ShutterModeStatus, ShutterSpeed,
+    SilentModeApertureDrive, SubjectRecognitionAF, SubjectTarget, Switch, TrackingFrameType,
     VideoRecordingFormatQuality, VideoStreamCodec, WhiteBalance, WhiteBalanceSwitch,
     ZoomDrivingStatus, ZoomTypeStatus,
 };
-use super::{property_value_type, PropertyValueType};
+use super::{property_value_type, DataType, DeviceProperty, PropertyValueType};
 use super::{
     AFTrackForSpeedChange, AFTrackingSensitivity, ApertureDriveInAF, AudioSignals,
     AudioStreamBitDepth, AutoPowerOffTemperature, BatteryRemainDisplayUnit, CameraOperatingMode,
@@ -31,16 +32,17 @@ use super::{
     DeviceOverheatingState, DispMode, DriveMode, EframingProductionEffect, FTPConnectionStatus,
     FocusOperation, FocusOperationWithInt16EnableStatus, FunctionOfTouchOperation,
     GainBaseSensitivity, HighIsoNR, ImageStabilizationLevelMovie,
-    ImageStabilizationSteadyShotMovie, ImagerScanMode, IntervalRecMode, IntervalRecShutterType,
-    IntervalRecStatus, IrisDisplayUnit, IsoAutoMinShutterSpeedMode, IsoAutoMinShutterSpeedPreset,
-    LensCompensationShading, LiveViewStatus, MediaSlotRecordingType, MediaSlotWritingState,
-    MonitoringOutputFormat, MovieFileFormat, NDFilterMode, NearFarEnableStatus, PictureEffect,
+    ImageStabilizationSteadyShotMovie, ImagerScanMode, IntervalRecAETrackingSensitivity,
+    IntervalRecMode, IntervalRecShutterType, IntervalRecStatus, IrisDisplayUnit,
+    IsoAutoMinShutterSpeedMode, IsoAutoMinShutterSpeedPreset, LensCompensationShading,
+    LiveViewStatus, MediaSlotRecordingType, MediaSlotWritingState, MonitoringOutputFormat,
+    MovieFileFormat, NDFilterMode, NearFarEnableStatus, PictureEffect,
     PictureProfileBlackGammaRange, PictureProfileColorMode, PictureProfileDetailAdjustMode,
     PictureProfileGamma, PictureProfileKneeAutoSetSensitivity, PictureProfileKneeMode,
     PictureProfileResetEnableStatus, PlaybackMedia, PowerSource, PriorityKeySettings,
     RAWFileCompressionType, RecorderStatus, RecordingMedia, RecordingMediaMovie, RecordingState,
     RemoconZoomSpeedType, RightLeftEyeSelect, SdkControlMode, SelectFinder,
-    ShutterReleaseTimeLagControl, ShutterType, SlotStatus, SoftSkinEffect,
+    ShutterReleaseTimeLagControl, ShutterType, SlotStatus, SoftSkinEffect, SpotBoostStatus,
     StillImageStoreDestination, StreamCipherType, StreamStatus,
     SubjectRecognitionAnimalBirdDetectionParts, SubjectRecognitionAnimalBirdPriority,
     TCUBDisplaySetting, TimeCodeFormat, TimeCodeMake, TimeCodePresetResetEnableStatus, TimeCodeRun,
@@ -63,7 +65,21 @@ use crate::property::traits::PropertyValue;
 /// let value = TypedValue::from_raw(DevicePropertyCode::Aperture, 280);
 /// assert_eq!(value.to_string(), "f/2.8");
 /// ```
-#[derive(Debug, Clone)]
+///
+/// Values compare semantically, so two snapshots can be diffed with `==`
+/// instead of comparing their Display strings. Use [`TypedValue::is_numeric`]
+/// and [`TypedValue::is_enum`] to handle kinds of value generically, or
+/// match on the variant to get at the decoded value:
+///
+/// ```ignore
+/// match TypedValue::from_property(&prop) {
+///     TypedValue::Iso(iso) => println!("ISO mode {:?}", iso.mode()),
+///     TypedValue::String(s) => println!("text: {}", s),
+///     v if v.is_numeric() => println!("number: {:?}", v.as_integer()),
+///     v => println!("{}", v),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum TypedValue {
     /// Aperture value (f-number)
@@ -74,6 +90,9 @@ pub enum TypedValue {
     ShutterAngle(ShutterAngle),
     /// ISO sensitivity value
     Iso(Iso),
+    /// Gain as ISO or dB, whichever `GainUnitSetting` selects (see
+    /// [`TypedValue::from_snapshot`])
+    Gain(Gain),
     /// Exposure compensation value
     ExposureComp(ExposureComp),
     /// Exposure meter level (signed)
@@ -84,6 +103,8 @@ pub enum TypedValue {
     MeteringMode(MeteringMode),
     /// Shutter mode status (Off/Speed/Angle/ECS/Auto)
     ShutterModeStatus(ShutterModeStatus),
+    /// Program shift status (Off/Shifted)
+    ProgramShiftStatus(ProgramShiftStatus),
     /// Shutter mode (Speed/Angle)
     ShutterMode(ShutterMode),
     /// Exposure control type (P/A/S/M vs Flexible)
@@ -96,6 +117,8 @@ pub enum TypedValue {
     FocusArea(FocusArea),
     /// Subject recognition in AF (Human/Animal/Bird/etc.)
     SubjectRecognitionAF(SubjectRecognitionAF),
+    /// AI subject recognition target
+    SubjectTarget(SubjectTarget),
     /// Priority setting in AF (AF/Release/Balanced)
     PrioritySetInAF(PrioritySetInAF),
     /// Focus tracking status (Off/Focusing/Tracking)
@@ -116,8 +139,12 @@ pub enum TypedValue {
     ColorTemperature(ColorTemperature),
     /// Drive mode (Single/Continuous/Bracket/Timer/etc.)
     DriveMode(DriveMode),
+    /// Continuous-shooting spot boost status
+    SpotBoostStatus(SpotBoostStatus),
     /// Interval recording shutter type
     IntervalRecShutterType(IntervalRecShutterType),
+    /// Interval recording AE tracking sensitivity
+    IntervalRecAETrackingSensitivity(IntervalRecAETrackingSensitivity),
     /// Flash mode (Auto/Off/Fill/Sync/etc.)
     FlashMode(FlashMode),
     /// File type (JPEG/RAW/RAW+JPEG/HEIF)
@@ -128,10 +155,14 @@ pub enum TypedValue {
     AspectRatio(AspectRatio),
     /// Image size (L/M/S/VGA)
     ImageSize(ImageSize),
+    /// Live view image quality
+    LiveViewQuality(LiveViewQuality),
     /// Movie file format (XAVC/AVCHD/etc.)
     MovieFileFormat(MovieFileFormat),
     /// Movie quality setting
     MovieQuality(MovieQuality),
+    /// Movie recording resolution
+    MovieResolution(MovieResolution),
     /// Movie shooting mode (Off/Cine EI/Custom/etc.)
     MovieShootingMode(MovieShootingMode),
     /// Movie recording state
@@ -346,6 +377,8 @@ pub enum TypedValue {
     MovieRecReviewPlayingState(MovieRecReviewPlayingState),
     /// Playback contents gamma type
     PlaybackContentsGammaType(PlaybackContentsGammaType),
+    /// Movie recording frame rate
+    RecordingFrameRate(RecordingFrameRate),
 
     // Picture profile control types
     /// Picture profile detail adjustment mode
@@ -365,11 +398,114 @@ pub enum TypedValue {
     Percentage(Percentage),
     /// Generic integer value
     Integer(Integer),
+    /// String value (for string-typed properties)
+    String(String),
+    /// The camera has no reading right now, e.g. zoom distance with a
+    /// manual lens or battery level on AC power (raw sentinel value)
+    Unavailable(u64),
     /// Unknown or unrecognized value
     Unknown(u64),
 }
 
+/// Whether `raw` is the sentinel `code` reports when it has no reading
+///
+/// This is the table behind [`TypedValue::Unavailable`]. Battery levels
+/// may carry a status word above the level, so only the level is compared.
+/// Focus distance is absent on purpose: its `0xFFFFFFFF` means infinity.
+pub(crate) fn is_unavailable(code: DevicePropertyCode, raw: u64) -> bool {
+    use DevicePropertyCode as C;
+    match code {
+        C::ZoomDistance => raw == 0xFFFF_FFFF,
+        C::BatteryRemain | C::SecondBatteryRemain | C::TotalBatteryRemain => raw & 0xFFFF == 0xFFFF,
+        C::BatteryRemainingInMinutes
+        | C::BatteryRemainingInVoltage
+        | C::MediaSLOT1RemainingTime
+        | C::MediaSLOT2RemainingTime
+        | C::MediaSLOT3RemainingTime => raw == 0xFFFF || raw == 0xFFFF_FFFF,
+        _ => false,
+    }
+}
+
 impl TypedValue {
+    /// Convert a property's current value to a typed value.
+    ///
+    /// Unlike [`TypedValue::from_raw`], this also handles string-typed
+    /// properties, which carry their value in `current_string`.
+    pub fn from_property(prop: &DeviceProperty) -> Self {
+        if prop.data_type == DataType::String {
+            if let Some(s) = &prop.current_string {
+                return TypedValue::String(s.clone());
+            }
+        }
+        match DevicePropertyCode::from_raw(prop.code) {
+            Some(code) => Self::from_raw(code, prop.current_value),
+            None => TypedValue::Unknown(prop.current_value),
+        }
+    }
+
+    /// Whether this is a numeric quantity (f-number, ISO, Kelvin, plain integer, etc.)
+    /// rather than a choice from a fixed set.
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            TypedValue::Aperture(_)
+                | TypedValue::ShutterSpeed(_)
+                | TypedValue::ShutterAngle(_)
+                | TypedValue::Iso(_)
+                | TypedValue::Gain(_)
+                | TypedValue::ExposureComp(_)
+                | TypedValue::MeterLevel(_)
+                | TypedValue::ColorTemperature(_)
+                | TypedValue::BatteryLevel(_)
+                | TypedValue::Percentage(_)
+                | TypedValue::Integer(_)
+        )
+    }
+
+    /// Whether this is a choice from a fixed set (a mode, status or on/off).
+    pub fn is_enum(&self) -> bool {
+        !self.is_numeric()
+            && !matches!(
+                self,
+                TypedValue::String(_) | TypedValue::Unavailable(_) | TypedValue::Unknown(_)
+            )
+    }
+
+    /// Get plain integer values as `i64`.
+    ///
+    /// Returns `Some` for [`TypedValue::Integer`], [`TypedValue::Percentage`],
+    /// [`TypedValue::MeterLevel`] and [`TypedValue::ColorTemperature`].
+    /// Values with their own units (aperture, shutter speed, ISO) are best
+    /// read through their own accessors.
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            TypedValue::Integer(v) => Some(v.value()),
+            TypedValue::Percentage(v) => Some(v.value() as i64),
+            TypedValue::MeterLevel(v) => Some(v.value()),
+            TypedValue::ColorTemperature(v) => Some(v.kelvin() as i64),
+            _ => None,
+        }
+    }
+
+    /// Convert `code`'s current value in a property snapshot to a typed value
+    ///
+    /// Like [`TypedValue::from_property`], except `IsoSensitivity` and
+    /// `GaindBValue` decode as [`TypedValue::Gain`] in the unit
+    /// `GainUnitSetting` selects, so a body set to dB shows "6 dB" rather
+    /// than its ISO. Returns `None` if `code` isn't in `props`.
+    pub fn from_snapshot(code: DevicePropertyCode, props: &[DeviceProperty]) -> Option<Self> {
+        let prop = props.iter().find(|p| p.code == code.as_raw())?;
+        if matches!(
+            code,
+            DevicePropertyCode::IsoSensitivity | DevicePropertyCode::GaindBValue
+        ) {
+            if let Some(gain) = Gain::from_properties(props) {
+                return Some(TypedValue::Gain(gain));
+            }
+        }
+        Some(Self::from_property(prop))
+    }
+
     /// Convert a raw SDK value to a typed value based on property code.
     ///
     /// This is the primary way to convert raw camera property values into
@@ -377,6 +513,10 @@ impl TypedValue {
     pub fn from_raw(code: DevicePropertyCode, raw: u64) -> Self {
         use PropertyValueType as PVT;
 
+        if is_unavailable(code, raw) {
+            return TypedValue::Unavailable(raw);
+        }
+
         match property_value_type(code) {
             PVT::Aperture => Aperture::from_raw(raw)
                 .map(TypedValue::Aperture)
@@ -399,6 +539,9 @@ impl TypedValue {
             PVT::MovieQuality => MovieQuality::from_raw(raw)
                 .map(TypedValue::MovieQuality)
                 .unwrap_or(TypedValue::Unknown(raw)),
+            PVT::MovieResolution => MovieResolution::from_raw(raw)
+                .map(TypedValue::MovieResolution)
+                .unwrap_or(TypedValue::Unknown(raw)),
             PVT::ExposureProgram => ExposureProgram::from_raw(raw)
                 .map(TypedValue::ExposureProgram)
                 .unwrap_or(TypedValue::Unknown(raw)),
@@ -414,6 +557,9 @@ impl TypedValue {
             PVT::SubjectRecognitionAF => SubjectRecognitionAF::from_raw(raw)
                 .map(TypedValue::SubjectRecognitionAF)
                 .unwrap_or(TypedValue::Unknown(raw)),
+            PVT::SubjectTarget => SubjectTarget::from_raw(raw)
+                .map(TypedValue::SubjectTarget)
+                .unwrap_or(TypedValue::Unknown(raw)),
             PVT::PrioritySetInAF => PrioritySetInAF::from_raw(raw)
                 .map(TypedValue::PrioritySetInAF)
                 .unwrap_or(TypedValue::Unknown(raw)),
@@ -444,9 +590,17 @@ impl TypedValue {
             PVT::DriveMode => DriveMode::from_raw(raw)
                 .map(TypedValue::DriveMode)
                 .unwrap_or(TypedValue::Unknown(raw)),
+            PVT::SpotBoostStatus => SpotBoostStatus::from_raw(raw)
+                .map(TypedValue::SpotBoostStatus)
+                .unwrap_or(TypedValue::Unknown(raw)),
             PVT::IntervalRecShutterType => IntervalRecShutterType::from_raw(raw)
                 .map(TypedValue::IntervalRecShutterType)
                 .unwrap_or(TypedValue::Unknown(raw)),
+            PVT::IntervalRecAETrackingSensitivity => {
+                IntervalRecAETrackingSensitivity::from_raw(raw)
+                    .map(TypedValue::IntervalRecAETrackingSensitivity)
+                    .unwrap_or(TypedValue::Unknown(raw))
+            }
             PVT::FlashMode => FlashMode::from_raw(raw)
                 .map(TypedValue::FlashMode)
                 .unwrap_or(TypedValue::Unknown(raw)),
@@ -462,6 +616,9 @@ impl TypedValue {
             PVT::ImageSize => ImageSize::from_raw(raw)
                 .map(TypedValue::ImageSize)
                 .unwrap_or(TypedValue::Unknown(raw)),
+            PVT::LiveViewQuality => LiveViewQuality::from_raw(raw)
+                .map(TypedValue::LiveViewQuality)
+                .unwrap_or(TypedValue::Unknown(raw)),
             PVT::MovieFileFormat => MovieFileFormat::from_raw(raw)
                 .map(TypedValue::MovieFileFormat)
                 .unwrap_or(TypedValue::Unknown(raw)),
@@ -720,6 +877,9 @@ impl TypedValue {
             PVT::ShutterModeStatus => ShutterModeStatus::from_raw(raw)
                 .map(TypedValue::ShutterModeStatus)
                 .unwrap_or(TypedValue::Unknown(raw)),
+            PVT::ProgramShiftStatus => ProgramShiftStatus::from_raw(raw)
+                .map(TypedValue::ProgramShiftStatus)
+                .unwrap_or(TypedValue::Unknown(raw)),
             PVT::ShutterMode => ShutterMode::from_raw(raw)
                 .map(TypedValue::ShutterMode)
                 .unwrap_or(TypedValue::Unknown(raw)),
@@ -784,6 +944,9 @@ impl TypedValue {
             PVT::PlaybackContentsGammaType => PlaybackContentsGammaType::from_raw(raw)
                 .map(TypedValue::PlaybackContentsGammaType)
                 .unwrap_or(TypedValue::Unknown(raw)),
+            PVT::RecordingFrameRate => RecordingFrameRate::from_raw(raw)
+                .map(TypedValue::RecordingFrameRate)
+                .unwrap_or(TypedValue::Unknown(raw)),
             // Picture profile control types
             PVT::PictureProfileDetailAdjustMode => PictureProfileDetailAdjustMode::from_raw(raw)
                 .map(TypedValue::PictureProfileDetailAdjustMode)
@@ -855,17 +1018,20 @@ impl TypedValue {
             TypedValue::ShutterSpeed(v) => v.to_raw(),
             TypedValue::ShutterAngle(v) => v.to_raw(),
             TypedValue::Iso(v) => v.to_raw(),
+            TypedValue::Gain(v) => v.to_raw(),
             TypedValue::ExposureComp(v) => v.to_raw(),
             TypedValue::MeterLevel(v) => v.to_raw(),
             TypedValue::ExposureProgram(v) => v.to_raw(),
             TypedValue::MeteringMode(v) => v.to_raw(),
             TypedValue::ShutterModeStatus(v) => v.to_raw(),
+            TypedValue::ProgramShiftStatus(v) => v.to_raw(),
             TypedValue::ShutterMode(v) => v.to_raw(),
             TypedValue::ExposureCtrlType(v) => v.to_raw(),
             TypedValue::GainUnitSetting(v) => v.to_raw(),
             TypedValue::FocusMode(v) => v.to_raw(),
             TypedValue::FocusArea(v) => v.to_raw(),
             TypedValue::SubjectRecognitionAF(v) => v.to_raw(),
+            TypedValue::SubjectTarget(v) => v.to_raw(),
             TypedValue::PrioritySetInAF(v) => v.to_raw(),
             TypedValue::FocusTrackingStatus(v) => v.to_raw(),
             TypedValue::FocusIndicator(v) => v.to_raw(),
@@ -877,14 +1043,18 @@ impl TypedValue {
             TypedValue::WhiteBalanceSwitch(v) => v.to_raw(),
             TypedValue::ColorTemperature(v) => v.to_raw(),
             TypedValue::DriveMode(v) => v.to_raw(),
+            TypedValue::SpotBoostStatus(v) => v.to_raw(),
             TypedValue::IntervalRecShutterType(v) => v.to_raw(),
+            TypedValue::IntervalRecAETrackingSensitivity(v) => v.to_raw(),
             TypedValue::FlashMode(v) => v.to_raw(),
             TypedValue::FileType(v) => v.to_raw(),
             TypedValue::ImageQuality(v) => v.to_raw(),
             TypedValue::AspectRatio(v) => v.to_raw(),
             TypedValue::ImageSize(v) => v.to_raw(),
+            TypedValue::LiveViewQuality(v) => v.to_raw(),
             TypedValue::MovieFileFormat(v) => v.to_raw(),
             TypedValue::MovieQuality(v) => v.to_raw(),
+            TypedValue::MovieResolution(v) => v.to_raw(),
             TypedValue::MovieShootingMode(v) => v.to_raw(),
             TypedValue::RecordingState(v) => v.to_raw(),
             TypedValue::RecorderStatus(v) => v.to_raw(),
@@ -991,6 +1161,7 @@ impl TypedValue {
             TypedValue::MoviePlayingState(v) => v.to_raw(),
             TypedValue::MovieRecReviewPlayingState(v) => v.to_raw(),
             TypedValue::PlaybackContentsGammaType(v) => v.to_raw(),
+            TypedValue::RecordingFrameRate(v) => v.to_raw(),
             // Picture profile control types
             TypedValue::PictureProfileDetailAdjustMode(v) => v.to_raw(),
             TypedValue::PictureProfileKneeMode(v) => v.to_raw(),
@@ -1000,6 +1171,9 @@ impl TypedValue {
             TypedValue::TimeCodePresetResetEnableStatus(v) => v.to_raw(),
             TypedValue::Percentage(v) => v.to_raw(),
             TypedValue::Integer(v) => v.to_raw(),
+            // String values aren't encoded in the raw value
+            TypedValue::String(_) => 0,
+            TypedValue::Unavailable(v) => *v,
             TypedValue::Unknown(v) => *v,
         }
     }
@@ -1012,17 +1186,20 @@ impl fmt::Display for TypedValue {
             TypedValue::ShutterSpeed(v) => write!(f, "{}", v),
             TypedValue::ShutterAngle(v) => write!(f, "{}", v),
             TypedValue::Iso(v) => write!(f, "{}", v),
+            TypedValue::Gain(v) => write!(f, "{}", v),
             TypedValue::ExposureComp(v) => write!(f, "{}", v),
             TypedValue::MeterLevel(v) => write!(f, "{}", v),
             TypedValue::ExposureProgram(v) => write!(f, "{}", v),
             TypedValue::MeteringMode(v) => write!(f, "{}", v),
             TypedValue::ShutterModeStatus(v) => write!(f, "{}", v),
+            TypedValue::ProgramShiftStatus(v) => write!(f, "{}", v),
             TypedValue::ShutterMode(v) => write!(f, "{}", v),
             TypedValue::ExposureCtrlType(v) => write!(f, "{}", v),
             TypedValue::GainUnitSetting(v) => write!(f, "{}", v),
             TypedValue::FocusMode(v) => write!(f, "{}", v),
             TypedValue::FocusArea(v) => write!(f, "{}", v),
             TypedValue::SubjectRecognitionAF(v) => write!(f, "{}", v),
+            TypedValue::SubjectTarget(v) => write!(f, "{}", v),
             TypedValue::PrioritySetInAF(v) => write!(f, "{}", v),
             TypedValue::FocusTrackingStatus(v) => write!(f, "{}", v),
             TypedValue::FocusIndicator(v) => write!(f, "{}", v),
@@ -1034,14 +1211,18 @@ impl fmt::Display for TypedValue {
             TypedValue::WhiteBalanceSwitch(v) => write!(f, "{}", v),
             TypedValue::ColorTemperature(v) => write!(f, "{}", v),
             TypedValue::DriveMode(v) => write!(f, "{}", v),
+            TypedValue::SpotBoostStatus(v) => write!(f, "{}", v),
             TypedValue::IntervalRecShutterType(v) => write!(f, "{}", v),
+            TypedValue::IntervalRecAETrackingSensitivity(v) => write!(f, "{}", v),
             TypedValue::FlashMode(v) => write!(f, "{}", v),
             TypedValue::FileType(v) => write!(f, "{}", v),
             TypedValue::ImageQuality(v) => write!(f, "{}", v),
             TypedValue::AspectRatio(v) => write!(f, "{}", v),
             TypedValue::ImageSize(v) => write!(f, "{}", v),
+            TypedValue::LiveViewQuality(v) => write!(f, "{}", v),
             TypedValue::MovieFileFormat(v) => write!(f, "{}", v),
             TypedValue::MovieQuality(v) => write!(f, "{}", v),
+            TypedValue::MovieResolution(v) => write!(f, "{}", v),
             TypedValue::MovieShootingMode(v) => write!(f, "{}", v),
             TypedValue::RecordingState(v) => write!(f, "{}", v),
             TypedValue::RecorderStatus(v) => write!(f, "{}", v),
@@ -1148,6 +1329,7 @@ impl fmt::Display for TypedValue {
             TypedValue::MoviePlayingState(v) => write!(f, "{}", v),
             TypedValue::MovieRecReviewPlayingState(v) => write!(f, "{}", v),
             TypedValue::PlaybackContentsGammaType(v) => write!(f, "{}", v),
+            TypedValue::RecordingFrameRate(v) => write!(f, "{}", v),
             // Picture profile control types
             TypedValue::PictureProfileDetailAdjustMode(v) => write!(f, "{}", v),
             TypedValue::PictureProfileKneeMode(v) => write!(f, "{}", v),
@@ -1157,6 +1339,8 @@ impl fmt::Display for TypedValue {
             TypedValue::TimeCodePresetResetEnableStatus(v) => write!(f, "{}", v),
             TypedValue::Percentage(v) => write!(f, "{}", v),
             TypedValue::Integer(v) => write!(f, "{}", v),
+            TypedValue::String(v) => write!(f, "{}", v),
+            TypedValue::Unavailable(_) => write!(f, "—"),
             TypedValue::Unknown(v) => write!(f, "0x{:X}", v),
         }
     }
@@ -1207,6 +1391,100 @@ mod tests {
         assert_eq!(value.to_string(), "0xDEADBEEF");
     }
 
+    #[test]
+    fn test_gain_from_snapshot() {
+        use crate::test_util::prop;
+
+        let mut props = vec![
+            prop(DevicePropertyCode::IsoSensitivity, 800),
+            prop(DevicePropertyCode::GaindBValue, 6),
+        ];
+        let iso = TypedValue::from_snapshot(DevicePropertyCode::IsoSensitivity, &props).unwrap();
+        assert_eq!(iso.to_string(), "ISO 800");
+
+        props.push(prop(
+            DevicePropertyCode::GainUnitSetting,
+            GainUnitSetting::DB.to_raw(),
+        ));
+        let gain = TypedValue::from_snapshot(DevicePropertyCode::IsoSensitivity, &props).unwrap();
+        assert_eq!(gain, TypedValue::Gain(Gain::Db(6)));
+        assert_eq!(gain.to_string(), "6 dB");
+
+        assert_eq!(
+            TypedValue::from_snapshot(DevicePropertyCode::FNumber, &props),
+            None
+        );
+    }
+
+    #[test]
+    fn test_unavailable_sentinels() {
+        let value = TypedValue::from_raw(DevicePropertyCode::ZoomDistance, 0xFFFF_FFFF);
+        assert_eq!(value, TypedValue::Unavailable(0xFFFF_FFFF));
+        assert_eq!(value.to_string(), "—");
+        assert_eq!(value.to_raw(), 0xFFFF_FFFF);
+        assert!(!value.is_numeric() && !value.is_enum());
+
+        // Focus distance uses the same value for infinity, which is a reading
+        assert!(!is_unavailable(
+            DevicePropertyCode::FocalDistanceInMeter,
+            0xFFFF_FFFF
+        ));
+
+        // Battery on AC, with a status word above the level
+        let value = TypedValue::from_raw(DevicePropertyCode::BatteryRemain, 0x1_FFFF);
+        assert!(matches!(value, TypedValue::Unavailable(_)));
+
+        let value = TypedValue::from_raw(DevicePropertyCode::BatteryRemain, 80);
+        assert!(!matches!(value, TypedValue::Unavailable(_)));
+    }
+
+    #[test]
+    fn test_semantic_comparison() {
+        let a = TypedValue::from_raw(DevicePropertyCode::FNumber, 280);
+        let b = TypedValue::from_raw(DevicePropertyCode::FNumber, 280);
+        let c = TypedValue::from_raw(DevicePropertyCode::FNumber, 400);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert!(matches!(a, TypedValue::Aperture(_)));
+    }
+
+    #[test]
+    fn test_value_kinds() {
+        let aperture = TypedValue::from_raw(DevicePropertyCode::FNumber, 280);
+        assert!(aperture.is_numeric());
+        assert!(!aperture.is_enum());
+        assert_eq!(aperture.as_integer(), None);
+
+        let focus = TypedValue::from_raw(DevicePropertyCode::FocusMode, 2);
+        assert!(focus.is_enum());
+
+        let text = TypedValue::String("FX3".to_string());
+        assert!(!text.is_numeric() && !text.is_enum());
+        assert_eq!(text.to_string(), "FX3");
+
+        assert_eq!(
+            TypedValue::Integer(Integer::from_raw(42).unwrap()).as_integer(),
+            Some(42)
+        );
+    }
+
+    #[test]
+    fn test_from_property_string() {
+        let prop = DeviceProperty {
+            code: DevicePropertyCode::FNumber.as_raw(),
+            data_type: DataType::String,
+            enable_flag: crate::property::EnableFlag::ReadOnly,
+            current_value: 0,
+            current_string: Some("hello".to_string()),
+            constraint: crate::property::ValueConstraint::None,
+            values: Vec::new(),
+        };
+        assert_eq!(
+            TypedValue::from_property(&prop),
+            TypedValue::String("hello".to_string())
+        );
+    }
+
     #[test]
     fn test_round_trip() {
         let original = 280u64;
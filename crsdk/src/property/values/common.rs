@@ -28,6 +28,8 @@ pub enum PropertyValueType {
     ColorTemperature,
     /// Movie recording quality/bitrate
     MovieQuality,
+    /// Movie recording resolution
+    MovieResolution,
 
     // Enum value types
     /// Exposure program mode (P, A, S, M, etc.)
@@ -40,6 +42,8 @@ pub enum PropertyValueType {
     FocusArea,
     /// Subject recognition for AF
     SubjectRecognitionAF,
+    /// AI subject recognition target
+    SubjectTarget,
     /// AF priority setting
     PrioritySetInAF,
     /// Focus tracking status
@@ -58,8 +62,12 @@ pub enum PropertyValueType {
     WhiteBalanceSwitch,
     /// Drive mode (single, continuous, bracket, timer)
     DriveMode,
+    /// Continuous-shooting spot boost status
+    SpotBoostStatus,
     /// Interval recording shutter type
     IntervalRecShutterType,
+    /// Interval recording AE tracking sensitivity
+    IntervalRecAETrackingSensitivity,
     /// Flash mode
     FlashMode,
     /// File type (slot assignment)
@@ -70,6 +78,8 @@ pub enum PropertyValueType {
     AspectRatio,
     /// Image size
     ImageSize,
+    /// Live view image quality
+    LiveViewQuality,
     /// Movie file format (XAVC, etc.)
     MovieFileFormat,
     /// Movie shooting mode (Off, Cine EI, Custom, etc.)
@@ -184,6 +194,8 @@ pub enum PropertyValueType {
     APSC_S35,
     /// Shutter mode status
     ShutterModeStatus,
+    /// Program shift status
+    ProgramShiftStatus,
     /// Shutter mode
     ShutterMode,
     /// Exposure control type
@@ -284,6 +296,8 @@ pub enum PropertyValueType {
     MovieRecReviewPlayingState,
     /// Playback contents gamma type
     PlaybackContentsGammaType,
+    /// Movie recording frame rate
+    RecordingFrameRate,
 
     // Picture profile control types
     /// Picture profile detail adjustment mode
@@ -318,6 +332,31 @@ pub enum PropertyValueType {
     Unknown,
 }
 
+impl PropertyValueType {
+    /// Raw value that turns a two-state property of this type on or off
+    ///
+    /// Covers the generic toggles and the Disable/Enable status types, whose
+    /// encodings differ (`Switch` is 1/2, `OnOff` is 0/1). For `AutoManual`,
+    /// on means automatic. `None` for types that aren't a plain on/off.
+    pub fn toggle_value(self, on: bool) -> Option<u64> {
+        let (off_raw, on_raw) = match self {
+            Self::OnOff => (OnOff::Off as u64, OnOff::On as u64),
+            Self::Switch => (Switch::Off as u64, Switch::On as u64),
+            Self::AutoManual => (AutoManual::Manual as u64, AutoManual::Automatic as u64),
+            Self::NearFarEnableStatus => (
+                NearFarEnableStatus::Disabled as u64,
+                NearFarEnableStatus::Enabled as u64,
+            ),
+            Self::FocusOperationWithInt16EnableStatus => (
+                FocusOperationWithInt16EnableStatus::Disable as u64,
+                FocusOperationWithInt16EnableStatus::Enable as u64,
+            ),
+            _ => return None,
+        };
+        Some(if on { on_raw } else { off_raw })
+    }
+}
+
 /// A generic integer property value.
 ///
 /// Used for properties that represent raw numeric values without special formatting.
@@ -1036,6 +1075,8 @@ pub enum PlaybackMedia {
     Slot1 = 0x01,
     /// Playback from slot 2
     Slot2 = 0x02,
+    /// Playback from slot 3 (three-slot bodies such as FX6/FX9)
+    Slot3 = 0x03,
 }
 
 impl ToCrsdk<u64> for PlaybackMedia {
@@ -1049,6 +1090,7 @@ impl FromCrsdk<u64> for PlaybackMedia {
         Ok(match raw as u8 {
             0x01 => Self::Slot1,
             0x02 => Self::Slot2,
+            0x03 => Self::Slot3,
             _ => return Err(Error::InvalidPropertyValue),
         })
     }
@@ -1061,6 +1103,7 @@ impl fmt::Display for PlaybackMedia {
         match self {
             Self::Slot1 => write!(f, "Slot 1"),
             Self::Slot2 => write!(f, "Slot 2"),
+            Self::Slot3 => write!(f, "Slot 3"),
         }
     }
 }
@@ -4498,6 +4541,16 @@ impl fmt::Display for FocusFrameType {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_toggle_value() {
+        assert_eq!(PropertyValueType::Switch.toggle_value(true), Some(2));
+        assert_eq!(PropertyValueType::Switch.toggle_value(false), Some(1));
+        assert_eq!(PropertyValueType::OnOff.toggle_value(true), Some(1));
+        assert_eq!(PropertyValueType::OnOff.toggle_value(false), Some(0));
+        assert_eq!(PropertyValueType::AutoManual.toggle_value(true), Some(1));
+        assert_eq!(PropertyValueType::Integer.toggle_value(true), None);
+    }
+
     #[test]
     fn test_integer_display() {
         assert_eq!(Integer(42).to_string(), "42");
@@ -1,5 +1,7 @@
 //! Drive mode value types.
 
+use std::time::Duration;
+
 use super::super::PropertyValue;
 use crate::error::{Error, Result};
 use crate::types::{FromCrsdk, ToCrsdk};
@@ -739,6 +741,25 @@ impl ToCrsdk<u64> for DriveMode {
     }
 }
 
+impl DriveMode {
+    /// Self-timer delay of this drive mode, if it is a plain self-timer mode
+    pub fn self_timer_delay(&self) -> Option<Duration> {
+        match self {
+            Self::Timer2s => Some(Duration::from_secs(2)),
+            Self::Timer5s => Some(Duration::from_secs(5)),
+            Self::Timer10s => Some(Duration::from_secs(10)),
+            _ => None,
+        }
+    }
+
+    /// Self-timer drive mode for a delay (2, 5 or 10 seconds)
+    pub fn self_timer(delay: Duration) -> Option<Self> {
+        [Self::Timer2s, Self::Timer5s, Self::Timer10s]
+            .into_iter()
+            .find(|mode| mode.self_timer_delay() == Some(delay))
+    }
+}
+
 /// Shutter type for interval recording (timelapse)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u8)]
@@ -780,6 +801,91 @@ impl std::fmt::Display for IntervalRecShutterType {
     }
 }
 
+/// How quickly exposure follows changing light during interval recording
+///
+/// Low smooths out flicker between frames; High keeps up with fast changes
+/// such as a sunset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum IntervalRecAETrackingSensitivity {
+    /// Follow brightness changes quickly
+    High = 1,
+    /// Balanced tracking
+    Mid = 2,
+    /// Follow brightness changes slowly for smoother results
+    Low = 3,
+}
+
+impl ToCrsdk<u64> for IntervalRecAETrackingSensitivity {
+    fn to_crsdk(&self) -> u64 {
+        *self as u64
+    }
+}
+
+impl FromCrsdk<u64> for IntervalRecAETrackingSensitivity {
+    fn from_crsdk(raw: u64) -> Result<Self> {
+        Ok(match raw as u8 {
+            1 => Self::High,
+            2 => Self::Mid,
+            3 => Self::Low,
+            _ => return Err(Error::InvalidPropertyValue),
+        })
+    }
+}
+
+impl PropertyValue for IntervalRecAETrackingSensitivity {}
+
+impl std::fmt::Display for IntervalRecAETrackingSensitivity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::High => write!(f, "High"),
+            Self::Mid => write!(f, "Mid"),
+            Self::Low => write!(f, "Low"),
+        }
+    }
+}
+
+/// Whether a continuous-shooting spot boost is currently active
+///
+/// While boosted the body shoots at `ContinuousShootingSpotBoostFrameSpeed`
+/// instead of the drive mode's normal rate. Values follow the SDK's
+/// `CrContinuousShootingSpotBoostStatus`: 1 not boosting, 2 boosting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum SpotBoostStatus {
+    /// Shooting at the drive mode's normal rate
+    NotBoosting = 1,
+    /// Boost is active
+    Boosting = 2,
+}
+
+impl ToCrsdk<u64> for SpotBoostStatus {
+    fn to_crsdk(&self) -> u64 {
+        *self as u64
+    }
+}
+
+impl FromCrsdk<u64> for SpotBoostStatus {
+    fn from_crsdk(raw: u64) -> Result<Self> {
+        Ok(match raw as u8 {
+            1 => Self::NotBoosting,
+            2 => Self::Boosting,
+            _ => return Err(Error::InvalidPropertyValue),
+        })
+    }
+}
+
+impl PropertyValue for SpotBoostStatus {}
+
+impl std::fmt::Display for SpotBoostStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotBoosting => write!(f, "Off"),
+            Self::Boosting => write!(f, "Boosting"),
+        }
+    }
+}
+
 impl std::fmt::Display for DriveMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
@@ -940,3 +1046,36 @@ impl std::fmt::Display for DriveMode {
         write!(f, "{}", s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_timer_round_trip() {
+        for mode in [DriveMode::Timer2s, DriveMode::Timer5s, DriveMode::Timer10s] {
+            let delay = mode.self_timer_delay().unwrap();
+            assert_eq!(DriveMode::self_timer(delay), Some(mode));
+        }
+        assert_eq!(DriveMode::Single.self_timer_delay(), None);
+        assert_eq!(DriveMode::self_timer(Duration::from_secs(3)), None);
+    }
+
+    #[test]
+    fn test_spot_boost_status() {
+        assert_eq!(
+            SpotBoostStatus::from_raw(1),
+            Some(SpotBoostStatus::NotBoosting)
+        );
+        assert_eq!(
+            SpotBoostStatus::from_raw(2),
+            Some(SpotBoostStatus::Boosting)
+        );
+        assert_eq!(SpotBoostStatus::from_raw(0), None);
+        assert_eq!(SpotBoostStatus::from_raw(3), None);
+        assert_eq!(SpotBoostStatus::NotBoosting.to_raw(), 1);
+        assert_eq!(SpotBoostStatus::Boosting.to_raw(), 2);
+        assert_eq!(SpotBoostStatus::NotBoosting.to_string(), "Off");
+        assert_eq!(SpotBoostStatus::Boosting.to_string(), "Boosting");
+    }
+}
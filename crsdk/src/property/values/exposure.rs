@@ -2,6 +2,7 @@
 
 use std::fmt;
 
+use super::super::core::{DeviceProperty, ValueConstraint};
 use super::super::traits::PropertyValue;
 use crate::error::{Error, Result};
 use crate::types::{FromCrsdk, ToCrsdk};
@@ -18,6 +19,30 @@ impl Aperture {
     pub fn f_number(&self) -> f64 {
         self.0 as f64 / 100.0
     }
+
+    /// Widest and narrowest apertures an `FNumber` constraint allows
+    ///
+    /// Placeholder values the SDK reports for iris close and unknown
+    /// (0xFFFD and up) are ignored. `None` if nothing usable is left.
+    pub fn limits(constraint: &ValueConstraint) -> Option<(Aperture, Aperture)> {
+        let usable = |raw: u64| raw != 0 && raw < 0xFFFD;
+        let (min, max) = match constraint {
+            ValueConstraint::None => return None,
+            ValueConstraint::Discrete(values) => {
+                let mut values = values.iter().copied().filter(|&v| usable(v));
+                let first = values.next()?;
+                values.fold((first, first), |(lo, hi), v| (lo.min(v), hi.max(v)))
+            }
+            ValueConstraint::Range { min, max, .. } => {
+                let (min, max) = (*min as u64, (*max as u64).min(0xFFFC));
+                if !usable(min) || min > max {
+                    return None;
+                }
+                (min, max)
+            }
+        };
+        Some((Aperture(min), Aperture(max)))
+    }
 }
 
 impl ToCrsdk<u64> for Aperture {
@@ -117,43 +142,84 @@ impl fmt::Display for ShutterSpeed {
 
 /// ISO sensitivity value.
 ///
-/// The SDK uses special encoding for ISO values:
-/// - `0xFFFFFF` represents Auto ISO
-/// - Values with bit `0x10000000` set are extended/Hi ISO values,
-///   where the actual ISO is in the lower bits
+/// The SDK packs a mode into the upper byte and the ISO number into the
+/// lower 24 bits:
+/// - `0xFFFFFF` in the lower bits represents Auto ISO
+/// - Mode `0x10` marks extended/Hi ISO values
+/// - Modes `0x01`/`0x02` mark multi-frame noise reduction (normal/high)
 /// - Regular values are the ISO number directly (e.g., 100, 800, 3200)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Iso(u64);
 
 const ISO_AUTO: u64 = 0xFFFFFF;
-const ISO_EXTENDED_FLAG: u64 = 0x10000000;
+const ISO_VALUE_MASK: u64 = 0xFFFFFF;
+const ISO_MODE_SHIFT: u32 = 24;
+const ISO_MODE_MULTI_FRAME_NR: u64 = 0x01;
+const ISO_MODE_MULTI_FRAME_NR_HIGH: u64 = 0x02;
+const ISO_MODE_EXTENDED: u64 = 0x10;
+
+/// How an [`Iso`] value is encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IsoMode {
+    /// Auto ISO
+    Auto,
+    /// Regular ISO value
+    Normal,
+    /// Extended/Hi ISO value
+    Extended,
+    /// Multi-frame noise reduction
+    MultiFrameNR,
+    /// Multi-frame noise reduction (high)
+    MultiFrameNRHigh,
+}
 
 impl Iso {
+    /// Auto ISO.
+    pub const AUTO: Iso = Iso(ISO_AUTO);
+
+    /// Create a regular ISO value from a plain ISO number (e.g. 800).
+    pub fn new(iso: u32) -> Self {
+        Iso(iso as u64 & ISO_VALUE_MASK)
+    }
+
     /// Get the raw SDK value.
     pub fn raw(&self) -> u64 {
         self.0
     }
 
+    /// Get how this value is encoded.
+    ///
+    /// Auto takes precedence, so multi-frame NR with Auto ISO is `Auto`.
+    pub fn mode(&self) -> IsoMode {
+        if self.is_auto() {
+            return IsoMode::Auto;
+        }
+        match (self.0 >> ISO_MODE_SHIFT) & 0xFF {
+            ISO_MODE_EXTENDED => IsoMode::Extended,
+            ISO_MODE_MULTI_FRAME_NR => IsoMode::MultiFrameNR,
+            ISO_MODE_MULTI_FRAME_NR_HIGH => IsoMode::MultiFrameNRHigh,
+            _ => IsoMode::Normal,
+        }
+    }
+
     /// Check if this is Auto ISO.
     pub fn is_auto(&self) -> bool {
-        self.0 == ISO_AUTO
+        (self.0 & ISO_VALUE_MASK) == ISO_AUTO
     }
 
     /// Check if this is an extended/Hi ISO value.
     pub fn is_extended(&self) -> bool {
-        (self.0 & ISO_EXTENDED_FLAG) != 0
+        self.mode() == IsoMode::Extended
     }
 
-    /// Get the effective ISO number, stripping any flags.
+    /// Get the effective ISO number, stripping any mode bits.
     ///
     /// Returns `None` for Auto ISO.
     pub fn value(&self) -> Option<u64> {
         if self.is_auto() {
             None
-        } else if self.is_extended() {
-            Some(self.0 & !ISO_EXTENDED_FLAG)
         } else {
-            Some(self.0)
+            Some(self.0 & ISO_VALUE_MASK)
         }
     }
 }
@@ -178,12 +244,14 @@ impl PropertyValue for Iso {}
 
 impl fmt::Display for Iso {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.is_auto() {
-            write!(f, "Auto")
-        } else if let Some(iso) = self.value() {
-            write!(f, "ISO {}", iso)
-        } else {
-            write!(f, "ISO {}", self.0)
+        match self.value() {
+            None => write!(f, "Auto")?,
+            Some(iso) => write!(f, "ISO {}", iso)?,
+        }
+        match (self.0 >> ISO_MODE_SHIFT) & 0xFF {
+            ISO_MODE_MULTI_FRAME_NR => write!(f, " (MFNR)"),
+            ISO_MODE_MULTI_FRAME_NR_HIGH => write!(f, " (MFNR High)"),
+            _ => Ok(()),
         }
     }
 }
@@ -196,6 +264,16 @@ impl fmt::Display for Iso {
 pub struct ExposureComp(i64);
 
 impl ExposureComp {
+    /// Build a value from EV, snapped to the nearest 1/3 step.
+    ///
+    /// Thirds are encoded the way the camera reports them: +1/3 EV is 300
+    /// and +2/3 EV is 700, so +1.7 EV becomes 1700.
+    pub fn from_ev(ev: f64) -> Self {
+        let thirds = (ev * 3.0).round() as i64;
+        let magnitude = thirds.abs() / 3 * 1000 + [0, 300, 700][(thirds.abs() % 3) as usize];
+        ExposureComp(thirds.signum() * magnitude)
+    }
+
     /// Get the EV value as a floating point number.
     pub fn ev(&self) -> f64 {
         self.0 as f64 / 1000.0
@@ -661,6 +739,26 @@ impl ExposureProgram {
                 | Self::MovieIntervalRecAuto
         )
     }
+
+    /// Check if this is a flexible exposure mode
+    ///
+    /// Cine bodies only offer these while `ExposureCtrlType` is
+    /// `FlexibleExposure`, and only the P/A/S/M modes while it is `Pasm`.
+    pub fn is_flexible_exposure(&self) -> bool {
+        matches!(
+            self,
+            Self::MovieF | Self::MovieSQMotionF | Self::MovieFMode | Self::MovieIntervalRecF
+        )
+    }
+
+    /// The exposure control type this mode belongs to
+    pub fn exposure_ctrl_type(&self) -> ExposureCtrlType {
+        if self.is_flexible_exposure() {
+            ExposureCtrlType::FlexibleExposure
+        } else {
+            ExposureCtrlType::Pasm
+        }
+    }
 }
 
 /// Metering mode settings
@@ -795,6 +893,43 @@ impl fmt::Display for ShutterModeStatus {
     }
 }
 
+/// Whether program shift is moving P mode off its default exposure pair
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum ProgramShiftStatus {
+    /// The camera's own aperture/shutter pair is in use
+    Off = 1,
+    /// A shifted pair is in use
+    On = 2,
+}
+
+impl ToCrsdk<u64> for ProgramShiftStatus {
+    fn to_crsdk(&self) -> u64 {
+        *self as u64
+    }
+}
+
+impl FromCrsdk<u64> for ProgramShiftStatus {
+    fn from_crsdk(raw: u64) -> Result<Self> {
+        Ok(match raw as u8 {
+            1 => Self::Off,
+            2 => Self::On,
+            _ => return Err(Error::InvalidPropertyValue),
+        })
+    }
+}
+
+impl PropertyValue for ProgramShiftStatus {}
+
+impl fmt::Display for ProgramShiftStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Off => write!(f, "Off"),
+            Self::On => write!(f, "Shifted"),
+        }
+    }
+}
+
 /// Shutter mode selection (Speed vs Angle)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u8)]
@@ -906,10 +1041,109 @@ impl fmt::Display for GainUnitSetting {
     }
 }
 
+/// Sensor gain in the unit the camera is set to display
+///
+/// Cinema bodies show gain either as ISO or in dB depending on
+/// `GainUnitSetting`. The two are separate properties: `IsoSensitivity`
+/// holds the ISO and `GaindBValue` the dB gain as a signed 16-bit value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gain {
+    /// Gain as ISO sensitivity
+    Iso(Iso),
+    /// Gain in decibels
+    Db(i16),
+}
+
+impl Gain {
+    /// Pick the gain for `unit` from the raw `IsoSensitivity` and `GaindBValue`
+    ///
+    /// Returns `None` if the value for the selected unit isn't available.
+    pub fn from_raw_parts(
+        unit: GainUnitSetting,
+        iso: Option<u64>,
+        db: Option<u64>,
+    ) -> Option<Self> {
+        match unit {
+            GainUnitSetting::ISO => Iso::from_raw(iso?).map(Gain::Iso),
+            GainUnitSetting::DB => Some(Gain::Db(db? as u16 as i16)),
+        }
+    }
+
+    /// Read the gain from a property snapshot
+    ///
+    /// Bodies that don't report `GainUnitSetting` are read as ISO.
+    pub fn from_properties(props: &[DeviceProperty]) -> Option<Self> {
+        use crsdk_sys::DevicePropertyCode as C;
+        let value = |code: C| {
+            props
+                .iter()
+                .find(|p| p.code == code.as_raw())
+                .map(|p| p.current_value)
+        };
+        let unit = value(C::GainUnitSetting)
+            .and_then(GainUnitSetting::from_raw)
+            .unwrap_or(GainUnitSetting::ISO);
+        Self::from_raw_parts(unit, value(C::IsoSensitivity), value(C::GaindBValue))
+    }
+
+    /// Raw value of the property holding this gain (`IsoSensitivity` or
+    /// `GaindBValue`)
+    pub fn to_raw(&self) -> u64 {
+        match self {
+            Self::Iso(iso) => iso.to_raw(),
+            Self::Db(db) => *db as u16 as u64,
+        }
+    }
+}
+
+impl fmt::Display for Gain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Iso(iso) => write!(f, "{}", iso),
+            Self::Db(db) => write!(f, "{} dB", db),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_aperture_limits() {
+        let discrete = ValueConstraint::Discrete(vec![560, 400, 800, 2200, 0xFFFE]);
+        let (wide, narrow) = Aperture::limits(&discrete).unwrap();
+        assert_eq!(wide.f_number(), 4.0);
+        assert_eq!(narrow.f_number(), 22.0);
+
+        let range = ValueConstraint::Range {
+            min: 280,
+            max: 1600,
+            step: 10,
+        };
+        let (wide, narrow) = Aperture::limits(&range).unwrap();
+        assert_eq!((wide.to_crsdk(), narrow.to_crsdk()), (280, 1600));
+
+        assert_eq!(Aperture::limits(&ValueConstraint::None), None);
+        assert_eq!(
+            Aperture::limits(&ValueConstraint::Discrete(vec![0xFFFF])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_exposure_program_ctrl_type() {
+        assert_eq!(
+            ExposureProgram::MovieFMode.exposure_ctrl_type(),
+            ExposureCtrlType::FlexibleExposure
+        );
+        assert!(ExposureProgram::MovieSQMotionF.is_flexible_exposure());
+        assert_eq!(
+            ExposureProgram::MovieM.exposure_ctrl_type(),
+            ExposureCtrlType::Pasm
+        );
+    }
+
     #[test]
     fn test_aperture_display() {
         assert_eq!(Aperture(140).to_string(), "f/1.4");
@@ -1022,6 +1256,34 @@ mod tests {
         assert_eq!(iso.to_string(), "ISO 800");
     }
 
+    #[test]
+    fn test_iso_mode() {
+        assert_eq!(Iso::AUTO.mode(), IsoMode::Auto);
+        assert_eq!(Iso(800).mode(), IsoMode::Normal);
+        assert_eq!(Iso(0x10064000).mode(), IsoMode::Extended);
+
+        // Multi-frame NR at ISO 3200 (0xC80)
+        let mfnr = Iso(0x01000C80);
+        assert_eq!(mfnr.mode(), IsoMode::MultiFrameNR);
+        assert_eq!(mfnr.value(), Some(3200));
+        assert_eq!(mfnr.to_string(), "ISO 3200 (MFNR)");
+        assert_eq!(Iso(0x02000C80).mode(), IsoMode::MultiFrameNRHigh);
+
+        // Auto ISO with multi-frame NR is still auto, not a huge number
+        let mfnr_auto = Iso(0x01FFFFFF);
+        assert_eq!(mfnr_auto.mode(), IsoMode::Auto);
+        assert_eq!(mfnr_auto.value(), None);
+        assert_eq!(mfnr_auto.to_string(), "Auto (MFNR)");
+    }
+
+    #[test]
+    fn test_iso_new() {
+        let iso = Iso::new(800);
+        assert_eq!(iso.raw(), 800);
+        assert_eq!(iso.mode(), IsoMode::Normal);
+        assert_eq!(iso.value(), Some(800));
+    }
+
     #[test]
     fn test_iso_from_raw() {
         assert!(Iso::from_raw(0).is_none());
@@ -1046,6 +1308,18 @@ mod tests {
         assert_eq!(ec.ev(), -3.7);
     }
 
+    #[test]
+    fn test_exposure_comp_from_ev() {
+        assert_eq!(ExposureComp::from_ev(0.0).raw_value(), 0);
+        assert_eq!(ExposureComp::from_ev(0.33).raw_value(), 300);
+        assert_eq!(ExposureComp::from_ev(2.0 / 3.0).raw_value(), 700);
+        assert_eq!(ExposureComp::from_ev(1.7).raw_value(), 1700);
+        assert_eq!(ExposureComp::from_ev(-1.3).raw_value(), -1300);
+        assert_eq!(ExposureComp::from_ev(-3.0).raw_value(), -3000);
+        // Snaps to the nearest third
+        assert_eq!(ExposureComp::from_ev(0.9).raw_value(), 1000);
+    }
+
     #[test]
     fn test_meter_level_display() {
         assert_eq!(MeterLevel(0).to_string(), "0");
@@ -1061,4 +1335,24 @@ mod tests {
         assert_eq!(ml.value(), -8000);
         assert_eq!(ml.to_string(), "-8000");
     }
+
+    #[test]
+    fn test_gain_follows_unit() {
+        let iso = Some(800);
+        let db = Some(0xFFFD);
+        assert_eq!(
+            Gain::from_raw_parts(GainUnitSetting::ISO, iso, db).map(|g| g.to_string()),
+            Some("ISO 800".to_string())
+        );
+        assert_eq!(
+            Gain::from_raw_parts(GainUnitSetting::DB, iso, db).map(|g| g.to_string()),
+            Some("-3 dB".to_string())
+        );
+        assert_eq!(
+            Gain::from_raw_parts(GainUnitSetting::DB, iso, Some(6)),
+            Some(Gain::Db(6))
+        );
+        assert_eq!(Gain::from_raw_parts(GainUnitSetting::DB, iso, None), None);
+        assert_eq!(Gain::Db(-3).to_raw(), 0xFFFD);
+    }
 }
@@ -244,6 +244,66 @@ impl fmt::Display for SubjectRecognitionAF {
     }
 }
 
+/// Subject type targeted by AI subject recognition
+///
+/// Values follow the SDK's `CrRecognitionTarget` ordering. Which targets a
+/// body offers varies by model and firmware.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum SubjectTarget {
+    /// People (face/eye/body)
+    Human = 0x01,
+    /// Animals and birds
+    AnimalBird = 0x02,
+    /// Animals only
+    Animal = 0x03,
+    /// Birds only
+    Bird = 0x04,
+    /// Insects
+    Insect = 0x05,
+    /// Cars and trains
+    CarTrain = 0x06,
+    /// Airplanes
+    Plane = 0x07,
+}
+
+impl ToCrsdk<u64> for SubjectTarget {
+    fn to_crsdk(&self) -> u64 {
+        *self as u64
+    }
+}
+
+impl FromCrsdk<u64> for SubjectTarget {
+    fn from_crsdk(raw: u64) -> Result<Self> {
+        Ok(match raw as u8 {
+            0x01 => Self::Human,
+            0x02 => Self::AnimalBird,
+            0x03 => Self::Animal,
+            0x04 => Self::Bird,
+            0x05 => Self::Insect,
+            0x06 => Self::CarTrain,
+            0x07 => Self::Plane,
+            _ => return Err(Error::InvalidPropertyValue),
+        })
+    }
+}
+
+impl PropertyValue for SubjectTarget {}
+
+impl fmt::Display for SubjectTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Human => write!(f, "Human"),
+            Self::AnimalBird => write!(f, "Animal/Bird"),
+            Self::Animal => write!(f, "Animal"),
+            Self::Bird => write!(f, "Bird"),
+            Self::Insect => write!(f, "Insect"),
+            Self::CarTrain => write!(f, "Car/Train"),
+            Self::Plane => write!(f, "Airplane"),
+        }
+    }
+}
+
 /// AF/Release priority settings
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u8)]
@@ -707,5 +767,8 @@ mod tests {
     fn test_subject_recognition_display() {
         assert_eq!(SubjectRecognitionAF::Off.to_string(), "Off");
         assert_eq!(SubjectRecognitionAF::PriorityAF.to_string(), "Priority AF");
+        assert_eq!(SubjectTarget::AnimalBird.to_string(), "Animal/Bird");
+        assert_eq!(SubjectTarget::from_raw(0x04), Some(SubjectTarget::Bird));
+        assert_eq!(SubjectTarget::from_raw(0x00), None);
     }
 }
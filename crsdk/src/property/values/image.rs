@@ -202,6 +202,46 @@ impl fmt::Display for ImageSize {
     }
 }
 
+/// Live view image quality
+///
+/// Higher quality delivers larger live view JPEGs at the cost of bandwidth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum LiveViewQuality {
+    /// Low quality (smaller frames)
+    Low = 0,
+    /// High quality (larger frames)
+    High = 1,
+}
+
+impl ToCrsdk<u64> for LiveViewQuality {
+    fn to_crsdk(&self) -> u64 {
+        *self as u64
+    }
+}
+
+impl FromCrsdk<u64> for LiveViewQuality {
+    fn from_crsdk(raw: u64) -> Result<Self> {
+        Ok(match raw as u8 {
+            0 => Self::Low,
+            1 => Self::High,
+            _ => return Err(Error::InvalidPropertyValue),
+        })
+    }
+}
+
+impl PropertyValue for LiveViewQuality {}
+
+impl fmt::Display for LiveViewQuality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Low => "Low",
+            Self::High => "High",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -230,4 +270,13 @@ mod tests {
         assert_eq!(ImageSize::Large.to_string(), "L");
         assert_eq!(ImageSize::Small.to_string(), "S");
     }
+
+    #[test]
+    fn test_live_view_quality_roundtrip() {
+        assert_eq!(LiveViewQuality::from_raw(0), Some(LiveViewQuality::Low));
+        assert_eq!(LiveViewQuality::from_raw(1), Some(LiveViewQuality::High));
+        assert_eq!(LiveViewQuality::from_raw(2), None);
+        assert_eq!(LiveViewQuality::High.to_raw(), 1);
+        assert_eq!(LiveViewQuality::High.to_string(), "High");
+    }
 }
@@ -50,24 +50,28 @@ pub use common::{
     VideoStreamCodec, WindNoiseReduction, ZoomDrivingStatus, ZoomOperation, ZoomTypeStatus,
     APSC_S35,
 };
-pub use drive::{DriveMode, IntervalRecShutterType};
+pub use drive::{
+    DriveMode, IntervalRecAETrackingSensitivity, IntervalRecShutterType, SpotBoostStatus,
+};
 pub use exposure::{
-    Aperture, ExposureComp, ExposureCtrlType, ExposureProgram, GainUnitSetting, Iso, MeterLevel,
-    MeteringMode, ShutterAngle, ShutterMode, ShutterModeStatus, ShutterSpeed,
+    Aperture, ExposureComp, ExposureCtrlType, ExposureProgram, Gain, GainUnitSetting, Iso, IsoMode,
+    MeterLevel, MeteringMode, ProgramShiftStatus, ShutterAngle, ShutterMode, ShutterModeStatus,
+    ShutterSpeed,
 };
 pub use flash::FlashMode;
 pub use focus::{
     FocusArea, FocusBracketOrder, FocusBracketShootingStatus, FocusDrivingStatus, FocusFrameState,
     FocusIndicator, FocusMode, FocusTouchSpotStatus, FocusTrackingStatus, PrioritySetInAF,
-    PushAutoFocus, SubjectRecognitionAF, TrackingFrameType,
+    PushAutoFocus, SubjectRecognitionAF, SubjectTarget, TrackingFrameType,
 };
-pub use image::{AspectRatio, FileType, ImageQuality, ImageSize};
+pub use image::{AspectRatio, FileType, ImageQuality, ImageSize, LiveViewQuality};
 pub use media::{LiveViewStatus, MediaSlotRecordingType, MediaSlotWritingState, SlotStatus};
 pub use movie::{
-    MovieFileFormat, MoviePlayingState, MovieQuality, MovieRecReviewPlayingState,
+    MovieFileFormat, MoviePlayingState, MovieQuality, MovieRecReviewPlayingState, MovieResolution,
     MovieShootingMode, MovieShootingModeColorGamut, MovieShootingModeTargetDisplay,
     PlaybackContentsGammaType, RecorderSaveDestination, RecorderStatus, RecordingFolderFormat,
-    RecordingState, TimeCodeFormat, TimeCodeMake, TimeCodeRun, VideoRecordingFormatQuality,
+    RecordingFrameRate, RecordingState, TimeCodeFormat, TimeCodeMake, TimeCodeRun,
+    VideoRecordingFormatQuality,
 };
 pub use power::{BatteryLevel, CameraPowerStatus};
 pub use white_balance::{ColorTemperature, PrioritySetInAWB, WhiteBalance, WhiteBalanceSwitch};
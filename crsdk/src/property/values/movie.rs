@@ -809,6 +809,190 @@ impl fmt::Display for PlaybackContentsGammaType {
     }
 }
 
+/// Movie recording resolution (`MovieRecordingResolutionForMain`/`ForProxy`).
+///
+/// Codes 0x01-0x05 as the Camera Remote SDK API reference lists them for
+/// `MovieRecordingResolutionForMain`; any other code fails to decode with
+/// `Error::InvalidPropertyValue`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MovieResolution(u64);
+
+impl MovieResolution {
+    /// 3840x2160 (UHD 4K)
+    pub const UHD: Self = Self(0x01);
+    /// 1920x1080 (Full HD)
+    pub const HD: Self = Self(0x02);
+    /// 1280x720
+    pub const HD_720: Self = Self(0x03);
+    /// 4096x2160 (DCI 4K)
+    pub const DCI_4K: Self = Self(0x04);
+    /// 7680x4320 (8K)
+    pub const UHD_8K: Self = Self(0x05);
+
+    /// Get the raw resolution code.
+    pub fn code(&self) -> u64 {
+        self.0
+    }
+
+    /// Width and height in pixels
+    pub fn dimensions(&self) -> (u32, u32) {
+        match *self {
+            Self::UHD => (3840, 2160),
+            Self::HD => (1920, 1080),
+            Self::HD_720 => (1280, 720),
+            Self::DCI_4K => (4096, 2160),
+            _ => (7680, 4320),
+        }
+    }
+
+    /// Short broadcast-style name, e.g. "UHD" or "HD"
+    pub fn short_name(&self) -> &'static str {
+        match *self {
+            Self::UHD => "UHD",
+            Self::HD => "HD",
+            Self::HD_720 => "720",
+            Self::DCI_4K => "DCI 4K",
+            _ => "8K",
+        }
+    }
+}
+
+impl ToCrsdk<u64> for MovieResolution {
+    fn to_crsdk(&self) -> u64 {
+        self.0
+    }
+}
+
+impl FromCrsdk<u64> for MovieResolution {
+    fn from_crsdk(raw: u64) -> Result<Self> {
+        match raw {
+            0x01..=0x05 => Ok(MovieResolution(raw)),
+            _ => Err(Error::InvalidPropertyValue),
+        }
+    }
+}
+
+impl PropertyValue for MovieResolution {}
+
+impl fmt::Display for MovieResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (w, h) = self.dimensions();
+        write!(f, "{}x{}", w, h)
+    }
+}
+
+/// Movie recording frame rate.
+///
+/// Used for both normal and S&Q (slow & quick) recording frame rates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum RecordingFrameRate {
+    /// 120p
+    Fps120 = 0x01,
+    /// 100p
+    Fps100 = 0x02,
+    /// 60p
+    Fps60 = 0x03,
+    /// 50p
+    Fps50 = 0x04,
+    /// 30p
+    Fps30 = 0x05,
+    /// 25p
+    Fps25 = 0x06,
+    /// 24p
+    Fps24 = 0x07,
+    /// 23.98p
+    Fps23_98 = 0x08,
+    /// 29.97p
+    Fps29_97 = 0x09,
+    /// 59.94p
+    Fps59_94 = 0x0A,
+    /// 119.88p
+    Fps119_88 = 0x0B,
+    /// 240p
+    Fps240 = 0x0C,
+    /// 200p
+    Fps200 = 0x0D,
+    /// 239.76p
+    Fps239_76 = 0x0E,
+}
+
+impl RecordingFrameRate {
+    /// All known frame rates
+    pub const ALL: &'static [RecordingFrameRate] = &[
+        Self::Fps120,
+        Self::Fps100,
+        Self::Fps60,
+        Self::Fps50,
+        Self::Fps30,
+        Self::Fps25,
+        Self::Fps24,
+        Self::Fps23_98,
+        Self::Fps29_97,
+        Self::Fps59_94,
+        Self::Fps119_88,
+        Self::Fps240,
+        Self::Fps200,
+        Self::Fps239_76,
+    ];
+
+    /// Exact frame rate in frames per second
+    pub fn fps(self) -> f64 {
+        match self {
+            Self::Fps120 => 120.0,
+            Self::Fps100 => 100.0,
+            Self::Fps60 => 60.0,
+            Self::Fps50 => 50.0,
+            Self::Fps30 => 30.0,
+            Self::Fps25 => 25.0,
+            Self::Fps24 => 24.0,
+            Self::Fps23_98 => 24000.0 / 1001.0,
+            Self::Fps29_97 => 30000.0 / 1001.0,
+            Self::Fps59_94 => 60000.0 / 1001.0,
+            Self::Fps119_88 => 120000.0 / 1001.0,
+            Self::Fps240 => 240.0,
+            Self::Fps200 => 200.0,
+            Self::Fps239_76 => 240000.0 / 1001.0,
+        }
+    }
+
+    /// Nominal (rounded) frame rate, e.g. 24 for both 24p and 23.98p
+    pub fn nominal_fps(self) -> u32 {
+        self.fps().round() as u32
+    }
+}
+
+impl ToCrsdk<u64> for RecordingFrameRate {
+    fn to_crsdk(&self) -> u64 {
+        *self as u64
+    }
+}
+
+impl FromCrsdk<u64> for RecordingFrameRate {
+    fn from_crsdk(raw: u64) -> Result<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|r| *r as u64 == raw)
+            .ok_or(Error::InvalidPropertyValue)
+    }
+}
+
+impl PropertyValue for RecordingFrameRate {}
+
+impl fmt::Display for RecordingFrameRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fps23_98 => write!(f, "23.98p"),
+            Self::Fps29_97 => write!(f, "29.97p"),
+            Self::Fps59_94 => write!(f, "59.94p"),
+            Self::Fps119_88 => write!(f, "119.88p"),
+            Self::Fps239_76 => write!(f, "239.76p"),
+            other => write!(f, "{}p", other.nominal_fps()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -820,6 +1004,19 @@ mod tests {
         assert_eq!(MovieQuality(56).to_string(), "100M 8bit");
     }
 
+    #[test]
+    fn test_movie_resolution() {
+        assert_eq!(MovieResolution::UHD.dimensions(), (3840, 2160));
+        assert_eq!(MovieResolution::UHD.to_string(), "3840x2160");
+        assert_eq!(MovieResolution::HD.short_name(), "HD");
+        assert_eq!(
+            MovieResolution::from_raw(0x05).map(|r| r.dimensions()),
+            Some((7680, 4320))
+        );
+        assert_eq!(MovieResolution::from_raw(0x00), None);
+        assert_eq!(MovieResolution::from_raw(0x7F), None);
+    }
+
     #[test]
     fn test_movie_quality_unknown_fallback() {
         assert_eq!(MovieQuality(999).to_string(), "999M");
@@ -830,4 +1027,20 @@ mod tests {
         let mq = MovieQuality::from_raw(38).unwrap();
         assert_eq!(mq.to_raw(), 38);
     }
+
+    #[test]
+    fn test_recording_frame_rate_round_trip() {
+        for rate in RecordingFrameRate::ALL {
+            assert_eq!(RecordingFrameRate::from_raw(rate.to_raw()), Some(*rate));
+        }
+        assert_eq!(RecordingFrameRate::from_raw(0x00), None);
+    }
+
+    #[test]
+    fn test_recording_frame_rate_display() {
+        assert_eq!(RecordingFrameRate::Fps24.to_string(), "24p");
+        assert_eq!(RecordingFrameRate::Fps23_98.to_string(), "23.98p");
+        assert_eq!(RecordingFrameRate::Fps23_98.nominal_fps(), 24);
+        assert_eq!(RecordingFrameRate::Fps119_88.nominal_fps(), 120);
+    }
 }
@@ -0,0 +1,531 @@
+//! Recording and replay of camera sessions
+//!
+//! A session file is JSON lines, one [`Interaction`] per SDK call a
+//! `CameraDevice` made, with what the call returned. Record one against a
+//! real camera with `CameraDeviceBuilder::record_to()`, then open it with
+//! `CameraDevice::replay()` to run the same code deterministically without
+//! hardware, e.g. in CI.
+//!
+//! Property reads (including the raw debug reads), property writes,
+//! commands and camera events are captured, in the order they happened.
+//! Recording doesn't change which SDK calls a device makes. A replayed
+//! device emits each recorded event just before answering the call that
+//! followed it, so code waiting on events sees them in the recorded order;
+//! events recorded before the first call are emitted when the replay opens.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::event::CameraEvent;
+use crate::event_sender::EventSender;
+use crate::log;
+use crate::property::DeviceProperty;
+
+/// A single SDK call and its outcome, or an event the camera sent
+///
+/// SDK failures are kept as the raw SDK error code so a replay fails with
+/// exactly the error the camera produced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "call", rename_all = "snake_case")]
+pub enum Interaction {
+    /// `GetDeviceProperties`, keeping every property
+    GetProperties {
+        /// The properties read, or the SDK error code
+        result: std::result::Result<Vec<DeviceProperty>, u32>,
+    },
+    /// `GetDeviceProperties`, keeping a single property
+    GetProperty {
+        /// Raw property code asked for
+        code: u32,
+        /// The property (`None` if the camera doesn't have it), or the SDK
+        /// error code
+        result: std::result::Result<Option<DeviceProperty>, u32>,
+    },
+    /// `GetDeviceProperties` with debug info for every property
+    GetPropertiesDebug {
+        /// The properties and their debug descriptions, or the SDK error code
+        result: std::result::Result<Vec<(DeviceProperty, String)>, u32>,
+    },
+    /// `GetDeviceProperties` for a single property and its raw value buffer
+    GetPropertyDebug {
+        /// Raw property code asked for
+        code: u32,
+        /// The property and its raw bytes (`None` if the camera doesn't have
+        /// it), or the SDK error code
+        result: std::result::Result<Option<(DeviceProperty, Vec<u8>)>, u32>,
+    },
+    /// `SetDeviceProperty` with a numeric value
+    SetProperty {
+        /// Raw property code
+        code: u32,
+        /// Value written
+        value: u64,
+        /// SDK result code (0 on success)
+        result: u32,
+    },
+    /// `SendCommand`
+    SendCommand {
+        /// Raw command ID
+        command: u32,
+        /// Command parameter
+        param: u16,
+        /// SDK result code (0 on success)
+        result: u32,
+    },
+    /// An event delivered through the SDK callbacks
+    Event {
+        /// The event as the device emitted it
+        event: CameraEvent,
+    },
+}
+
+/// Read every interaction from a session file
+pub fn read_session(path: impl AsRef<Path>) -> Result<Vec<Interaction>> {
+    let file = File::open(path.as_ref())
+        .map_err(|e| Error::Other(format!("failed to open session: {}", e)))?;
+    let mut interactions = Vec::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.map_err(|e| Error::Other(format!("failed to read session: {}", e)))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let interaction = serde_json::from_str(&line).map_err(|e| {
+            Error::Other(format!(
+                "invalid session entry on line {}: {}",
+                index + 1,
+                e
+            ))
+        })?;
+        interactions.push(interaction);
+    }
+    Ok(interactions)
+}
+
+/// Appends interactions to a session file
+///
+/// Shared between the device, which records its calls, and its
+/// `EventSender`, which records events as they arrive.
+pub(crate) struct Recorder(Mutex<File>);
+
+impl Recorder {
+    /// Write one interaction as a line of the session file
+    pub(crate) fn append(&self, interaction: &Interaction) {
+        let mut line = match serde_json::to_string(interaction) {
+            Ok(line) => line,
+            Err(e) => {
+                log::warn!("Failed to encode session entry: {}", e);
+                return;
+            }
+        };
+        line.push('\n');
+        let mut file = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        if let Err(e) = file.write_all(line.as_bytes()) {
+            log::warn!("Failed to write session entry: {}", e);
+        }
+    }
+}
+
+/// Where a device's SDK calls go besides (or instead of) the SDK
+///
+/// Replayed calls take the device's `EventSender` so the events recorded
+/// ahead of them are emitted first.
+pub(crate) enum Session {
+    /// Calls reach the SDK and are appended to a file
+    Record(Arc<Recorder>),
+    /// Calls are answered from a recording, in order
+    Replay(Mutex<VecDeque<Interaction>>),
+}
+
+impl Session {
+    /// Start recording to `path`, replacing any existing file
+    pub(crate) fn record(path: &Path) -> Result<Self> {
+        let file = File::create(path)
+            .map_err(|e| Error::Other(format!("failed to create session: {}", e)))?;
+        Ok(Session::Record(Arc::new(Recorder(Mutex::new(file)))))
+    }
+
+    /// Replay the session recorded at `path`
+    pub(crate) fn replay(path: &Path) -> Result<Self> {
+        Ok(Session::Replay(Mutex::new(read_session(path)?.into())))
+    }
+
+    /// The file a recording session writes to, for sharing with the
+    /// device's `EventSender`
+    pub(crate) fn recorder(&self) -> Option<Arc<Recorder>> {
+        match self {
+            Session::Record(recorder) => Some(Arc::clone(recorder)),
+            Session::Replay(_) => None,
+        }
+    }
+
+    /// Emit the events recorded before the first call, e.g. `Connected`
+    pub(crate) fn emit_leading_events(&self, events: &EventSender) {
+        if let Session::Replay(queue) = self {
+            let mut queue = queue.lock().unwrap_or_else(|e| e.into_inner());
+            while let Some(interaction) = queue.pop_front() {
+                match interaction {
+                    Interaction::Event { event } => events.send(event),
+                    call => {
+                        queue.push_front(call);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Answer a `GetDeviceProperties` call that keeps every property
+    pub(crate) fn get_properties(
+        &self,
+        call: impl FnOnce() -> std::result::Result<Vec<DeviceProperty>, u32>,
+        events: &EventSender,
+    ) -> std::result::Result<Vec<DeviceProperty>, u32> {
+        match self {
+            Session::Record(recorder) => {
+                let result = call();
+                recorder.append(&Interaction::GetProperties {
+                    result: result.clone(),
+                });
+                result
+            }
+            Session::Replay(queue) => match next(queue, events) {
+                Some(Interaction::GetProperties { result }) => result,
+                other => diverged("get_properties", other),
+            },
+        }
+    }
+
+    /// Answer a `GetDeviceProperties` call for a single property
+    pub(crate) fn get_property(
+        &self,
+        code: u32,
+        call: impl FnOnce() -> std::result::Result<Option<DeviceProperty>, u32>,
+        events: &EventSender,
+    ) -> std::result::Result<Option<DeviceProperty>, u32> {
+        match self {
+            Session::Record(recorder) => {
+                let result = call();
+                recorder.append(&Interaction::GetProperty {
+                    code,
+                    result: result.clone(),
+                });
+                result
+            }
+            Session::Replay(queue) => match next(queue, events) {
+                Some(Interaction::GetProperty { code: c, result }) if c == code => result,
+                other => diverged(&format!("get_property(0x{:X})", code), other),
+            },
+        }
+    }
+
+    /// Answer a `GetDeviceProperties` call with debug info for every property
+    pub(crate) fn get_properties_debug(
+        &self,
+        call: impl FnOnce() -> std::result::Result<Vec<(DeviceProperty, String)>, u32>,
+        events: &EventSender,
+    ) -> std::result::Result<Vec<(DeviceProperty, String)>, u32> {
+        match self {
+            Session::Record(recorder) => {
+                let result = call();
+                recorder.append(&Interaction::GetPropertiesDebug {
+                    result: result.clone(),
+                });
+                result
+            }
+            Session::Replay(queue) => match next(queue, events) {
+                Some(Interaction::GetPropertiesDebug { result }) => result,
+                other => diverged("get_properties_debug", other),
+            },
+        }
+    }
+
+    /// Answer a `GetDeviceProperties` call for one property's raw values
+    pub(crate) fn get_property_debug(
+        &self,
+        code: u32,
+        call: impl FnOnce() -> std::result::Result<Option<(DeviceProperty, Vec<u8>)>, u32>,
+        events: &EventSender,
+    ) -> std::result::Result<Option<(DeviceProperty, Vec<u8>)>, u32> {
+        match self {
+            Session::Record(recorder) => {
+                let result = call();
+                recorder.append(&Interaction::GetPropertyDebug {
+                    code,
+                    result: result.clone(),
+                });
+                result
+            }
+            Session::Replay(queue) => match next(queue, events) {
+                Some(Interaction::GetPropertyDebug { code: c, result }) if c == code => result,
+                other => diverged(&format!("get_property_debug(0x{:X})", code), other),
+            },
+        }
+    }
+
+    /// Answer a numeric `SetDeviceProperty` call
+    pub(crate) fn set_property(
+        &self,
+        code: u32,
+        value: u64,
+        call: impl FnOnce() -> u32,
+        events: &EventSender,
+    ) -> u32 {
+        match self {
+            Session::Record(recorder) => {
+                let result = call();
+                recorder.append(&Interaction::SetProperty {
+                    code,
+                    value,
+                    result,
+                });
+                result
+            }
+            Session::Replay(queue) => match next(queue, events) {
+                Some(Interaction::SetProperty {
+                    code: c,
+                    value: v,
+                    result,
+                }) if c == code && v == value => result,
+                other => diverged(&format!("set_property(0x{:X}, {})", code, value), other),
+            },
+        }
+    }
+
+    /// Answer a `SendCommand` call
+    pub(crate) fn send_command(
+        &self,
+        command: u32,
+        param: u16,
+        call: impl FnOnce() -> u32,
+        events: &EventSender,
+    ) -> u32 {
+        match self {
+            Session::Record(recorder) => {
+                let result = call();
+                recorder.append(&Interaction::SendCommand {
+                    command,
+                    param,
+                    result,
+                });
+                result
+            }
+            Session::Replay(queue) => match next(queue, events) {
+                Some(Interaction::SendCommand {
+                    command: c,
+                    param: p,
+                    result,
+                }) if c == command && p == param => result,
+                other => diverged(&format!("send_command({}, {})", command, param), other),
+            },
+        }
+    }
+
+    /// Whether this session answers calls instead of the SDK
+    pub(crate) fn is_replay(&self) -> bool {
+        matches!(self, Session::Replay(_))
+    }
+}
+
+/// Take the next recorded call, emitting the events recorded before it
+fn next(queue: &Mutex<VecDeque<Interaction>>, events: &EventSender) -> Option<Interaction> {
+    let mut queue = queue.lock().unwrap_or_else(|e| e.into_inner());
+    loop {
+        match queue.pop_front() {
+            Some(Interaction::Event { event }) => events.send(event),
+            other => return other,
+        }
+    }
+}
+
+/// A replayed device made a call the recording doesn't have next
+///
+/// This means the code under test behaves differently from when the session
+/// was recorded, which is exactly what a replay test is meant to catch.
+fn diverged(call: &str, recorded: Option<Interaction>) -> ! {
+    match recorded {
+        Some(recorded) => panic!(
+            "replay diverged: device called {} but the session has {:?}",
+            call, recorded
+        ),
+        None => panic!(
+            "replay diverged: device called {} after the session ended",
+            call
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::ValueConstraint;
+    use crate::test_util::prop;
+    use crsdk_sys::DevicePropertyCode;
+
+    fn event_sender() -> EventSender {
+        let (tx, _) = tokio::sync::mpsc::unbounded_channel();
+        EventSender::new(tx)
+    }
+
+    #[test]
+    fn test_record_then_replay() {
+        let path = std::env::temp_dir().join(format!("crsdk-session-{}.jsonl", std::process::id()));
+
+        let iso = DevicePropertyCode::IsoSensitivity;
+        let recorded = DeviceProperty {
+            constraint: ValueConstraint::Discrete(vec![100, 200]),
+            ..prop(iso, 100)
+        };
+
+        let recorder = Session::record(&path).unwrap();
+        let events = event_sender().record_to(recorder.recorder().unwrap());
+        events.send_from_camera(CameraEvent::Connected { version: 3 });
+        let props = recorder.get_properties(|| Ok(vec![recorded.clone()]), &events);
+        assert_eq!(props.unwrap()[0].current_value, 100);
+        assert_eq!(recorder.set_property(iso.as_raw(), 200, || 0, &events), 0);
+        events.send_from_camera(CameraEvent::PropertyChanged { codes: vec![iso] });
+        let single = recorder.get_property(iso.as_raw(), || Ok(Some(recorded.clone())), &events);
+        assert!(single.unwrap().is_some());
+        let raw = recorder.get_property_debug(
+            iso.as_raw(),
+            || Ok(Some((recorded.clone(), vec![1, 2]))),
+            &events,
+        );
+        assert_eq!(raw.unwrap().unwrap().1, vec![1, 2]);
+        assert_eq!(
+            recorder
+                .get_properties(|| Err(0x8402), &events)
+                .unwrap_err(),
+            0x8402
+        );
+        assert_eq!(recorder.send_command(1, 1, || 0x8204, &events), 0x8204);
+        drop(recorder);
+
+        let replay = Session::replay(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(replay.is_replay());
+        assert!(replay.recorder().is_none());
+
+        let events = event_sender();
+        let received = events.subscribe();
+        replay.emit_leading_events(&events);
+        assert!(matches!(
+            received.try_recv(),
+            Ok(CameraEvent::Connected { version: 3 })
+        ));
+        assert!(received.try_recv().is_err());
+
+        let props = replay
+            .get_properties(|| unreachable!("replay must not call the SDK"), &events)
+            .unwrap();
+        assert_eq!(props.len(), 1);
+        assert_eq!(props[0].code, iso.as_raw());
+        assert_eq!(
+            props[0].constraint,
+            ValueConstraint::Discrete(vec![100, 200])
+        );
+        assert_eq!(
+            replay.set_property(iso.as_raw(), 200, || unreachable!(), &events),
+            0
+        );
+        assert!(received.try_recv().is_err());
+        let single = replay
+            .get_property(iso.as_raw(), || unreachable!(), &events)
+            .unwrap()
+            .unwrap();
+        assert_eq!(single.current_value, 100);
+        match received.try_recv() {
+            Ok(CameraEvent::PropertyChanged { codes }) => assert_eq!(codes, vec![iso]),
+            other => panic!("expected the recorded PropertyChanged, got {:?}", other),
+        }
+        let (_, bytes) = replay
+            .get_property_debug(iso.as_raw(), || unreachable!(), &events)
+            .unwrap()
+            .unwrap();
+        assert_eq!(bytes, vec![1, 2]);
+        assert_eq!(
+            replay
+                .get_properties(|| unreachable!(), &events)
+                .unwrap_err(),
+            0x8402
+        );
+        assert_eq!(
+            replay.send_command(1, 1, || unreachable!(), &events),
+            0x8204
+        );
+    }
+
+    #[test]
+    fn test_replayed_device_waits_ready() {
+        let path = std::env::temp_dir().join(format!("crsdk-replay-{}.jsonl", std::process::id()));
+        let mode = DevicePropertyCode::CameraOperatingMode;
+        let iso = DevicePropertyCode::IsoSensitivity;
+        let sessions = [
+            Interaction::Event {
+                event: CameraEvent::Connected { version: 3 },
+            },
+            Interaction::GetProperty {
+                code: mode.as_raw(),
+                result: Ok(None),
+            },
+            Interaction::GetProperty {
+                code: mode.as_raw(),
+                result: Ok(Some(prop(mode, 1))),
+            },
+            Interaction::GetProperty {
+                code: iso.as_raw(),
+                result: Ok(Some(prop(iso, 100))),
+            },
+        ];
+        let lines: Vec<String> = sessions
+            .iter()
+            .map(|i| serde_json::to_string(i).unwrap())
+            .collect();
+        std::fs::write(&path, lines.join("\n")).unwrap();
+
+        let device = crate::blocking::CameraDevice::replay(&path, crate::CameraModel::Fx3).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(device.is_connected());
+        device
+            .wait_ready(std::time::Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(device.get_property(iso).unwrap().current_value, 100);
+
+        device.disconnect().unwrap();
+        assert!(!device.is_connected());
+        assert!(matches!(device.get_property(iso), Err(Error::NotConnected)));
+    }
+
+    #[test]
+    fn test_replay_without_connected_event_starts_disconnected() {
+        let path =
+            std::env::temp_dir().join(format!("crsdk-replay-offline-{}.jsonl", std::process::id()));
+        std::fs::write(&path, "").unwrap();
+
+        let device = crate::blocking::CameraDevice::replay(&path, crate::CameraModel::Fx3).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(!device.is_connected());
+    }
+
+    #[test]
+    #[should_panic(expected = "replay diverged")]
+    fn test_replay_divergence_panics() {
+        let iso = DevicePropertyCode::IsoSensitivity;
+        let replay = Session::Replay(Mutex::new(
+            vec![Interaction::SendCommand {
+                command: 0,
+                param: 1,
+                result: 0,
+            }]
+            .into(),
+        ));
+        replay.set_property(iso.as_raw(), 200, || 0, &event_sender());
+    }
+}
@@ -0,0 +1,1075 @@
+//! Composite camera settings that are read and written as a unit
+
+use std::fmt;
+
+use crate::error::{Error, Result};
+use crate::property::{
+    Aperture, AudioInputCHInputSelect, CreativeLook, DeviceProperty, ImageStabilizationLevelMovie,
+    ImageStabilizationSteadyShotMovie, IntervalRecAETrackingSensitivity, IntervalRecMode,
+    IntervalRecShutterType, MonitoringOutputFormat, MovieFileFormat, MovieQuality, MovieResolution,
+    PictureProfileBlackGammaRange, PictureProfileColorMode, PictureProfileDetailAdjustMode,
+    PictureProfileGamma, PictureProfileKneeAutoSetSensitivity, PictureProfileKneeMode,
+    ProgramShiftStatus, PropertyValue, RecordingFrameRate, ShutterAngle, ShutterMode, ShutterSpeed,
+    StreamCipherType, Switch,
+};
+use crsdk_sys::DevicePropertyCode;
+
+use DevicePropertyCode as C;
+
+/// All adjustable parameters of a single picture profile
+///
+/// Read with `CameraDevice::picture_profile()` and written back with
+/// `CameraDevice::apply_picture_profile()`, which makes it easy to copy a
+/// graded look between bodies. Every field is optional because not every
+/// body exposes every parameter; `None` fields are left untouched on apply.
+///
+/// Numeric fields hold the signed value as shown in the camera menu.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PictureProfileSettings {
+    /// Gamma curve
+    pub gamma: Option<PictureProfileGamma>,
+    /// Black level
+    pub black_level: Option<i64>,
+    /// Black gamma range
+    pub black_gamma_range: Option<PictureProfileBlackGammaRange>,
+    /// Black gamma level
+    pub black_gamma_level: Option<i64>,
+    /// Knee mode
+    pub knee_mode: Option<PictureProfileKneeMode>,
+    /// Knee auto-set maximum point
+    pub knee_auto_max_point: Option<i64>,
+    /// Knee auto-set sensitivity
+    pub knee_auto_sensitivity: Option<PictureProfileKneeAutoSetSensitivity>,
+    /// Knee manual-set point
+    pub knee_manual_point: Option<i64>,
+    /// Knee manual-set slope
+    pub knee_manual_slope: Option<i64>,
+    /// Color mode
+    pub color_mode: Option<PictureProfileColorMode>,
+    /// Saturation
+    pub saturation: Option<i64>,
+    /// Color phase
+    pub color_phase: Option<i64>,
+    /// Color depth: red
+    pub color_depth_red: Option<i64>,
+    /// Color depth: green
+    pub color_depth_green: Option<i64>,
+    /// Color depth: blue
+    pub color_depth_blue: Option<i64>,
+    /// Color depth: cyan
+    pub color_depth_cyan: Option<i64>,
+    /// Color depth: magenta
+    pub color_depth_magenta: Option<i64>,
+    /// Color depth: yellow
+    pub color_depth_yellow: Option<i64>,
+    /// Detail level
+    pub detail_level: Option<i64>,
+    /// Detail adjustment mode
+    pub detail_adjust_mode: Option<PictureProfileDetailAdjustMode>,
+    /// Detail V/H balance
+    pub detail_vh_balance: Option<i64>,
+    /// Detail B/W balance
+    pub detail_bw_balance: Option<i64>,
+    /// Detail limit
+    pub detail_limit: Option<i64>,
+    /// Detail crispening
+    pub detail_crispening: Option<i64>,
+    /// Detail hi-light detail
+    pub detail_hilight: Option<i64>,
+}
+
+impl PictureProfileSettings {
+    /// Build settings from a property snapshot (e.g. `get_all_properties()`)
+    ///
+    /// The snapshot must have been taken while the profile of interest was
+    /// selected, since the camera only reports parameters for the active one.
+    pub fn from_properties(properties: &[DeviceProperty]) -> Self {
+        let find = |code: DevicePropertyCode| {
+            properties
+                .iter()
+                .find(|p| p.code == code.as_raw() && p.is_readable())
+        };
+        let int = |code| find(code).map(|p| p.data_type.to_signed(p.current_value));
+        fn typed<T: PropertyValue>(prop: Option<&DeviceProperty>) -> Option<T> {
+            prop.and_then(|p| T::from_raw(p.current_value))
+        }
+
+        Self {
+            gamma: typed(find(C::PictureProfileGamma)),
+            black_level: int(C::PictureProfileBlackLevel),
+            black_gamma_range: typed(find(C::PictureProfileBlackGammaRange)),
+            black_gamma_level: int(C::PictureProfileBlackGammaLevel),
+            knee_mode: typed(find(C::PictureProfileKneeMode)),
+            knee_auto_max_point: int(C::PictureProfileKneeAutoSetMaxPoint),
+            knee_auto_sensitivity: typed(find(C::PictureProfileKneeAutoSetSensitivity)),
+            knee_manual_point: int(C::PictureProfileKneeManualSetPoint),
+            knee_manual_slope: int(C::PictureProfileKneeManualSetSlope),
+            color_mode: typed(find(C::PictureProfileColorMode)),
+            saturation: int(C::PictureProfileSaturation),
+            color_phase: int(C::PictureProfileColorPhase),
+            color_depth_red: int(C::PictureProfileColorDepthRed),
+            color_depth_green: int(C::PictureProfileColorDepthGreen),
+            color_depth_blue: int(C::PictureProfileColorDepthBlue),
+            color_depth_cyan: int(C::PictureProfileColorDepthCyan),
+            color_depth_magenta: int(C::PictureProfileColorDepthMagenta),
+            color_depth_yellow: int(C::PictureProfileColorDepthYellow),
+            detail_level: int(C::PictureProfileDetailLevel),
+            detail_adjust_mode: typed(find(C::PictureProfileDetailAdjustMode)),
+            detail_vh_balance: int(C::PictureProfileDetailAdjustVHBalance),
+            detail_bw_balance: int(C::PictureProfileDetailAdjustBWBalance),
+            detail_limit: int(C::PictureProfileDetailAdjustLimit),
+            detail_crispening: int(C::PictureProfileDetailAdjustCrispening),
+            detail_hilight: int(C::PictureProfileDetailAdjustHiLightDetail),
+        }
+    }
+
+    /// Raw property values to write, in the order they must be applied
+    ///
+    /// Modes come before the parameters they unlock (e.g. knee mode before
+    /// the manual knee point), since the camera only accepts writes to
+    /// parameters that are active for the current mode.
+    pub fn to_raw_values(&self) -> Vec<(DevicePropertyCode, u64)> {
+        let typed = |code, v: Option<u64>| v.map(|raw| (code, raw));
+        let int = |code, v: Option<i64>| v.map(|n| (code, n as u64));
+
+        [
+            typed(C::PictureProfileGamma, self.gamma.map(|v| v.to_raw())),
+            int(C::PictureProfileBlackLevel, self.black_level),
+            typed(
+                C::PictureProfileBlackGammaRange,
+                self.black_gamma_range.map(|v| v.to_raw()),
+            ),
+            int(C::PictureProfileBlackGammaLevel, self.black_gamma_level),
+            typed(
+                C::PictureProfileKneeMode,
+                self.knee_mode.map(|v| v.to_raw()),
+            ),
+            int(
+                C::PictureProfileKneeAutoSetMaxPoint,
+                self.knee_auto_max_point,
+            ),
+            typed(
+                C::PictureProfileKneeAutoSetSensitivity,
+                self.knee_auto_sensitivity.map(|v| v.to_raw()),
+            ),
+            int(C::PictureProfileKneeManualSetPoint, self.knee_manual_point),
+            int(C::PictureProfileKneeManualSetSlope, self.knee_manual_slope),
+            typed(
+                C::PictureProfileColorMode,
+                self.color_mode.map(|v| v.to_raw()),
+            ),
+            int(C::PictureProfileSaturation, self.saturation),
+            int(C::PictureProfileColorPhase, self.color_phase),
+            int(C::PictureProfileColorDepthRed, self.color_depth_red),
+            int(C::PictureProfileColorDepthGreen, self.color_depth_green),
+            int(C::PictureProfileColorDepthBlue, self.color_depth_blue),
+            int(C::PictureProfileColorDepthCyan, self.color_depth_cyan),
+            int(C::PictureProfileColorDepthMagenta, self.color_depth_magenta),
+            int(C::PictureProfileColorDepthYellow, self.color_depth_yellow),
+            typed(
+                C::PictureProfileDetailAdjustMode,
+                self.detail_adjust_mode.map(|v| v.to_raw()),
+            ),
+            int(C::PictureProfileDetailLevel, self.detail_level),
+            int(
+                C::PictureProfileDetailAdjustVHBalance,
+                self.detail_vh_balance,
+            ),
+            int(
+                C::PictureProfileDetailAdjustBWBalance,
+                self.detail_bw_balance,
+            ),
+            int(C::PictureProfileDetailAdjustLimit, self.detail_limit),
+            int(
+                C::PictureProfileDetailAdjustCrispening,
+                self.detail_crispening,
+            ),
+            int(
+                C::PictureProfileDetailAdjustHiLightDetail,
+                self.detail_hilight,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// The active creative look and its adjustments
+///
+/// Read with `CameraDevice::creative_look()` and written back with
+/// `CameraDevice::apply_creative_look()`. As with
+/// [`PictureProfileSettings`], `None` fields are left untouched on apply and
+/// numeric fields hold the signed value shown in the camera menu.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CreativeLookSettings {
+    /// Look preset the adjustments apply to
+    pub look: Option<CreativeLook>,
+    /// Contrast
+    pub contrast: Option<i64>,
+    /// Highlights
+    pub highlights: Option<i64>,
+    /// Shadows
+    pub shadows: Option<i64>,
+    /// Fade
+    pub fade: Option<i64>,
+    /// Saturation
+    pub saturation: Option<i64>,
+    /// Sharpness
+    pub sharpness: Option<i64>,
+    /// Sharpness range
+    pub sharpness_range: Option<i64>,
+    /// Clarity
+    pub clarity: Option<i64>,
+}
+
+impl CreativeLookSettings {
+    /// Build from a property snapshot
+    ///
+    /// The adjustments reported are those of the currently selected look.
+    pub fn from_properties(properties: &[DeviceProperty]) -> Self {
+        let find = |code: DevicePropertyCode| {
+            properties
+                .iter()
+                .find(|p| p.code == code.as_raw() && p.is_readable())
+        };
+        let int = |code| find(code).map(|p| p.data_type.to_signed(p.current_value));
+
+        Self {
+            look: find(C::CreativeLook).and_then(|p| CreativeLook::from_raw(p.current_value)),
+            contrast: int(C::CreativeLookContrast),
+            highlights: int(C::CreativeLookHighlights),
+            shadows: int(C::CreativeLookShadows),
+            fade: int(C::CreativeLookFade),
+            saturation: int(C::CreativeLookSaturation),
+            sharpness: int(C::CreativeLookSharpness),
+            sharpness_range: int(C::CreativeLookSharpnessRange),
+            clarity: int(C::CreativeLookClarity),
+        }
+    }
+
+    /// Raw property values to write, in the order they must be applied
+    ///
+    /// The look is selected first, since the adjustments belong to it.
+    pub fn to_raw_values(&self) -> Vec<(DevicePropertyCode, u64)> {
+        let int = |code, v: Option<i64>| v.map(|n| (code, n as u64));
+
+        [
+            self.look.map(|v| (C::CreativeLook, v.to_raw())),
+            int(C::CreativeLookContrast, self.contrast),
+            int(C::CreativeLookHighlights, self.highlights),
+            int(C::CreativeLookShadows, self.shadows),
+            int(C::CreativeLookFade, self.fade),
+            int(C::CreativeLookSaturation, self.saturation),
+            int(C::CreativeLookSharpness, self.sharpness),
+            int(C::CreativeLookSharpnessRange, self.sharpness_range),
+            int(C::CreativeLookClarity, self.clarity),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// Network streaming settings
+///
+/// Applied with `CameraDevice::configure_stream()`. Stream destinations
+/// (RTMP/SRT URLs and keys) are registered on the camera itself; these are
+/// the transport options the SDK lets a remote host change. `None` fields
+/// are left untouched.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StreamConfig {
+    /// Streaming mode on/off (`StreamModeSetting`)
+    pub mode: Option<Switch>,
+    /// Encryption (`StreamCipherType`)
+    pub cipher: Option<StreamCipherType>,
+    /// Raw latency setting (`StreamLatency`), body-specific
+    pub latency: Option<u64>,
+    /// Packet time-to-live (`StreamTTL`)
+    pub ttl: Option<u64>,
+}
+
+impl StreamConfig {
+    /// Raw property values to write, in the order they must be applied
+    ///
+    /// The mode comes first since the transport options are only writable
+    /// once streaming mode is on.
+    pub fn to_raw_values(&self) -> Vec<(DevicePropertyCode, u64)> {
+        [
+            self.mode.map(|v| (C::StreamModeSetting, v.to_raw())),
+            self.cipher.map(|v| (C::StreamCipherType, v.to_raw())),
+            self.latency.map(|v| (C::StreamLatency, v)),
+            self.ttl.map(|v| (C::StreamTTL, v)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// External monitor (HDMI) output settings
+///
+/// Read with `CameraDevice::hdmi_output()`. Each field is `None` when the
+/// body doesn't report the underlying property.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HdmiOutputConfig {
+    /// Output resolution and scan (`MonitoringOutputFormat`)
+    pub format: Option<MonitoringOutputFormat>,
+    /// RAW video output (`MovieHDMIOutputRAW`)
+    pub raw_output: Option<Switch>,
+    /// Timecode embedded in the output (`MovieHDMIOutputTimeCode`)
+    pub timecode: Option<Switch>,
+    /// Record trigger sent to an external recorder (`MovieHDMIOutputRecControl`)
+    pub rec_control: Option<Switch>,
+    /// Shooting info overlaid on the output (`MonitoringOutputDisplayHDMI`)
+    pub info_display: Option<Switch>,
+}
+
+impl HdmiOutputConfig {
+    /// Read the HDMI output settings from a property snapshot
+    pub fn from_properties(props: &[DeviceProperty]) -> Self {
+        let value = |code: DevicePropertyCode| {
+            props
+                .iter()
+                .find(|p| p.code == code.as_raw())
+                .map(|p| p.current_value)
+        };
+        let switch = |code| value(code).and_then(Switch::from_raw);
+        Self {
+            format: value(C::MonitoringOutputFormat).and_then(MonitoringOutputFormat::from_raw),
+            raw_output: switch(C::MovieHDMIOutputRAW),
+            timecode: switch(C::MovieHDMIOutputTimeCode),
+            rec_control: switch(C::MovieHDMIOutputRecControl),
+            info_display: switch(C::MonitoringOutputDisplayHDMI),
+        }
+    }
+
+    /// Whether the output is 4K (UHD or DCI)
+    pub fn is_4k(&self) -> bool {
+        matches!(
+            self.format,
+            Some(MonitoringOutputFormat::P3840x2160 | MonitoringOutputFormat::P4096x2160)
+        )
+    }
+
+    /// Whether RAW video is being sent out
+    pub fn is_raw(&self) -> bool {
+        self.raw_output == Some(Switch::On)
+    }
+}
+
+/// Shutter setting in the unit the camera is showing it in
+///
+/// Cine bodies show shutter either as a speed (1/50) or as an angle
+/// (172.8°), switched with `CameraDevice::set_shutter_mode()`. Read with
+/// `CameraDevice::shutter()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shutter {
+    /// Exposure time (`ShutterSpeed`)
+    Speed(ShutterSpeed),
+    /// Shutter angle (`ShutterAngle`)
+    Angle(ShutterAngle),
+}
+
+impl Shutter {
+    /// Read the shutter from a property snapshot, following `ShutterMode`
+    ///
+    /// Bodies that don't report `ShutterMode` always give a speed. Returns
+    /// `None` if the value for the active mode is missing.
+    pub fn from_properties(props: &[DeviceProperty]) -> Option<Self> {
+        let value = |code: DevicePropertyCode| {
+            props
+                .iter()
+                .find(|p| p.code == code.as_raw())
+                .map(|p| p.current_value)
+        };
+        match value(C::ShutterMode).and_then(ShutterMode::from_raw) {
+            Some(ShutterMode::Angle) => value(C::ShutterAngle)
+                .and_then(ShutterAngle::from_raw)
+                .map(Shutter::Angle),
+            _ => value(C::ShutterSpeed)
+                .and_then(ShutterSpeed::from_raw)
+                .map(Shutter::Speed),
+        }
+    }
+}
+
+impl fmt::Display for Shutter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Shutter::Speed(v) => write!(f, "{}", v),
+            Shutter::Angle(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+/// Program shift state in P mode
+///
+/// Read with `CameraDevice::program_shift_status()` and changed with
+/// `CameraDevice::program_shift()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProgramShift {
+    /// The camera's own aperture/shutter pair is in use
+    NotShifted,
+    /// A shifted pair is in use
+    Shifted {
+        /// Effective aperture, if the body reports it
+        aperture: Option<Aperture>,
+        /// Effective shutter speed, if the body reports it
+        shutter_speed: Option<ShutterSpeed>,
+    },
+}
+
+impl ProgramShift {
+    /// Read the program shift state from a property snapshot
+    ///
+    /// Returns `None` if the body doesn't report `ProgramShiftStatus`.
+    pub fn from_properties(props: &[DeviceProperty]) -> Option<Self> {
+        let value = |code: DevicePropertyCode| {
+            props
+                .iter()
+                .find(|p| p.code == code.as_raw())
+                .map(|p| p.current_value)
+        };
+        match ProgramShiftStatus::from_raw(value(C::ProgramShiftStatus)?)? {
+            ProgramShiftStatus::Off => Some(ProgramShift::NotShifted),
+            ProgramShiftStatus::On => Some(ProgramShift::Shifted {
+                aperture: value(C::FNumber).and_then(Aperture::from_raw),
+                shutter_speed: value(C::ShutterSpeed).and_then(ShutterSpeed::from_raw),
+            }),
+        }
+    }
+
+    /// Whether a shift is active
+    pub fn is_shifted(&self) -> bool {
+        matches!(self, ProgramShift::Shifted { .. })
+    }
+}
+
+impl fmt::Display for ProgramShift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProgramShift::NotShifted => write!(f, "Not shifted"),
+            ProgramShift::Shifted {
+                aperture,
+                shutter_speed,
+            } => {
+                write!(f, "Shifted")?;
+                match (aperture, shutter_speed) {
+                    (Some(a), Some(s)) => write!(f, " ({} {})", a, s),
+                    (Some(a), None) => write!(f, " ({})", a),
+                    (None, Some(s)) => write!(f, " ({})", s),
+                    (None, None) => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+/// Interval recording (timelapse) settings
+///
+/// Read with `CameraDevice::interval_config()` and applied with
+/// `CameraDevice::configure_interval()`. `None` fields are left untouched
+/// on apply, and are `None` when read from a body that doesn't report them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IntervalConfig {
+    /// Interval recording on/off (`IntervalRecMode`)
+    pub mode: Option<IntervalRecMode>,
+    /// Seconds between shots (`IntervalRecShootingInterval`)
+    pub interval_secs: Option<u64>,
+    /// Number of shots to take (`IntervalRecNumberOfShots`)
+    pub shots: Option<u64>,
+    /// Shutter used for each shot (`IntervalRecShutterType`)
+    pub shutter_type: Option<IntervalRecShutterType>,
+    /// How quickly exposure follows the light, for day-to-night ramps
+    /// (`IntervalRecAETrackingSensitivity`)
+    pub ae_tracking: Option<IntervalRecAETrackingSensitivity>,
+}
+
+impl IntervalConfig {
+    /// Read the interval settings from a property snapshot
+    pub fn from_properties(props: &[DeviceProperty]) -> Self {
+        let value = |code: DevicePropertyCode| {
+            props
+                .iter()
+                .find(|p| p.code == code.as_raw())
+                .map(|p| p.current_value)
+        };
+        Self {
+            mode: value(C::IntervalRecMode).and_then(IntervalRecMode::from_raw),
+            interval_secs: value(C::IntervalRecShootingInterval),
+            shots: value(C::IntervalRecNumberOfShots),
+            shutter_type: value(C::IntervalRecShutterType)
+                .and_then(IntervalRecShutterType::from_raw),
+            ae_tracking: value(C::IntervalRecAETrackingSensitivity)
+                .and_then(IntervalRecAETrackingSensitivity::from_raw),
+        }
+    }
+
+    /// Raw property values to write, mode first
+    pub fn to_raw_values(&self) -> Vec<(DevicePropertyCode, u64)> {
+        [
+            self.mode.map(|v| (C::IntervalRecMode, v.to_raw())),
+            self.interval_secs
+                .map(|v| (C::IntervalRecShootingInterval, v)),
+            self.shots.map(|v| (C::IntervalRecNumberOfShots, v)),
+            self.shutter_type
+                .map(|v| (C::IntervalRecShutterType, v.to_raw())),
+            self.ae_tracking
+                .map(|v| (C::IntervalRecAETrackingSensitivity, v.to_raw())),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// Source selection properties for audio channels 1-4
+const AUDIO_INPUT_SELECT: [DevicePropertyCode; 4] = [
+    C::AudioInputCH1InputSelect,
+    C::AudioInputCH2InputSelect,
+    C::AudioInputCH3InputSelect,
+    C::AudioInputCH4InputSelect,
+];
+
+/// Recording level properties for audio channels 1-4
+const AUDIO_INPUT_LEVEL: [DevicePropertyCode; 4] = [
+    C::AudioInputCH1Level,
+    C::AudioInputCH2Level,
+    C::AudioInputCH3Level,
+    C::AudioInputCH4Level,
+];
+
+/// Movie audio routing: which input feeds each channel, and at what level
+///
+/// Applied with `CameraDevice::set_audio_routing()`. Index 0 is CH1; bodies
+/// with two channels ignore CH3 and CH4 if they are left `None`. `None`
+/// entries are left untouched.
+///
+/// ```
+/// use crsdk::property::AudioInputCHInputSelect;
+/// use crsdk::AudioRouting;
+///
+/// // XLR input 1 on CH1 and input 2 on CH2, both at -6 dB
+/// let routing = AudioRouting::default()
+///     .with_input(1, AudioInputCHInputSelect::INPUT1)
+///     .with_input(2, AudioInputCHInputSelect::INPUT2)
+///     .with_level_db(1, -6)
+///     .with_level_db(2, -6);
+/// assert_eq!(routing.inputs[0], Some(AudioInputCHInputSelect::INPUT1));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AudioRouting {
+    /// Source for each channel (`AudioInputCHnInputSelect`)
+    pub inputs: [Option<AudioInputCHInputSelect>; 4],
+    /// Recording level for each channel in dB (`AudioInputCHnLevel`)
+    pub levels_db: [Option<i64>; 4],
+}
+
+impl AudioRouting {
+    /// Route `input` to `channel` (1-4)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channel` is not between 1 and 4.
+    pub fn with_input(mut self, channel: usize, input: AudioInputCHInputSelect) -> Self {
+        self.inputs[audio_channel_index(channel)] = Some(input);
+        self
+    }
+
+    /// Set the recording level of `channel` (1-4) in dB
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channel` is not between 1 and 4.
+    pub fn with_level_db(mut self, channel: usize, db: i64) -> Self {
+        self.levels_db[audio_channel_index(channel)] = Some(db);
+        self
+    }
+
+    /// Input selections to write, by channel
+    pub(crate) fn input_values(&self) -> Vec<(DevicePropertyCode, AudioInputCHInputSelect)> {
+        AUDIO_INPUT_SELECT
+            .iter()
+            .zip(self.inputs)
+            .filter_map(|(&code, input)| Some((code, input?)))
+            .collect()
+    }
+
+    /// Levels to write in dB, by channel
+    pub(crate) fn level_values(&self) -> Vec<(DevicePropertyCode, i64)> {
+        AUDIO_INPUT_LEVEL
+            .iter()
+            .zip(self.levels_db)
+            .filter_map(|(&code, db)| Some((code, db?)))
+            .collect()
+    }
+}
+
+fn audio_channel_index(channel: usize) -> usize {
+    assert!(
+        (1..=4).contains(&channel),
+        "audio channel must be 1-4, got {}",
+        channel
+    );
+    channel - 1
+}
+
+/// A movie recording mode: file format, frame rate and recording setting
+///
+/// The three depend on each other: the frame rates on offer depend on the
+/// format, and the recording settings on both. List the combinations a body
+/// accepts with `CameraDevice::probe_video_modes()` and apply one with
+/// `CameraDevice::set_video_mode()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VideoMode {
+    /// File format (`MovieFileFormat`)
+    pub format: MovieFileFormat,
+    /// Recording frame rate (`MovieRecordingFrameRateSetting`)
+    pub frame_rate: RecordingFrameRate,
+    /// Recording setting (`MovieRecordingSetting`)
+    pub setting: MovieQuality,
+}
+
+impl VideoMode {
+    /// Read the current video mode from a property snapshot
+    ///
+    /// Returns `None` if any of the three properties is missing or holds a
+    /// value this crate doesn't know.
+    pub fn from_properties(props: &[DeviceProperty]) -> Option<Self> {
+        let value = |code: DevicePropertyCode| {
+            props
+                .iter()
+                .find(|p| p.code == code.as_raw())
+                .map(|p| p.current_value)
+        };
+        Some(VideoMode {
+            format: MovieFileFormat::from_raw(value(C::MovieFileFormat)?)?,
+            frame_rate: RecordingFrameRate::from_raw(value(C::MovieRecordingFrameRateSetting)?)?,
+            setting: MovieQuality::from_raw(value(C::MovieRecordingSetting)?)?,
+        })
+    }
+
+    /// Raw property values to write, in the order they must be applied
+    pub fn to_raw_values(&self) -> Vec<(DevicePropertyCode, u64)> {
+        vec![
+            (C::MovieFileFormat, self.format.to_raw()),
+            (C::MovieRecordingFrameRateSetting, self.frame_rate.to_raw()),
+            (C::MovieRecordingSetting, self.setting.to_raw()),
+        ]
+    }
+}
+
+impl fmt::Display for VideoMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} ({})", self.format, self.frame_rate, self.setting)
+    }
+}
+
+/// Main recording resolution and frame rate, e.g. for a slate overlay
+///
+/// Displays in broadcast style, e.g. "UHD 23.98p". Either half is `None`
+/// if the body doesn't report it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VideoFormat {
+    /// Main recording resolution (`MovieRecordingResolutionForMain`)
+    pub resolution: Option<MovieResolution>,
+    /// Recording frame rate (`MovieRecordingFrameRateSetting`)
+    pub frame_rate: Option<RecordingFrameRate>,
+}
+
+impl VideoFormat {
+    /// Build from a property snapshot
+    pub fn from_properties(props: &[DeviceProperty]) -> Self {
+        let value = |code: DevicePropertyCode| {
+            props
+                .iter()
+                .find(|p| p.code == code.as_raw())
+                .map(|p| p.current_value)
+        };
+        VideoFormat {
+            resolution: value(C::MovieRecordingResolutionForMain)
+                .and_then(MovieResolution::from_raw),
+            frame_rate: value(C::MovieRecordingFrameRateSetting)
+                .and_then(RecordingFrameRate::from_raw),
+        }
+    }
+}
+
+impl fmt::Display for VideoFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.resolution {
+            Some(r) => write!(f, "{}", r.short_name())?,
+            None => write!(f, "--")?,
+        }
+        match self.frame_rate {
+            Some(rate) => write!(f, " {}", rate),
+            None => write!(f, " --"),
+        }
+    }
+}
+
+/// An image stabilization setting for stills or movies
+///
+/// Stills and movies are stabilized through different properties: stills
+/// only switch SteadyShot on or off, while movies pick a SteadyShot mode or,
+/// on bodies that expose it instead, a level. Applied with
+/// `CameraDevice::set_stabilization()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stabilization {
+    /// Stills SteadyShot (`ImageStabilizationSteadyShot`)
+    Still(Switch),
+    /// Movie SteadyShot mode (`MovieImageStabilizationSteadyShot`)
+    Movie(ImageStabilizationSteadyShotMovie),
+    /// Movie stabilization level (`MovieImageStabilizationLevel`)
+    MovieLevel(ImageStabilizationLevelMovie),
+}
+
+impl Stabilization {
+    /// The property this setting is written to
+    pub fn code(&self) -> DevicePropertyCode {
+        match self {
+            Stabilization::Still(_) => C::ImageStabilizationSteadyShot,
+            Stabilization::Movie(_) => C::MovieImageStabilizationSteadyShot,
+            Stabilization::MovieLevel(_) => C::MovieImageStabilizationLevel,
+        }
+    }
+
+    /// Raw SDK value for [`code()`](Self::code)
+    pub fn to_raw(&self) -> u64 {
+        match self {
+            Stabilization::Still(v) => v.to_raw(),
+            Stabilization::Movie(v) => v.to_raw(),
+            Stabilization::MovieLevel(v) => v.to_raw(),
+        }
+    }
+}
+
+impl fmt::Display for Stabilization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stabilization::Still(v) => write!(f, "SteadyShot {}", v),
+            Stabilization::Movie(v) => write!(f, "Movie SteadyShot {}", v),
+            Stabilization::MovieLevel(v) => write!(f, "Movie SteadyShot level {}", v),
+        }
+    }
+}
+
+/// Check a clip name before it is written to `RecorderClipName`
+///
+/// Clip names end up in file names on the card, so only ASCII letters,
+/// digits, `_` and `-` are allowed. The SDK reports no length limit, so
+/// length is left to the camera.
+pub(crate) fn validate_clip_name(name: &str) -> Result<()> {
+    if name.is_empty() {
+        return Err(Error::InvalidParameter("clip name is empty".to_string()));
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && *c != '_' && *c != '-')
+    {
+        return Err(Error::InvalidParameter(format!(
+            "clip name {:?} contains {:?} (allowed: A-Z, a-z, 0-9, '_', '-')",
+            name, c
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::DataType;
+    use crate::test_util::typed_prop;
+
+    #[test]
+    fn test_picture_profile_from_properties() {
+        let props = vec![
+            typed_prop(
+                C::PictureProfileGamma,
+                DataType::UInt16,
+                PictureProfileGamma::Cine1.to_raw(),
+            ),
+            // Zero-extended -3 as reported for an Int8 property
+            typed_prop(C::PictureProfileBlackLevel, DataType::Int8, 0xFD),
+            typed_prop(
+                C::PictureProfileKneeMode,
+                DataType::UInt8,
+                PictureProfileKneeMode::Manual.to_raw(),
+            ),
+        ];
+
+        let settings = PictureProfileSettings::from_properties(&props);
+        assert_eq!(settings.gamma, Some(PictureProfileGamma::Cine1));
+        assert_eq!(settings.black_level, Some(-3));
+        assert_eq!(settings.knee_mode, Some(PictureProfileKneeMode::Manual));
+        assert_eq!(settings.saturation, None);
+    }
+
+    #[test]
+    fn test_creative_look_round_trip() {
+        let props = vec![
+            typed_prop(
+                C::CreativeLook,
+                DataType::UInt16,
+                CreativeLook::Film.to_raw(),
+            ),
+            // Zero-extended -2 as reported for an Int8 property
+            typed_prop(C::CreativeLookShadows, DataType::Int8, 0xFE),
+            typed_prop(C::CreativeLookClarity, DataType::Int8, 5),
+        ];
+
+        let settings = CreativeLookSettings::from_properties(&props);
+        assert_eq!(settings.look, Some(CreativeLook::Film));
+        assert_eq!(settings.shadows, Some(-2));
+        assert_eq!(settings.clarity, Some(5));
+        assert_eq!(settings.contrast, None);
+
+        assert_eq!(
+            settings.to_raw_values(),
+            vec![
+                (C::CreativeLook, CreativeLook::Film.to_raw()),
+                (C::CreativeLookShadows, -2i64 as u64),
+                (C::CreativeLookClarity, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_video_format_display() {
+        let props = vec![
+            typed_prop(
+                C::MovieRecordingResolutionForMain,
+                DataType::UInt8,
+                MovieResolution::UHD.to_raw(),
+            ),
+            typed_prop(
+                C::MovieRecordingFrameRateSetting,
+                DataType::UInt8,
+                RecordingFrameRate::Fps23_98.to_raw(),
+            ),
+        ];
+
+        let format = VideoFormat::from_properties(&props);
+        assert_eq!(
+            format.resolution.map(|r| r.dimensions()),
+            Some((3840, 2160))
+        );
+        assert_eq!(format.to_string(), "UHD 23.98p");
+        assert_eq!(VideoFormat::from_properties(&[]).to_string(), "-- --");
+    }
+
+    #[test]
+    fn test_picture_profile_raw_values_order() {
+        let settings = PictureProfileSettings {
+            knee_manual_point: Some(90),
+            knee_mode: Some(PictureProfileKneeMode::Manual),
+            black_level: Some(-3),
+            ..Default::default()
+        };
+
+        let values = settings.to_raw_values();
+        assert_eq!(
+            values,
+            vec![
+                (C::PictureProfileBlackLevel, -3i64 as u64),
+                (
+                    C::PictureProfileKneeMode,
+                    PictureProfileKneeMode::Manual.to_raw()
+                ),
+                (C::PictureProfileKneeManualSetPoint, 90),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stream_config_order() {
+        let config = StreamConfig {
+            mode: Some(Switch::On),
+            cipher: Some(StreamCipherType::AES128),
+            latency: None,
+            ttl: Some(64),
+        };
+        assert_eq!(
+            config.to_raw_values(),
+            vec![
+                (C::StreamModeSetting, Switch::On.to_raw()),
+                (C::StreamCipherType, StreamCipherType::AES128.to_raw()),
+                (C::StreamTTL, 64),
+            ]
+        );
+        assert!(StreamConfig::default().to_raw_values().is_empty());
+    }
+
+    #[test]
+    fn test_video_mode_from_properties() {
+        let mut props = vec![
+            typed_prop(
+                C::MovieFileFormat,
+                DataType::UInt8,
+                MovieFileFormat::XavcS4k.to_raw(),
+            ),
+            typed_prop(
+                C::MovieRecordingFrameRateSetting,
+                DataType::UInt8,
+                RecordingFrameRate::Fps24.to_raw(),
+            ),
+        ];
+        assert_eq!(VideoMode::from_properties(&props), None);
+
+        props.push(typed_prop(C::MovieRecordingSetting, DataType::UInt16, 3));
+        let mode = VideoMode::from_properties(&props).unwrap();
+        assert_eq!(mode.format, MovieFileFormat::XavcS4k);
+        assert_eq!(mode.frame_rate, RecordingFrameRate::Fps24);
+        assert_eq!(mode.setting.code(), 3);
+        assert_eq!(
+            mode.to_raw_values()
+                .iter()
+                .map(|(code, _)| *code)
+                .collect::<Vec<_>>(),
+            vec![
+                C::MovieFileFormat,
+                C::MovieRecordingFrameRateSetting,
+                C::MovieRecordingSetting,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stabilization_targets() {
+        let still = Stabilization::Still(Switch::Off);
+        assert_eq!(still.code(), C::ImageStabilizationSteadyShot);
+        assert_eq!(still.to_raw(), Switch::Off.to_raw());
+
+        let movie = Stabilization::Movie(ImageStabilizationSteadyShotMovie::Active);
+        assert_eq!(movie.code(), C::MovieImageStabilizationSteadyShot);
+        assert_eq!(movie.to_raw(), 0x03);
+        assert_eq!(movie.to_string(), "Movie SteadyShot Active");
+
+        let level = Stabilization::MovieLevel(ImageStabilizationLevelMovie::Low);
+        assert_eq!(level.code(), C::MovieImageStabilizationLevel);
+        assert_eq!(level.to_raw(), 0x02);
+    }
+
+    #[test]
+    fn test_shutter_follows_mode() {
+        let mut props = vec![
+            typed_prop(C::ShutterSpeed, DataType::UInt32, 0x0001_0032),
+            typed_prop(C::ShutterAngle, DataType::UInt32, 172_800),
+        ];
+        let shutter = Shutter::from_properties(&props).unwrap();
+        assert!(matches!(shutter, Shutter::Speed(_)));
+        assert_eq!(shutter.to_string(), "1/50");
+
+        props.push(typed_prop(
+            C::ShutterMode,
+            DataType::UInt8,
+            ShutterMode::Angle.to_raw(),
+        ));
+        let shutter = Shutter::from_properties(&props).unwrap();
+        assert!(matches!(shutter, Shutter::Angle(_)));
+        assert_eq!(shutter.to_string(), "172.8°");
+    }
+
+    #[test]
+    fn test_hdmi_output_config() {
+        let props = vec![
+            typed_prop(C::MonitoringOutputFormat, DataType::UInt8, 0x07),
+            typed_prop(C::MovieHDMIOutputRAW, DataType::UInt8, 2),
+            typed_prop(C::MovieHDMIOutputTimeCode, DataType::UInt8, 1),
+        ];
+        let config = HdmiOutputConfig::from_properties(&props);
+        assert_eq!(config.format, Some(MonitoringOutputFormat::P3840x2160));
+        assert!(config.is_4k() && config.is_raw());
+        assert_eq!(config.timecode, Some(Switch::Off));
+        assert_eq!(config.rec_control, None);
+
+        let config = HdmiOutputConfig::from_properties(&[]);
+        assert!(!config.is_4k() && !config.is_raw());
+    }
+
+    #[test]
+    fn test_program_shift() {
+        let mut props = vec![
+            typed_prop(C::FNumber, DataType::UInt16, 560),
+            typed_prop(C::ShutterSpeed, DataType::UInt32, 0x0001_00FA),
+        ];
+        assert_eq!(ProgramShift::from_properties(&props), None);
+
+        props.push(typed_prop(C::ProgramShiftStatus, DataType::UInt8, 1));
+        let shift = ProgramShift::from_properties(&props).unwrap();
+        assert_eq!(shift, ProgramShift::NotShifted);
+        assert!(!shift.is_shifted());
+
+        props.last_mut().unwrap().current_value = 2;
+        let shift = ProgramShift::from_properties(&props).unwrap();
+        assert!(shift.is_shifted());
+        assert_eq!(shift.to_string(), "Shifted (f/5.6 1/250)");
+    }
+
+    #[test]
+    fn test_interval_config() {
+        let props = vec![
+            typed_prop(C::IntervalRecMode, DataType::UInt16, 0x0002),
+            typed_prop(C::IntervalRecShootingInterval, DataType::UInt16, 5),
+            typed_prop(C::IntervalRecAETrackingSensitivity, DataType::UInt8, 3),
+        ];
+        let config = IntervalConfig::from_properties(&props);
+        assert_eq!(config.mode, Some(IntervalRecMode::On));
+        assert_eq!(config.interval_secs, Some(5));
+        assert_eq!(config.shots, None);
+        assert_eq!(
+            config.ae_tracking,
+            Some(IntervalRecAETrackingSensitivity::Low)
+        );
+
+        let config = IntervalConfig {
+            ae_tracking: Some(IntervalRecAETrackingSensitivity::Mid),
+            shutter_type: Some(IntervalRecShutterType::Electronic),
+            mode: Some(IntervalRecMode::On),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.to_raw_values(),
+            vec![
+                (C::IntervalRecMode, 0x0002),
+                (C::IntervalRecShutterType, 3),
+                (C::IntervalRecAETrackingSensitivity, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_audio_routing_values() {
+        let routing = AudioRouting::default()
+            .with_input(2, AudioInputCHInputSelect::INPUT2)
+            .with_input(1, AudioInputCHInputSelect::INPUT1)
+            .with_level_db(2, -6);
+
+        assert_eq!(
+            routing.input_values(),
+            vec![
+                (C::AudioInputCH1InputSelect, AudioInputCHInputSelect::INPUT1),
+                (C::AudioInputCH2InputSelect, AudioInputCHInputSelect::INPUT2),
+            ]
+        );
+        assert_eq!(routing.level_values(), vec![(C::AudioInputCH2Level, -6)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "audio channel must be 1-4")]
+    fn test_audio_routing_channel_out_of_range() {
+        let _ = AudioRouting::default().with_level_db(5, 0);
+    }
+
+    #[test]
+    fn test_validate_clip_name() {
+        assert!(validate_clip_name("A_0001").is_ok());
+        assert!(validate_clip_name("cam-B").is_ok());
+
+        assert!(validate_clip_name("").is_err());
+        assert!(validate_clip_name("A 001").is_err());
+        assert!(validate_clip_name("A/001").is_err());
+        assert!(validate_clip_name("Ä_001").is_err());
+    }
+}
@@ -0,0 +1,37 @@
+//! Fixtures shared by unit tests
+
+use crate::property::{DataType, DeviceProperty, EnableFlag, ValueConstraint};
+use crsdk_sys::DevicePropertyCode;
+
+/// A readable `UInt32` property holding `value`
+pub(crate) fn prop(code: DevicePropertyCode, value: u64) -> DeviceProperty {
+    DeviceProperty {
+        code: code.as_raw(),
+        data_type: DataType::UInt32,
+        enable_flag: EnableFlag::ReadOnly,
+        current_value: value,
+        current_string: None,
+        constraint: ValueConstraint::None,
+        values: Vec::new(),
+    }
+}
+
+/// Like [`prop`], with the raw value interpreted as `data_type`
+pub(crate) fn typed_prop(
+    code: DevicePropertyCode,
+    data_type: DataType,
+    value: u64,
+) -> DeviceProperty {
+    DeviceProperty {
+        data_type,
+        ..prop(code, value)
+    }
+}
+
+/// Like [`prop`], with a string value as well
+pub(crate) fn string_prop(code: DevicePropertyCode, value: u64, string: &str) -> DeviceProperty {
+    DeviceProperty {
+        current_string: Some(string.to_string()),
+        ..prop(code, value)
+    }
+}
@@ -4,6 +4,7 @@ use crate::error::{Error, Result};
 use std::fmt;
 use std::net::Ipv4Addr;
 use std::str::FromStr;
+use std::time::Duration;
 
 /// Convert a Rust type to its CRSDK representation
 pub trait ToCrsdk<T> {
@@ -35,23 +36,49 @@ impl FromStr for MacAddr {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let parts: Vec<&str> = s.split(':').collect();
+        let parts: Vec<&str> = s.trim().split(':').collect();
         if parts.len() != 6 {
-            return Err(Error::AddrParse(
-                "MAC address must have 6 octets separated by colons".to_string(),
-            ));
+            return Err(Error::AddrParse(format!(
+                "'{}': expected 6 octets separated by colons, found {}",
+                s,
+                parts.len()
+            )));
         }
 
         let mut bytes = [0u8; 6];
         for (i, part) in parts.iter().enumerate() {
-            bytes[i] = u8::from_str_radix(part, 16)
-                .map_err(|_| Error::AddrParse(format!("Invalid hex byte: {}", part)))?;
+            let valid =
+                !part.is_empty() && part.len() <= 2 && part.chars().all(|c| c.is_ascii_hexdigit());
+            if !valid {
+                return Err(Error::AddrParse(format!(
+                    "'{}': octet {} ('{}') is not a hex byte (00-FF)",
+                    s,
+                    i + 1,
+                    part
+                )));
+            }
+            bytes[i] = u8::from_str_radix(part, 16).expect("validated hex byte");
         }
 
         Ok(MacAddr(bytes))
     }
 }
 
+/// Parse a builder IP address argument, naming the input on failure
+pub(crate) fn parse_ip_address(s: &str) -> Result<Ipv4Addr> {
+    s.trim()
+        .parse()
+        .map_err(|_| Error::InvalidParameter(format!("invalid IP address '{}'", s)))
+}
+
+/// Parse a builder MAC address argument, naming the input on failure
+pub(crate) fn parse_mac_address(s: &str) -> Result<MacAddr> {
+    s.parse().map_err(|e| match e {
+        Error::AddrParse(msg) => Error::InvalidParameter(format!("invalid MAC address {}", msg)),
+        other => other,
+    })
+}
+
 impl fmt::Display for MacAddr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -115,6 +142,68 @@ impl CameraModel {
         CameraModel::Alpha9Ii,
         CameraModel::Alpha9Iii,
     ];
+
+    /// Whether the body has a full frame sensor (the FX30 is APS-C)
+    pub fn is_full_frame(self) -> bool {
+        !matches!(self, CameraModel::Fx30)
+    }
+
+    /// Circle of confusion for the whole sensor in mm, for depth of field
+    ///
+    /// The conventional 0.030mm for full frame and 0.020mm for APS-C.
+    pub fn circle_of_confusion_mm(self) -> f64 {
+        if self.is_full_frame() {
+            0.030
+        } else {
+            0.020
+        }
+    }
+
+    /// Names accepted by `from_str()`, in normalized form
+    fn aliases(self) -> &'static [&'static str] {
+        match self {
+            CameraModel::Fx3 => &["fx3", "ilmefx3"],
+            CameraModel::Fx6 => &["fx6", "ilmefx6"],
+            CameraModel::Fx30 => &["fx30", "ilmefx30"],
+            CameraModel::Alpha1 => &["a1", "ilce1"],
+            CameraModel::Alpha7Iv => &["a7iv", "a7m4", "ilce7m4"],
+            CameraModel::Alpha7Rv => &["a7rv", "a7rm5", "ilce7rm5"],
+            CameraModel::Alpha7Siii => &["a7siii", "a7sm3", "ilce7sm3"],
+            CameraModel::Alpha9Ii => &["a9ii", "a9m2", "ilce9m2"],
+            CameraModel::Alpha9Iii => &["a9iii", "a9m3", "ilce9m3"],
+        }
+    }
+}
+
+impl FromStr for CameraModel {
+    type Err = Error;
+
+    /// Parse a model name such as `FX30`, `ILME-FX3`, `a7 IV`, `α7S III` or
+    /// `ILCE-7M4`, ignoring case, spaces, dashes and a leading "Sony"
+    fn from_str(s: &str) -> Result<Self> {
+        let lower = s.trim().to_lowercase().replace('α', "a");
+        let lower = lower
+            .strip_prefix("sony")
+            .unwrap_or(&lower)
+            .replace("alpha", "a");
+        let normalized: String = lower
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect();
+
+        CameraModel::ALL
+            .iter()
+            .copied()
+            .find(|model| model.aliases().contains(&normalized.as_str()))
+            .ok_or_else(|| {
+                let valid: Vec<String> = CameraModel::ALL.iter().map(|m| m.to_string()).collect();
+                Error::InvalidParameter(format!(
+                    "unknown camera model '{}' (valid: {})",
+                    s,
+                    valid.join(", ")
+                ))
+            })
+    }
 }
 
 impl ToCrsdk<u32> for CameraModel {
@@ -143,6 +232,22 @@ pub enum ConnectionType {
     Usb,
 }
 
+impl FromStr for ConnectionType {
+    type Err = Error;
+
+    /// Parse `network` (or `ethernet`/`wifi`) and `usb`, ignoring case
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "network" | "ethernet" | "wifi" => Ok(ConnectionType::Network),
+            "usb" => Ok(ConnectionType::Usb),
+            _ => Err(Error::InvalidParameter(format!(
+                "unknown connection type '{}' (valid: network, usb)",
+                s
+            ))),
+        }
+    }
+}
+
 impl fmt::Display for ConnectionType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -152,8 +257,43 @@ impl fmt::Display for ConnectionType {
     }
 }
 
+/// Connection status reported for a discovered camera
+///
+/// Cameras accept a single remote host, so one that is already connected is
+/// claimed by someone else and connecting to it will fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionStatus {
+    /// Not connected to any host (available)
+    Disconnected,
+    /// Already connected to a host
+    Connected,
+    /// Status value not known to this crate
+    Unknown(u32),
+}
+
+impl ConnectionStatus {
+    /// Convert the raw value from `ICrCameraObjectInfo::GetConnectionStatus()`
+    pub(crate) fn from_raw(raw: u32) -> Self {
+        match raw {
+            0 => Self::Disconnected,
+            1 => Self::Connected,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl fmt::Display for ConnectionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnectionStatus::Disconnected => write!(f, "Available"),
+            ConnectionStatus::Connected => write!(f, "In use"),
+            ConnectionStatus::Unknown(raw) => write!(f, "Unknown (0x{:X})", raw),
+        }
+    }
+}
+
 /// A camera discovered through network/USB enumeration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DiscoveredCamera {
     /// Camera model name (e.g., "ILME-FX3")
     pub model: String,
@@ -169,6 +309,8 @@ pub struct DiscoveredCamera {
     pub ssh_supported: bool,
     /// USB product ID (for USB connections)
     pub usb_pid: Option<i16>,
+    /// Whether the camera is already connected to a host
+    pub connection_status: ConnectionStatus,
 }
 
 impl DiscoveredCamera {
@@ -181,6 +323,11 @@ impl DiscoveredCamera {
     pub fn is_usb(&self) -> bool {
         self.connection_type == ConnectionType::Usb
     }
+
+    /// Check if another host is already connected to this camera
+    pub fn is_in_use(&self) -> bool {
+        self.connection_status == ConnectionStatus::Connected
+    }
 }
 
 impl fmt::Display for DiscoveredCamera {
@@ -208,6 +355,45 @@ impl fmt::Display for DiscoveredCamera {
     }
 }
 
+/// What the SDK returned during a discovery scan
+///
+/// Returned alongside the cameras by `discover_cameras_debug()`, for
+/// diagnosing cameras that don't show up.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiscoveryDiagnostics {
+    /// Number of entries the SDK enumeration reported
+    pub raw_count: u32,
+    /// Indices of entries the SDK returned no info for
+    pub null_entries: Vec<u32>,
+    /// Entries whose info couldn't be parsed, as (index, reason)
+    pub parse_errors: Vec<(u32, String)>,
+}
+
+impl DiscoveryDiagnostics {
+    /// Number of raw entries that didn't become a `DiscoveredCamera`
+    pub fn filtered(&self) -> usize {
+        self.null_entries.len() + self.parse_errors.len()
+    }
+}
+
+impl fmt::Display for DiscoveryDiagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} entries enumerated, {} filtered",
+            self.raw_count,
+            self.filtered()
+        )?;
+        for index in &self.null_entries {
+            write!(f, "\n  #{}: no camera info", index)?;
+        }
+        for (index, reason) in &self.parse_errors {
+            write!(f, "\n  #{}: {}", index, reason)?;
+        }
+        Ok(())
+    }
+}
+
 /// Connection information for a camera
 #[derive(Debug, Clone, Default)]
 pub struct ConnectionInfo {
@@ -225,6 +411,13 @@ pub struct ConnectionInfo {
     pub ssh_password: Option<String>,
     /// SSH fingerprint (fetched from camera, must be confirmed by user)
     pub ssh_fingerprint: Option<String>,
+    /// How long to wait for the camera to confirm the connection
+    pub connect_timeout: Option<Duration>,
+    /// How long to wait for the camera's initial properties after connecting
+    pub ready_timeout: Option<Duration>,
+    /// File to record the session's SDK calls to
+    #[cfg(feature = "record")]
+    pub record_path: Option<std::path::PathBuf>,
 }
 
 impl ToCrsdk<u32> for Ipv4Addr {
@@ -247,6 +440,39 @@ mod tests {
         assert_eq!(zeros.0, [0, 0, 0, 0, 0, 0]);
     }
 
+    #[test]
+    fn test_mac_addr_parse_errors() {
+        let err = "aa:bb:cc:dd:ee".parse::<MacAddr>().unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("'aa:bb:cc:dd:ee'"));
+        assert!(msg.contains("found 5"));
+
+        let err = "aa:bb:cc:dd:ee:gg".parse::<MacAddr>().unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("octet 6"));
+        assert!(msg.contains("'gg'"));
+
+        assert!("aa:bb:cc:dd:ee:fff".parse::<MacAddr>().is_err());
+        assert!("aa:bb::dd:ee:ff".parse::<MacAddr>().is_err());
+        assert!("+a:bb:cc:dd:ee:ff".parse::<MacAddr>().is_err());
+    }
+
+    #[test]
+    fn test_parse_builder_addresses() {
+        assert_eq!(
+            parse_ip_address(" 192.168.1.100 ").unwrap(),
+            Ipv4Addr::new(192, 168, 1, 100)
+        );
+
+        let err = parse_ip_address("192.168.1.1000").unwrap_err();
+        assert!(matches!(err, Error::InvalidParameter(_)));
+        assert!(err.to_string().contains("'192.168.1.1000'"));
+
+        let err = parse_mac_address("00:11:22:33:44").unwrap_err();
+        assert!(matches!(err, Error::InvalidParameter(_)));
+        assert!(err.to_string().contains("'00:11:22:33:44'"));
+    }
+
     #[test]
     fn test_ipv4_to_crsdk() {
         let ip: Ipv4Addr = "192.168.1.100".parse().unwrap();
@@ -259,6 +485,45 @@ mod tests {
         assert_eq!(ConnectionType::Usb.to_string(), "USB");
     }
 
+    #[test]
+    fn test_connection_type_parse() {
+        assert_eq!(
+            "usb".parse::<ConnectionType>().unwrap(),
+            ConnectionType::Usb
+        );
+        assert_eq!(
+            " Network ".parse::<ConnectionType>().unwrap(),
+            ConnectionType::Network
+        );
+        let err = "serial".parse::<ConnectionType>().unwrap_err();
+        assert!(err.to_string().contains("valid: network, usb"));
+    }
+
+    #[test]
+    fn test_camera_model_parse() {
+        for (input, model) in [
+            ("FX30", CameraModel::Fx30),
+            ("ILME-FX3", CameraModel::Fx3),
+            ("Sony FX6", CameraModel::Fx6),
+            ("a7 IV", CameraModel::Alpha7Iv),
+            ("ILCE-7M4", CameraModel::Alpha7Iv),
+            ("Sony α7S III", CameraModel::Alpha7Siii),
+            ("alpha1", CameraModel::Alpha1),
+            ("a9m3", CameraModel::Alpha9Iii),
+        ] {
+            assert_eq!(input.parse::<CameraModel>().unwrap(), model, "{}", input);
+        }
+
+        // Every model parses back from its display name
+        for &model in CameraModel::ALL {
+            assert_eq!(model.to_string().parse::<CameraModel>().unwrap(), model);
+        }
+
+        let err = "FX9".parse::<CameraModel>().unwrap_err();
+        assert!(matches!(err, Error::InvalidParameter(_)));
+        assert!(err.to_string().contains("Sony FX30"));
+    }
+
     #[test]
     fn test_discovered_camera_is_network() {
         let camera = DiscoveredCamera {
@@ -269,6 +534,7 @@ mod tests {
             mac_address: Some("00:00:00:00:00:00".parse().unwrap()),
             ssh_supported: true,
             usb_pid: None,
+            connection_status: ConnectionStatus::Disconnected,
         };
 
         assert!(camera.is_network());
@@ -285,12 +551,38 @@ mod tests {
             mac_address: None,
             ssh_supported: false,
             usb_pid: Some(0x0c06),
+            connection_status: ConnectionStatus::Disconnected,
         };
 
         assert!(!camera.is_network());
         assert!(camera.is_usb());
     }
 
+    #[test]
+    fn test_discovery_diagnostics_display() {
+        let diagnostics = DiscoveryDiagnostics {
+            raw_count: 3,
+            null_entries: vec![0],
+            parse_errors: vec![(2, "bad connection type".to_string())],
+        };
+        assert_eq!(diagnostics.filtered(), 2);
+        assert_eq!(
+            diagnostics.to_string(),
+            "3 entries enumerated, 2 filtered\n  #0: no camera info\n  #2: bad connection type"
+        );
+    }
+
+    #[test]
+    fn test_connection_status_from_raw() {
+        assert_eq!(
+            ConnectionStatus::from_raw(0),
+            ConnectionStatus::Disconnected
+        );
+        assert_eq!(ConnectionStatus::from_raw(1), ConnectionStatus::Connected);
+        assert_eq!(ConnectionStatus::from_raw(7), ConnectionStatus::Unknown(7));
+        assert_eq!(ConnectionStatus::Connected.to_string(), "In use");
+    }
+
     #[test]
     fn test_discovered_camera_display_network() {
         let camera = DiscoveredCamera {
@@ -301,6 +593,7 @@ mod tests {
             mac_address: Some("00:00:00:00:00:00".parse().unwrap()),
             ssh_supported: true,
             usb_pid: None,
+            connection_status: ConnectionStatus::Disconnected,
         };
 
         assert_eq!(camera.to_string(), "ILME-FX3 (Network) at 192.168.1.100");
@@ -316,6 +609,7 @@ mod tests {
             mac_address: None,
             ssh_supported: false,
             usb_pid: Some(0x0c06),
+            connection_status: ConnectionStatus::Disconnected,
         };
 
         assert_eq!(camera.to_string(), "ILCE-7M4 (USB, PID: 0c06)");
@@ -22,6 +22,13 @@ pub fn run(device: &crsdk::blocking::CameraDevice) -> Result<()> {
         writable,
         readonly
     );
+    for summary in crsdk::property::summarize_categories(&properties) {
+        println!(
+            "  {}: {} ({} writable)",
+            summary.category, summary.total, summary.writable
+        );
+    }
+    println!();
 
     for code in [
         DevicePropertyCode::ExposureProgramMode,
@@ -31,6 +38,13 @@ pub fn run(device: &crsdk::blocking::CameraDevice) -> Result<()> {
         DevicePropertyCode::FocusMode,
         DevicePropertyCode::WhiteBalance,
     ] {
+        // Cinema bodies may be set to show gain in dB rather than ISO
+        if code == DevicePropertyCode::IsoSensitivity {
+            if let Ok(gain) = device.gain() {
+                println!("  Gain: {}", gain);
+                continue;
+            }
+        }
         if let Ok(prop) = device.get_property(code) {
             println!(
                 "  {}: {}",
@@ -0,0 +1,66 @@
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use clap::Args as ClapArgs;
+use crsdk::{Error, LiveViewRate, LiveViewStatus, Result};
+
+/// How often the measured frame rate is printed
+const REPORT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long without a new frame before the stream counts as frozen
+const STALL_THRESHOLD: Duration = Duration::from_secs(2);
+
+/// Delay between polls while the camera has no new frame
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(ClapArgs)]
+pub struct Args {
+    /// Write each new frame to this file (overwritten in place)
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+
+    /// Stop after this many seconds instead of running until interrupted
+    #[arg(long)]
+    pub duration: Option<u64>,
+}
+
+pub fn run(device: &crsdk::blocking::CameraDevice, args: &Args) -> Result<()> {
+    let deadline = args
+        .duration
+        .map(|secs| Instant::now() + Duration::from_secs(secs));
+    let mut rate = LiveViewRate::new();
+    let mut buf = Vec::new();
+    let mut last_report = Instant::now();
+    let mut reported_stall = false;
+
+    println!("Streaming live view...");
+    while deadline.is_none_or(|deadline| Instant::now() < deadline) {
+        match device.get_live_view_into(&mut buf) {
+            Ok(meta) => {
+                rate.record(meta.frame_number);
+                reported_stall = false;
+                if let Some(path) = &args.output {
+                    std::fs::write(path, &buf)?;
+                }
+            }
+            Err(Error::FrameNotUpdated) => std::thread::sleep(POLL_INTERVAL),
+            Err(e) => return Err(e),
+        }
+
+        if rate.is_stalled(STALL_THRESHOLD) && !reported_stall {
+            reported_stall = true;
+            match device.live_view_status() {
+                Ok(LiveViewStatus::Enabled) | Err(_) => {
+                    println!("No new frame for {}s", STALL_THRESHOLD.as_secs())
+                }
+                Ok(status) => println!("Live view stopped: {}", status),
+            }
+        }
+
+        if last_report.elapsed() >= REPORT_INTERVAL {
+            last_report = Instant::now();
+            println!("{:.1} fps", rate.fps());
+        }
+    }
+    Ok(())
+}
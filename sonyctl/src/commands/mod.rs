@@ -1,5 +1,6 @@
 pub mod capture;
 pub mod info;
+pub mod live_view;
 pub mod props;
 pub mod record;
 
@@ -28,6 +29,8 @@ pub enum Command {
     },
     /// Show camera info
     Info,
+    /// Stream live view and report the delivered frame rate
+    LiveView(live_view::Args),
 }
 
 pub async fn run(cli: &Cli) -> anyhow::Result<()> {
@@ -52,6 +55,9 @@ pub async fn run(cli: &Cli) -> anyhow::Result<()> {
                 Command::Info => {
                     info::run(&device)?;
                 }
+                Command::LiveView(args) => {
+                    live_view::run(&device, args)?;
+                }
             }
         }
     }
@@ -71,8 +77,8 @@ pub async fn connect(cli: &Cli) -> Result<crsdk::blocking::CameraDevice> {
     eprintln!("Connecting to {}...", ip);
 
     let mut builder = CameraDevice::builder()
-        .ip_address(ip.parse().expect("Invalid IP address"))
-        .mac_address(mac.parse().expect("Invalid MAC address"))
+        .try_ip_address(ip)?
+        .try_mac_address(mac)?
         .model(CameraModel::Fx3);
 
     if let (Some(user), Some(password)) = (&cli.user, &cli.password) {
@@ -101,11 +107,12 @@ pub async fn connect(cli: &Cli) -> Result<crsdk::blocking::CameraDevice> {
             .ssh_fingerprint(fingerprint);
     }
 
-    let camera = builder.connect().await?;
+    eprintln!("Connecting and loading properties...");
+    let camera = builder
+        .ready_timeout(tokio::time::Duration::from_secs(10))
+        .connect()
+        .await?;
     eprintln!("Connected to {}", camera.model().await);
-
-    eprintln!("Loading properties...");
-    tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
     eprintln!();
 
     Ok(camera.into_inner())
@@ -115,18 +122,17 @@ pub fn find_property_code(properties: &[DeviceProperty], name: &str) -> Option<D
     use crsdk::property_display_name;
 
     let name_lower = name.to_lowercase();
-
-    properties.iter().find_map(|prop| {
-        let code = DevicePropertyCode::from_raw(prop.code)?;
-        let display = property_display_name(code).to_lowercase();
-        let code_name = code.name().to_lowercase();
-
-        if display == name_lower || code_name == name_lower {
-            Some(code)
-        } else {
-            None
-        }
-    })
+    let code = DevicePropertyCode::from_name(name).or_else(|| {
+        properties
+            .iter()
+            .filter_map(|prop| DevicePropertyCode::from_raw(prop.code))
+            .find(|&code| property_display_name(code).to_lowercase() == name_lower)
+    })?;
+
+    properties
+        .iter()
+        .any(|prop| prop.code == code.as_raw())
+        .then_some(code)
 }
 
 pub fn format_value(code: DevicePropertyCode, raw: u64) -> String {
@@ -85,11 +85,9 @@ fn list(
         let code_a = DevicePropertyCode::from_raw(a.code);
         let code_b = DevicePropertyCode::from_raw(b.code);
         match (code_a, code_b) {
-            (Some(ca), Some(cb)) => {
-                let cat_a = format!("{:?}", property_category(ca));
-                let cat_b = format!("{:?}", property_category(cb));
-                cat_a.cmp(&cat_b).then_with(|| ca.name().cmp(cb.name()))
-            }
+            (Some(ca), Some(cb)) => property_category(ca)
+                .cmp(&property_category(cb))
+                .then_with(|| ca.name().cmp(cb.name())),
             (Some(_), None) => std::cmp::Ordering::Less,
             (None, Some(_)) => std::cmp::Ordering::Greater,
             (None, None) => a.code.cmp(&b.code),
@@ -38,6 +38,9 @@
 //! # Start/stop recording
 //! sonyctl record start
 //! sonyctl record stop
+//!
+//! # Stream live view, saving the latest frame and printing the frame rate
+//! sonyctl live-view --output frame.jpg
 //! ```
 
 mod commands;
@@ -1,10 +1,11 @@
+use crsdk::property::{FocusOperation, ZoomOperation};
+
 /// Actions represent all possible user intents and system events
 #[derive(Debug, Clone)]
 pub enum Action {
     // Lifecycle
     Quit,
     Tick,
-    FlushPendingProperty,
 
     // Navigation
     Back,
@@ -25,6 +26,8 @@ pub enum Action {
     AdjustPropertyDown,
     Capture,
     HalfPressShutter,
+    DriveFocus(FocusOperation), // Hold Shift+left/right; Stop on release
+    DriveZoom(ZoomOperation),   // Hold Shift+up/down; Stop on release
     StartRecording,
     StopRecording,
     ShowPropertyEditor,
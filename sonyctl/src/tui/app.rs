@@ -14,7 +14,6 @@ use crsdk::{
     PropertyCategoryId,
 };
 
-const PROPERTY_DEBOUNCE_MS: u64 = 400;
 const IN_FLIGHT_TIMEOUT_MS: u64 = 2000;
 const MAX_EVENT_LOG_SIZE: usize = 100;
 
@@ -221,8 +220,9 @@ pub struct App {
     /// Whether to automatically trust SSH fingerprints (--trust flag)
     trust_ssh_fingerprint: bool,
 
-    /// Pending property change for debouncing (property_code, value_index, timestamp)
-    pending_property: Option<(DevicePropertyCode, usize, Instant)>,
+    /// Property with a value handed to the camera service but not written
+    /// yet; the service debounces rapid changes and reports `PropertySent`
+    pending_property: Option<DevicePropertyCode>,
     /// Property currently being sent to SDK, waiting for confirmation (with timestamp for timeout)
     in_flight_property: Option<(DevicePropertyCode, Instant)>,
 }
@@ -265,39 +265,18 @@ impl App {
         }
     }
 
-    /// Get the duration until the pending property should be flushed, if any
-    pub fn debounce_timeout(&self) -> Option<Duration> {
-        self.pending_property.map(|(_, _, timestamp)| {
-            let elapsed = timestamp.elapsed();
-            let debounce = Duration::from_millis(PROPERTY_DEBOUNCE_MS);
-            debounce.saturating_sub(elapsed)
-        })
-    }
-
-    /// Flush any pending property change to the camera (call when debounce timeout fires)
-    pub async fn flush_pending_property(&mut self) {
-        if let Some((code, value_index, timestamp)) = self.pending_property.take() {
-            if timestamp.elapsed() >= Duration::from_millis(PROPERTY_DEBOUNCE_MS) {
-                tracing::debug!("Setting in_flight_property to {:?}", code);
-                self.in_flight_property = Some((code, Instant::now()));
-                let _ = self
-                    .camera_service
-                    .send(CameraCommand::SetProperty { code, value_index })
-                    .await;
-            }
-        }
-    }
-
-    /// Queue a property change with debouncing
+    /// Hand a property change to the camera service, which debounces it
     fn queue_property_change(&mut self, code: DevicePropertyCode, value_index: usize) {
-        self.pending_property = Some((code, value_index, Instant::now()));
+        self.pending_property = Some(code);
+        let _ = self
+            .camera_service
+            .cmd_tx
+            .try_send(CameraCommand::SetProperty { code, value_index });
     }
 
-    /// Check if a specific property has a pending change (debouncing)
+    /// Check if a specific property has a change the service hasn't sent yet
     pub fn has_pending_change(&self, code: DevicePropertyCode) -> bool {
-        self.pending_property
-            .map(|(pending_code, _, _)| pending_code == code)
-            .unwrap_or(false)
+        self.pending_property == Some(code)
     }
 
     /// Check if a specific property is in-flight (sent to SDK, waiting for response)
@@ -385,6 +364,13 @@ impl App {
                 self.properties
                     .update_property(code, &value, raw_value, available, writable, kind);
             }
+            CameraUpdate::PropertySent { code } => {
+                if self.pending_property == Some(code) {
+                    self.pending_property = None;
+                    tracing::debug!("Setting in_flight_property to {:?}", code);
+                    self.in_flight_property = Some((code, Instant::now()));
+                }
+            }
             CameraUpdate::Error { message } => {
                 self.log_event("Error", &message);
                 self.modal = Some(Modal::Error { message });
@@ -517,7 +503,6 @@ impl App {
             Action::ShowHelp => self.help_visible = true,
             Action::HideHelp => self.help_visible = false,
             Action::Tick => self.handle_tick(),
-            Action::FlushPendingProperty => self.flush_pending_property().await,
             Action::Back => self.handle_back().await,
             _ => self.handle_screen_action(action).await,
         }
@@ -647,6 +632,18 @@ impl App {
                     self.jump_to_property_in_editor(code);
                 }
             }
+            Action::DriveFocus(direction) => {
+                let _ = self
+                    .camera_service
+                    .send(CameraCommand::DriveFocus(direction))
+                    .await;
+            }
+            Action::DriveZoom(direction) => {
+                let _ = self
+                    .camera_service
+                    .send(CameraCommand::DriveZoom(direction))
+                    .await;
+            }
             Action::Capture => {
                 let _ = self.camera_service.send(CameraCommand::Capture).await;
             }
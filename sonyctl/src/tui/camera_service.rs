@@ -7,13 +7,39 @@ use std::net::Ipv4Addr;
 
 use tokio::sync::mpsc;
 
+use crsdk::property::{FocusOperation, ZoomOperation};
 use crsdk::{
     warning_code_name, warning_param_description, CameraDevice, CameraEvent as SdkEvent,
-    DeviceProperty, DevicePropertyCode, MacAddr, ValueConstraint,
+    DeviceProperty, DevicePropertyCode, MacAddr, TypedValue, ValueConstraint,
 };
 
 use super::property::{format_sdk_value, PropertyKind};
 
+/// How long to wait for the camera to send its properties after connecting
+const READY_TIMEOUT: tokio::time::Duration = tokio::time::Duration::from_secs(10);
+
+/// Stop a focus/zoom drive if its key isn't repeated within this long
+///
+/// Covers terminals that don't report key releases, so it has to exceed
+/// the keyboard's initial repeat delay (660ms by default under X11) or a
+/// held key stops before its first repeat.
+const DRIVE_HOLD_TIMEOUT: tokio::time::Duration = tokio::time::Duration::from_millis(750);
+
+/// Properties that decide how gain is shown: ISO shows as dB when
+/// `GainUnitSetting` says so
+const GAIN_CODES: [DevicePropertyCode; 3] = [
+    DevicePropertyCode::GainUnitSetting,
+    DevicePropertyCode::IsoSensitivity,
+    DevicePropertyCode::GaindBValue,
+];
+
+/// Property values picked closer together than this are sent once, as the
+/// last one
+const PROPERTY_QUIET_PERIOD: tokio::time::Duration = tokio::time::Duration::from_millis(400);
+
+/// Property changes closer together than this are redrawn once
+const EVENT_COALESCE_WINDOW: tokio::time::Duration = tokio::time::Duration::from_millis(50);
+
 /// Get available values from a property's constraint as formatted strings.
 /// For discrete values, formats each value. For ranges, returns the current value.
 fn format_available_values(code: DevicePropertyCode, prop: &DeviceProperty) -> Vec<String> {
@@ -58,6 +84,8 @@ pub enum CameraUpdate {
         writable: bool,
         kind: PropertyKind,
     },
+    /// A `SetProperty` value was written, or failed (reported as `Error`)
+    PropertySent { code: DevicePropertyCode },
     /// An error occurred
     Error { message: String },
     /// Discovery results are available
@@ -169,6 +197,17 @@ pub enum CameraCommand {
     StopRecording,
     /// Half-press shutter (autofocus)
     HalfPressShutter,
+    /// Start (or keep alive) a continuous focus drive; `Stop` ends it
+    DriveFocus(FocusOperation),
+    /// Start (or keep alive) a continuous zoom; `Stop` ends it
+    DriveZoom(ZoomOperation),
+}
+
+/// A continuous focus or zoom drive in progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Drive {
+    Focus(FocusOperation),
+    Zoom(ZoomOperation),
 }
 
 /// Handle to communicate with the camera service
@@ -203,6 +242,12 @@ pub struct CameraService {
     af_engaged: bool,
     /// When to auto-release AF (following SDK example pattern of fixed delay)
     af_release_at: Option<tokio::time::Instant>,
+    /// Focus/zoom drive currently running, if any
+    drive: Option<Drive>,
+    /// When to stop the drive if the key isn't repeated or released
+    drive_stop_at: Option<tokio::time::Instant>,
+    /// Command that arrived while a debounced property write was pending
+    deferred: Option<CameraCommand>,
 }
 
 impl CameraService {
@@ -219,6 +264,9 @@ impl CameraService {
             cached_properties: std::collections::HashMap::new(),
             af_engaged: false,
             af_release_at: None,
+            drive: None,
+            drive_stop_at: None,
+            deferred: None,
         };
 
         tokio::spawn(service.run());
@@ -228,6 +276,11 @@ impl CameraService {
 
     async fn run(mut self) {
         loop {
+            if let Some(cmd) = self.deferred.take() {
+                self.handle_command(cmd).await;
+                continue;
+            }
+
             // Check if we need to auto-release AF
             let af_timeout = if let Some(release_at) = self.af_release_at {
                 let now = tokio::time::Instant::now();
@@ -257,11 +310,22 @@ impl CameraService {
                     // AF timeout - auto-release shutter
                     self.handle_af_timeout().await;
                 }
+                _ = sleep_until(self.drive_stop_at) => {
+                    // Key no longer held - stop focus/zoom
+                    self.stop_drive().await;
+                }
             }
         }
     }
 }
 
+async fn sleep_until(deadline: Option<tokio::time::Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
 async fn recv_event(rx: &mut Option<mpsc::UnboundedReceiver<SdkEvent>>) -> Option<SdkEvent> {
     match rx {
         Some(receiver) => receiver.recv().await,
@@ -270,6 +334,25 @@ async fn recv_event(rx: &mut Option<mpsc::UnboundedReceiver<SdkEvent>>) -> Optio
 }
 
 impl CameraService {
+    /// Format a property's current value, showing gain in the unit the
+    /// camera is set to display
+    fn format_current(&self, code: DevicePropertyCode, prop: &DeviceProperty) -> String {
+        if !GAIN_CODES.contains(&code) {
+            return format_sdk_value(code, prop.current_value);
+        }
+        let mut snapshot = vec![prop.clone()];
+        snapshot.extend(
+            GAIN_CODES
+                .iter()
+                .filter(|&&c| c != code)
+                .filter_map(|c| self.cached_properties.get(c).cloned()),
+        );
+        TypedValue::from_snapshot(code, &snapshot).map_or_else(
+            || format_sdk_value(code, prop.current_value),
+            |v| v.to_string(),
+        )
+    }
+
     async fn send_update(&self, update: CameraUpdate) {
         if let Err(e) = self.update_tx.send(update).await {
             tracing::debug!("UI channel closed, update not sent: {}", e);
@@ -321,14 +404,21 @@ impl CameraService {
             CameraCommand::HalfPressShutter => {
                 self.handle_half_press().await;
             }
+            CameraCommand::DriveFocus(direction) => {
+                self.handle_drive(Drive::Focus(direction)).await;
+            }
+            CameraCommand::DriveZoom(direction) => {
+                self.handle_drive(Drive::Zoom(direction)).await;
+            }
         }
     }
 
     async fn handle_discover(&mut self) {
         self.send_update(CameraUpdate::DiscoveryStarted).await;
 
-        match crsdk::discover_cameras(5).await {
-            Ok(cameras) => {
+        match crsdk::discover_cameras_debug(5).await {
+            Ok((cameras, diagnostics)) => {
+                tracing::debug!("Discovery: {}", diagnostics);
                 let infos: Vec<DiscoveredCameraInfo> = cameras
                     .into_iter()
                     .map(|c| DiscoveredCameraInfo {
@@ -418,7 +508,7 @@ impl CameraService {
                 let address = ip.to_string();
                 tracing::info!("Connected to {} ({})", model, address);
 
-                self.event_rx = device.take_event_receiver();
+                self.event_rx = device.events_coalesced(EVENT_COALESCE_WINDOW);
                 self.device = Some(device);
 
                 self.send_update(CameraUpdate::Connected {
@@ -429,7 +519,11 @@ impl CameraService {
 
                 // Wait for camera to initialize and send property updates
                 tracing::info!("Waiting for camera to initialize...");
-                tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+                if let Some(device) = &self.device {
+                    if let Err(e) = device.wait_ready(READY_TIMEOUT).await {
+                        tracing::warn!("Camera not ready: {}", e);
+                    }
+                }
 
                 tracing::info!("Syncing properties...");
                 self.sync_all_properties().await;
@@ -456,7 +550,7 @@ impl CameraService {
                 let address = ip.to_string();
                 tracing::info!("Connected to {} ({})", model, address);
 
-                self.event_rx = device.take_event_receiver();
+                self.event_rx = device.events_coalesced(EVENT_COALESCE_WINDOW);
                 self.device = Some(device);
 
                 self.send_update(CameraUpdate::Connected {
@@ -467,7 +561,11 @@ impl CameraService {
 
                 // Wait for camera to initialize and send property updates
                 tracing::info!("Waiting for camera to initialize...");
-                tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+                if let Some(device) = &self.device {
+                    if let Err(e) = device.wait_ready(READY_TIMEOUT).await {
+                        tracing::warn!("Camera not ready: {}", e);
+                    }
+                }
 
                 tracing::info!("Syncing properties...");
                 self.sync_all_properties().await;
@@ -484,6 +582,8 @@ impl CameraService {
     }
 
     async fn handle_disconnect(&mut self) {
+        self.drive = None;
+        self.drive_stop_at = None;
         self.device = None;
         self.event_rx = None;
         self.cached_properties.clear();
@@ -500,13 +600,23 @@ impl CameraService {
             Ok(all_props) => {
                 tracing::info!("Camera exposes {} properties", all_props.len());
 
+                // Cache the gain unit up front so ISO is formatted with it
+                // regardless of the order properties arrive in
+                for (prop, _) in &all_props {
+                    if let Some(code) = DevicePropertyCode::from_raw(prop.code) {
+                        if GAIN_CODES.contains(&code) && prop.enable_flag.is_readable() {
+                            self.cached_properties.insert(code, prop.clone());
+                        }
+                    }
+                }
+
                 for (prop, _debug_info) in all_props {
                     if !prop.enable_flag.is_readable() {
                         continue;
                     }
 
                     if let Some(code) = DevicePropertyCode::from_raw(prop.code) {
-                        let current = format_sdk_value(code, prop.current_value);
+                        let current = self.format_current(code, &prop);
                         let raw_value = prop.current_value;
                         let available = format_available_values(code, &prop);
                         let writable = prop.enable_flag.is_writable();
@@ -691,52 +801,21 @@ impl CameraService {
         .await;
     }
 
+    /// Send a value picked by index, debounced: further `SetProperty`
+    /// commands for the same property that arrive within the quiet period
+    /// replace it, and only the last one is written
     async fn handle_set_property(&mut self, code: DevicePropertyCode, value_index: usize) {
-        let Some(cached) = self.cached_properties.get(&code) else {
-            self.send_update(CameraUpdate::Error {
-                message: format!("Property {} not in cache", code.name()),
-            })
-            .await;
-            return;
-        };
-
-        let value = match &cached.constraint {
-            ValueConstraint::Discrete(values) => {
-                let Some(&v) = values.get(value_index) else {
-                    self.send_update(CameraUpdate::Error {
-                        message: format!("Invalid value index {} for {}", value_index, code.name()),
-                    })
-                    .await;
-                    return;
-                };
-                v
-            }
-            ValueConstraint::Range { min, max, step } => {
-                let step = if *step == 0 { 1 } else { *step };
-                let count = ((max - min) / step + 1) as usize;
-                if value_index >= count {
-                    self.send_update(CameraUpdate::Error {
-                        message: format!("Invalid value index {} for {}", value_index, code.name()),
-                    })
-                    .await;
-                    return;
-                }
-                (min + (value_index as i64) * step) as u64
-            }
-            ValueConstraint::None => {
-                self.send_update(CameraUpdate::Error {
-                    message: format!("Property {} has no selectable values", code.name()),
-                })
-                .await;
+        let value = match self.value_for_index(code, value_index) {
+            Ok(value) => value,
+            Err(message) => {
+                self.send_update(CameraUpdate::PropertySent { code }).await;
+                self.send_update(CameraUpdate::Error { message }).await;
                 return;
             }
         };
 
-        self.handle_set_property_raw(code, value).await;
-    }
-
-    async fn handle_set_property_raw(&mut self, code: DevicePropertyCode, value: u64) {
         let Some(ref device) = self.device else {
+            self.send_update(CameraUpdate::PropertySent { code }).await;
             self.send_update(CameraUpdate::Error {
                 message: "Not connected".to_string(),
             })
@@ -744,7 +823,33 @@ impl CameraService {
             return;
         };
 
-        if let Err(e) = device.set_property(code, value).await {
+        let mut sink = device
+            .set_property_debounced(code)
+            .with_quiet_period(PROPERTY_QUIET_PERIOD);
+        sink.set(value);
+
+        let result = loop {
+            tokio::select! {
+                result = sink.settle() => break result,
+                cmd = self.cmd_rx.recv() => match cmd {
+                    Some(CameraCommand::SetProperty { code: next, value_index }) if next == code => {
+                        match self.value_for_index(code, value_index) {
+                            Ok(value) => sink.set(value),
+                            Err(message) => tracing::warn!("{}", message),
+                        }
+                    }
+                    other => {
+                        // Anything else goes out right after the pending value
+                        self.deferred = other;
+                        break sink.flush().await;
+                    }
+                },
+            }
+        };
+        drop(sink);
+
+        self.send_update(CameraUpdate::PropertySent { code }).await;
+        if let Err(e) = result {
             self.send_update(CameraUpdate::Error {
                 message: format!("Failed to set property: {}", e),
             })
@@ -752,6 +857,35 @@ impl CameraService {
         }
     }
 
+    /// The raw value at `value_index` among the cached property's values
+    fn value_for_index(&self, code: DevicePropertyCode, value_index: usize) -> Result<u64, String> {
+        let Some(cached) = self.cached_properties.get(&code) else {
+            return Err(format!("Property {} not in cache", code.name()));
+        };
+
+        match &cached.constraint {
+            ValueConstraint::Discrete(values) => values
+                .get(value_index)
+                .copied()
+                .ok_or_else(|| format!("Invalid value index {} for {}", value_index, code.name())),
+            ValueConstraint::Range { min, max, step } => {
+                let step = if *step == 0 { 1 } else { *step };
+                let count = ((max - min) / step + 1) as usize;
+                if value_index >= count {
+                    return Err(format!(
+                        "Invalid value index {} for {}",
+                        value_index,
+                        code.name()
+                    ));
+                }
+                Ok((min + (value_index as i64) * step) as u64)
+            }
+            ValueConstraint::None => {
+                Err(format!("Property {} has no selectable values", code.name()))
+            }
+        }
+    }
+
     async fn handle_capture(&mut self) {
         let Some(ref device) = self.device else {
             self.send_update(CameraUpdate::Error {
@@ -840,6 +974,64 @@ impl CameraService {
         }
     }
 
+    /// Start, keep alive or stop a press-and-hold focus/zoom drive
+    ///
+    /// Each key press or repeat pushes the auto-stop deadline out, so the
+    /// drive also ends on terminals that never report key releases.
+    async fn handle_drive(&mut self, drive: Drive) {
+        if matches!(
+            drive,
+            Drive::Focus(FocusOperation::Stop) | Drive::Zoom(ZoomOperation::Stop)
+        ) {
+            if self.drive.is_some() {
+                self.stop_drive().await;
+            }
+            return;
+        }
+
+        if self.drive != Some(drive) {
+            if self.drive.is_some() {
+                self.stop_drive().await;
+            }
+            let Some(ref device) = self.device else {
+                return;
+            };
+            let result = match drive {
+                Drive::Focus(direction) => device.drive_focus(direction).await,
+                Drive::Zoom(direction) => device.drive_zoom(direction).await,
+            };
+            if let Err(e) = result {
+                tracing::error!("{:?} failed: {}", drive, e);
+                self.send_update(CameraUpdate::Error {
+                    message: format!("Focus/zoom drive failed: {}", e),
+                })
+                .await;
+                return;
+            }
+            self.drive = Some(drive);
+        }
+
+        self.drive_stop_at = Some(tokio::time::Instant::now() + DRIVE_HOLD_TIMEOUT);
+    }
+
+    /// Stop the running focus/zoom drive
+    async fn stop_drive(&mut self) {
+        self.drive_stop_at = None;
+        let Some(drive) = self.drive.take() else {
+            return;
+        };
+        let Some(ref device) = self.device else {
+            return;
+        };
+        let result = match drive {
+            Drive::Focus(_) => device.drive_focus(FocusOperation::Stop).await,
+            Drive::Zoom(_) => device.drive_zoom(ZoomOperation::Stop).await,
+        };
+        if let Err(e) = result {
+            tracing::error!("Failed to stop {:?}: {}", drive, e);
+        }
+    }
+
     async fn handle_half_press(&mut self) {
         let Some(ref device) = self.device else {
             tracing::warn!("Half-press: no device connected");
@@ -902,7 +1094,18 @@ impl CameraService {
                 self.send_update(CameraUpdate::Disconnected { error: error_msg })
                     .await;
             }
-            SdkEvent::PropertyChanged { codes } => {
+            SdkEvent::PropertyChanged { mut codes } => {
+                // A unit or dB change alters how ISO is shown, so redraw it
+                // after the others
+                if codes.iter().any(|c| {
+                    matches!(
+                        c,
+                        DevicePropertyCode::GainUnitSetting | DevicePropertyCode::GaindBValue
+                    )
+                }) && !codes.contains(&DevicePropertyCode::IsoSensitivity)
+                {
+                    codes.push(DevicePropertyCode::IsoSensitivity);
+                }
                 for sdk_code in codes {
                     if let Some(code) = DevicePropertyCode::from_raw(sdk_code.as_raw()) {
                         if let Some(ref device) = self.device {
@@ -911,7 +1114,7 @@ impl CameraService {
                                     continue;
                                 }
 
-                                let current = format_sdk_value(code, prop.current_value);
+                                let current = self.format_current(code, &prop);
                                 let raw_value = prop.current_value;
                                 let available = format_available_values(code, &prop);
                                 let writable = prop.enable_flag.is_writable();
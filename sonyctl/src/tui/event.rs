@@ -1,6 +1,7 @@
 use std::time::Duration;
 
-use crossterm::event::{Event, EventStream, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{Event, EventStream, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crsdk::property::{FocusOperation, ZoomOperation};
 use futures::StreamExt;
 use tokio::time::{interval, Interval};
 
@@ -21,9 +22,6 @@ impl EventHandler {
     }
 
     pub async fn next(&mut self, app: &App) -> Option<Action> {
-        // Get debounce timeout if there's a pending property change
-        let debounce_timeout = app.debounce_timeout();
-
         tokio::select! {
             biased;
 
@@ -31,17 +29,6 @@ impl EventHandler {
                 Self::map_terminal_event(event, app)
             }
 
-            // Fire debounce timeout if pending property exists and timeout elapsed
-            _ = async {
-                if let Some(timeout) = debounce_timeout {
-                    tokio::time::sleep(timeout).await;
-                } else {
-                    std::future::pending::<()>().await;
-                }
-            } => {
-                Some(Action::FlushPendingProperty)
-            }
-
             _ = self.tick_interval.tick() => {
                 Some(Action::Tick)
             }
@@ -62,6 +49,12 @@ impl EventHandler {
             return Some(Action::Quit);
         }
 
+        // Releases are only reported by terminals with keyboard enhancement
+        // (and on Windows); they end a press-and-hold drive and nothing else
+        if key.kind == KeyEventKind::Release {
+            return Self::map_key_release(key, app);
+        }
+
         // Help is visible - any key closes it
         if app.help_visible {
             return Some(Action::HideHelp);
@@ -81,6 +74,17 @@ impl EventHandler {
         }
     }
 
+    fn map_key_release(key: KeyEvent, app: &App) -> Option<Action> {
+        if app.screen != Screen::Dashboard || app.modal.is_some() || app.help_visible {
+            return None;
+        }
+        match key.code {
+            KeyCode::Left | KeyCode::Right => Some(Action::DriveFocus(FocusOperation::Stop)),
+            KeyCode::Up | KeyCode::Down => Some(Action::DriveZoom(ZoomOperation::Stop)),
+            _ => None,
+        }
+    }
+
     fn map_modal_key(key: KeyEvent) -> Option<Action> {
         match key.code {
             KeyCode::Esc => Some(Action::ModalClose),
@@ -118,9 +122,16 @@ impl EventHandler {
     }
 
     fn map_dashboard_key(key: KeyEvent) -> Option<Action> {
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+
         match key.code {
             KeyCode::Char('q') => Some(Action::Quit),
             KeyCode::Char('?') => Some(Action::ShowHelp),
+            // Press-and-hold focus/zoom drive (repeats keep it going)
+            KeyCode::Left if shift => Some(Action::DriveFocus(FocusOperation::Wide)),
+            KeyCode::Right if shift => Some(Action::DriveFocus(FocusOperation::Tele)),
+            KeyCode::Up if shift => Some(Action::DriveZoom(ZoomOperation::Tele)),
+            KeyCode::Down if shift => Some(Action::DriveZoom(ZoomOperation::Wide)),
             // Property navigation
             KeyCode::Char('j') | KeyCode::Down => Some(Action::SelectNextDashboardProperty),
             KeyCode::Char('k') | KeyCode::Up => Some(Action::SelectPrevDashboardProperty),
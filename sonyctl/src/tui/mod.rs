@@ -1,12 +1,20 @@
 use std::io;
+use std::net::Ipv4Addr;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Args as ClapArgs;
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{
+        DisableMouseCapture, EnableMouseCapture, KeyboardEnhancementFlags,
+        PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal::{
+        disable_raw_mode, enable_raw_mode, supports_keyboard_enhancement, EnterAlternateScreen,
+        LeaveAlternateScreen,
+    },
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
 use tracing_appender::non_blocking::WorkerGuard;
@@ -19,7 +27,7 @@ pub mod event;
 pub mod property;
 pub mod ui;
 
-use app::App;
+use app::{App, Modal};
 use camera_service::{CameraCommand, CameraService};
 use crsdk::MacAddr;
 use event::EventHandler;
@@ -54,30 +62,93 @@ fn setup_logging(args: &Args) -> Result<WorkerGuard> {
     Ok(guard)
 }
 
+/// Whether key release reporting was turned on and must be undone
+static KEYBOARD_ENHANCED: AtomicBool = AtomicBool::new(false);
+
 fn setup_terminal() -> Result<Terminal<CrosstermBackend<io::Stdout>>> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    // Report key releases where the terminal can, so press-and-hold
+    // focus/zoom stops as soon as the key is let go
+    if supports_keyboard_enhancement().unwrap_or(false) {
+        execute!(
+            stdout,
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::REPORT_EVENT_TYPES)
+        )?;
+        KEYBOARD_ENHANCED.store(true, Ordering::Relaxed);
+    }
     let backend = CrosstermBackend::new(stdout);
     let terminal = Terminal::new(backend)?;
     Ok(terminal)
 }
 
 fn restore_terminal() -> Result<()> {
+    if KEYBOARD_ENHANCED.swap(false, Ordering::Relaxed) {
+        execute!(io::stdout(), PopKeyboardEnhancementFlags)?;
+    }
     disable_raw_mode()?;
     execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
     Ok(())
 }
 
+/// Restore the terminal before printing a panic, so a bug doesn't leave the
+/// user's shell in raw mode on the alternate screen.
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = restore_terminal();
+        default_hook(info);
+    }));
+}
+
 pub async fn run(cli: &Cli, args: &Args) -> Result<()> {
     let _guard = setup_logging(args)?;
 
-    let terminal = setup_terminal()?;
+    install_panic_hook();
+    // Setup can fail after raw mode is already on
+    let terminal = setup_terminal().inspect_err(|_| {
+        let _ = restore_terminal();
+    })?;
     let result = run_app(terminal, cli).await;
     restore_terminal()?;
     result
 }
 
+fn parse_address(ip: &str, mac: &str) -> Result<(Ipv4Addr, MacAddr)> {
+    let ip = ip
+        .parse()
+        .with_context(|| format!("Invalid IP address: {}", ip))?;
+    let mac = mac
+        .parse()
+        .with_context(|| format!("Invalid MAC address: {}", mac))?;
+    Ok((ip, mac))
+}
+
+async fn connect_from_cli(app: &mut App, cli: &Cli, ip: Ipv4Addr, mac: MacAddr) {
+    // Skip discovery screen - go straight to dashboard while connecting
+    app.set_connecting();
+
+    if let (Some(user), Some(password)) = (&cli.user, &cli.password) {
+        tracing::info!("SSH enabled, fetching fingerprint...");
+
+        let _ = app
+            .camera_service_cmd()
+            .send(CameraCommand::FetchSshFingerprint {
+                ip,
+                mac,
+                ssh_user: user.clone(),
+                ssh_pass: password.clone(),
+            })
+            .await;
+    } else {
+        let _ = app
+            .camera_service_cmd()
+            .send(CameraCommand::Connect { ip, mac })
+            .await;
+    }
+}
+
 async fn run_app(mut terminal: Terminal<CrosstermBackend<io::Stdout>>, cli: &Cli) -> Result<()> {
     let camera_handle = CameraService::spawn();
 
@@ -88,31 +159,15 @@ async fn run_app(mut terminal: Terminal<CrosstermBackend<io::Stdout>>, cli: &Cli
     if let (Some(ip_str), Some(mac_str)) = (&cli.ip, &cli.mac) {
         tracing::info!("Connecting via CLI args: ip={}, mac={}", ip_str, mac_str);
 
-        let ip: std::net::Ipv4Addr = ip_str.parse().expect("Invalid IP address");
-        let mac: MacAddr = mac_str.parse().expect("Invalid MAC address");
-
-        // Skip discovery screen - go straight to dashboard while connecting
-        app.set_connecting();
-
-        if cli.user.is_some() && cli.password.is_some() {
-            let user = cli.user.clone().unwrap();
-            let password = cli.password.clone().unwrap();
-            tracing::info!("SSH enabled, fetching fingerprint...");
-
-            let _ = app
-                .camera_service_cmd()
-                .send(CameraCommand::FetchSshFingerprint {
-                    ip,
-                    mac,
-                    ssh_user: user,
-                    ssh_pass: password,
-                })
-                .await;
-        } else {
-            let _ = app
-                .camera_service_cmd()
-                .send(CameraCommand::Connect { ip, mac })
-                .await;
+        match parse_address(ip_str, mac_str) {
+            Ok((ip, mac)) => connect_from_cli(&mut app, cli, ip, mac).await,
+            Err(e) => {
+                // Stay on the discovery screen so the user can pick a camera instead
+                tracing::error!("Invalid connection arguments: {:#}", e);
+                app.modal = Some(Modal::Error {
+                    message: format!("{:#}", e),
+                });
+            }
         }
     }
 
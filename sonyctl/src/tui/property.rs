@@ -194,12 +194,11 @@ impl PropertyStore {
 
     fn insert_pinned_sorted(&mut self, code: DevicePropertyCode) {
         let category = property_category(code);
-        let category_order = category_sort_order(category);
 
         let insert_pos = self
             .pinned
             .iter()
-            .position(|&p| category_sort_order(property_category(p)) > category_order)
+            .position(|&p| property_category(p) > category)
             .unwrap_or(self.pinned.len());
 
         self.pinned.insert(insert_pos, code);
@@ -221,7 +220,7 @@ impl PropertyStore {
             .values()
             .map(|p| property_category(p.code))
             .collect();
-        categories.sort_by_key(|c| category_sort_order(*c));
+        categories.sort();
         categories.dedup();
         categories
     }
@@ -326,32 +325,6 @@ fn is_default_pinned(code: DevicePropertyCode) -> bool {
     )
 }
 
-fn category_sort_order(cat: PropertyCategoryId) -> u8 {
-    match cat.name() {
-        "Exposure" => 0,
-        "Focus" => 1,
-        "White Balance" => 2,
-        "Image" => 3,
-        "Movie" => 4,
-        "Media" => 5,
-        "Drive" => 6,
-        "Metering" => 7,
-        "Flash" => 8,
-        "Zoom" => 9,
-        "Lens" => 10,
-        "Audio" => 11,
-        "Picture Profile" => 12,
-        "ND Filter" => 13,
-        "Stabilization" => 14,
-        "Display" => 15,
-        "Power" => 16,
-        "Custom Buttons" => 17,
-        "Silent" => 18,
-        "Other" => 19,
-        _ => 20,
-    }
-}
-
 pub fn format_sdk_value(code: DevicePropertyCode, raw: u64) -> String {
     TypedValue::from_raw(code, raw).to_string()
 }
@@ -544,13 +517,7 @@ mod tests {
 
     #[test]
     fn test_category_sort_order() {
-        assert!(
-            category_sort_order(PropertyCategoryId("Exposure"))
-                < category_sort_order(PropertyCategoryId("Focus"))
-        );
-        assert!(
-            category_sort_order(PropertyCategoryId("Focus"))
-                < category_sort_order(PropertyCategoryId("Movie"))
-        );
+        assert!(PropertyCategoryId("Exposure") < PropertyCategoryId("Focus"));
+        assert!(PropertyCategoryId("Focus") < PropertyCategoryId("Movie"));
     }
 }
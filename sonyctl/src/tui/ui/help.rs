@@ -70,7 +70,8 @@ fn dashboard_help() -> Vec<Line<'static>> {
         two_col_shortcut("↑↓  j/k", "Select setting", "Space", "Capture"),
         two_col_shortcut("←→  h/l", "Adjust value", "f", "Focus"),
         two_col_shortcut("Tab", "Next panel", "v/s", "Record"),
-        two_col_shortcut("1-5", "Go to panel", "", ""),
+        two_col_shortcut("1-5", "Go to panel", "⇧←→", "Focus (hold)"),
+        two_col_shortcut("", "", "⇧↑↓", "Zoom (hold)"),
         Line::from(""),
         two_columns("Screens", "General"),
         two_col_shortcut("p", "Properties", "?", "Help"),